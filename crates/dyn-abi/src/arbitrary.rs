@@ -405,6 +405,10 @@ impl DynSolValue {
             DynSolType::Function => u.arbitrary().map(Self::Function),
             &DynSolType::Int(sz) => u.arbitrary().map(|x| Self::Int(adjust_int(x, sz), sz)),
             &DynSolType::Uint(sz) => u.arbitrary().map(|x| Self::Uint(adjust_uint(x, sz), sz)),
+            &DynSolType::Fixed(m, n) => u.arbitrary().map(|x| Self::Fixed(adjust_int(x, m), m, n)),
+            &DynSolType::Ufixed(m, n) => {
+                u.arbitrary().map(|x| Self::Ufixed(adjust_uint(x, m), m, n))
+            }
             &DynSolType::FixedBytes(sz) => {
                 u.arbitrary().map(|x| Self::FixedBytes(adjust_fb(x, sz), sz))
             }
@@ -469,6 +473,12 @@ impl DynSolValue {
             &DynSolType::Uint(sz) => {
                 any::<U256>().prop_map(move |x| Self::Uint(adjust_uint(x, sz), sz)).sboxed()
             }
+            &DynSolType::Fixed(m, n) => {
+                any::<I256>().prop_map(move |x| Self::Fixed(adjust_int(x, m), m, n)).sboxed()
+            }
+            &DynSolType::Ufixed(m, n) => {
+                any::<U256>().prop_map(move |x| Self::Ufixed(adjust_uint(x, m), m, n)).sboxed()
+            }
             &DynSolType::FixedBytes(sz) => {
                 any::<B256>().prop_map(move |x| Self::FixedBytes(adjust_fb(x, sz), sz)).sboxed()
             }
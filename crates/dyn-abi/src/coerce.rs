@@ -37,6 +37,9 @@ impl DynSolType {
     ///     `0b`, `0o`, or `0x` respectively.
     ///   - unit: same as [Solidity ether units](https://docs.soliditylang.org/en/latest/units-and-global-variables.html#ether-units)
     ///   - decimals with more digits than the unit's exponent value are not allowed
+    /// - [`Fixed`](DynSolType::Fixed): `[+-]?{Ufixed}`
+    /// - [`Ufixed`](DynSolType::Ufixed): `[0-9]+(\.[0-9]+)?`
+    ///   - decimals with more digits than the type's decimals are not allowed
     /// - [`FixedBytes`](DynSolType::FixedBytes): `(0x)?[0-9A-Fa-f]{$0*2}`
     /// - [`Address`](DynSolType::Address): `(0x)?[0-9A-Fa-f]{40}`
     /// - [`Function`](DynSolType::Function): `(0x)?[0-9A-Fa-f]{48}`
@@ -52,7 +55,9 @@ impl DynSolType {
     ///   parentheses (`()`)
     #[cfg_attr(
         feature = "eip712",
-        doc = "- [`CustomStruct`](DynSolType::CustomStruct): the same as `Tuple`"
+        doc = "- [`CustomStruct`](DynSolType::CustomStruct): the same as `Tuple`, or the fields \
+               as `name: value` pairs, in any order, delimited by commas (`,`) and surrounded by \
+               braces (`{}`). The result is a `Tuple` value in either case."
     )]
     ///
     /// # Examples
@@ -107,6 +112,12 @@ impl<'i> Parser<Input<'i>, DynSolValue, ErrMode<ContextError>> for ValueParser<'
             &DynSolType::Uint(size) => {
                 uint(size).parse_next(input).map(|uint| DynSolValue::Uint(uint, size))
             }
+            &DynSolType::Fixed(m, n) => {
+                fixed(m, n).parse_next(input).map(|raw| DynSolValue::Fixed(raw, m, n))
+            }
+            &DynSolType::Ufixed(m, n) => {
+                ufixed(m, n).parse_next(input).map(|raw| DynSolValue::Ufixed(raw, m, n))
+            }
             &DynSolType::FixedBytes(size) => {
                 fixed_bytes(size).parse_next(input).map(|word| DynSolValue::FixedBytes(word, size))
             }
@@ -122,6 +133,14 @@ impl<'i> Parser<Input<'i>, DynSolValue, ErrMode<ContextError>> for ValueParser<'
             DynSolType::FixedArray(ty, len) => self.in_list(']', |this| {
                 this.with(ty).fixed_array(*len).parse_next(input).map(DynSolValue::FixedArray)
             }),
+            #[cfg(feature = "eip712")]
+            DynSolType::CustomStruct { prop_names, tuple, .. }
+                if input.trim_start().starts_with('{') =>
+            {
+                self.in_list('}', |this| {
+                    this.object(prop_names, tuple).parse_next(input).map(DynSolValue::Tuple)
+                })
+            }
             as_tuple!(DynSolType tys) => {
                 self.in_list(')', |this| this.tuple(tys).parse_next(input).map(DynSolValue::Tuple))
             }
@@ -266,6 +285,62 @@ impl<'a> ValueParser<'a> {
             Ok(values)
         })
     }
+
+    #[cfg(feature = "eip712")]
+    fn object<'i: 's, 't: 's, 's>(
+        &'s self,
+        prop_names: &'t [alloc::string::String],
+        tuple: &'t [DynSolType],
+    ) -> impl ModalParser<Input<'i>, Vec<DynSolValue>, ContextError> + 's {
+        trace("struct", move |input: &mut Input<'i>| {
+            if prop_names.len() != tuple.len() {
+                let e = Error::FieldCountMismatch(prop_names.len(), tuple.len());
+                return Err(ErrMode::from_external_error(input, e).cut());
+            }
+
+            space0(input)?;
+            char_parser('{').parse_next(input)?;
+
+            let mut values = alloc::vec![None; tuple.len()];
+            space0(input)?;
+            if !input.starts_with('}') {
+                loop {
+                    space0(input)?;
+                    let name = field_name(input)?;
+                    let Some(i) = prop_names.iter().position(|p| p == name) else {
+                        let e = Error::UnknownField(name.into());
+                        return Err(ErrMode::from_external_error(input, e).cut());
+                    };
+                    if values[i].is_some() {
+                        let e = Error::DuplicateField(name.into());
+                        return Err(ErrMode::from_external_error(input, e).cut());
+                    }
+
+                    space0(input)?;
+                    cut_err(char_parser(':')).parse_next(input)?;
+                    space0(input)?;
+                    values[i] = Some(self.with(&tuple[i]).parse_next(input)?);
+
+                    space0(input)?;
+                    if opt(char_parser(',')).parse_next(input)?.is_none() {
+                        break;
+                    }
+                }
+            }
+
+            space0(input)?;
+            char_parser('}').parse_next(input)?;
+
+            core::iter::zip(values, prop_names)
+                .map(|(value, name)| {
+                    value.ok_or_else(|| {
+                        let e = Error::MissingField(name.clone());
+                        ErrMode::from_external_error(input, e).cut()
+                    })
+                })
+                .collect()
+        })
+    }
 }
 
 #[derive(Debug)]
@@ -277,6 +352,14 @@ enum Error {
     InvalidFixedBytesLength(usize),
     FixedArrayLengthMismatch(usize, usize),
     EmptyHexStringWithoutPrefix,
+    #[cfg(feature = "eip712")]
+    UnknownField(alloc::string::String),
+    #[cfg(feature = "eip712")]
+    DuplicateField(alloc::string::String),
+    #[cfg(feature = "eip712")]
+    MissingField(alloc::string::String),
+    #[cfg(feature = "eip712")]
+    FieldCountMismatch(usize, usize),
 }
 
 impl core::error::Error for Error {}
@@ -299,6 +382,16 @@ impl fmt::Display for Error {
             Self::EmptyHexStringWithoutPrefix => {
                 f.write_str("expected hex digits or the `0x` prefix for an empty hex string")
             }
+            #[cfg(feature = "eip712")]
+            Self::UnknownField(name) => write!(f, "unknown struct field `{name}`"),
+            #[cfg(feature = "eip712")]
+            Self::DuplicateField(name) => write!(f, "duplicate struct field `{name}`"),
+            #[cfg(feature = "eip712")]
+            Self::MissingField(name) => write!(f, "missing struct field `{name}`"),
+            #[cfg(feature = "eip712")]
+            Self::FieldCountMismatch(names, fields) => {
+                write!(f, "struct has {names} field names for {fields} fields")
+            }
         }
     }
 }
@@ -425,6 +518,68 @@ fn uint<'i>(len: usize) -> impl ModalParser<Input<'i>, U256, ContextError> {
     })
 }
 
+#[inline]
+fn fixed<'i>(size: usize, decimals: usize) -> impl ModalParser<Input<'i>, I256, ContextError> {
+    #[cfg(feature = "debug")]
+    let name = format!("fixed{size}x{decimals}");
+    #[cfg(not(feature = "debug"))]
+    let name = "fixed";
+    trace(
+        name,
+        (int_sign, ufixed(size, decimals)).try_map(move |(sign, abs)| {
+            // The range is `[-2^(size-1), 2^(size-1) - 1]`.
+            let max = U256::from(1) << (size - 1);
+            if if sign.is_negative() { abs > max } else { abs >= max } {
+                return Err(Error::IntOverflow);
+            }
+            I256::checked_from_sign_and_abs(sign, abs).ok_or(Error::IntOverflow)
+        }),
+    )
+}
+
+#[inline]
+fn ufixed<'i>(size: usize, decimals: usize) -> impl ModalParser<Input<'i>, U256, ContextError> {
+    #[cfg(feature = "debug")]
+    let name = format!("ufixed{size}x{decimals}");
+    #[cfg(not(feature = "debug"))]
+    let name = "ufixed";
+    trace(name, move |input: &mut Input<'_>| {
+        let intpart = digit1
+            .context(StrContext::Expected(StrContextValue::Description("at least one digit")))
+            .parse_next(input)?;
+        let fract =
+            opt(preceded(
+                '.',
+                cut_err(digit1.context(StrContext::Expected(StrContextValue::Description(
+                    "at least one digit",
+                )))),
+            ))
+            .parse_next(input)?
+            .unwrap_or("");
+
+        if fract.len() > decimals {
+            return Err(ErrMode::from_external_error(
+                input,
+                Error::TooManyDecimals(decimals, fract.len()),
+            ));
+        }
+
+        // intpart * 10^decimals + fract * 10^(decimals-fract.len())
+        let raw = (|| -> Option<U256> {
+            let intpart = U256::from_str_radix(intpart, 10).ok()?;
+            let fract_uint =
+                if fract.is_empty() { U256::ZERO } else { U256::from_str_radix(fract, 10).ok()? };
+            let exp = U256::from(10u64).checked_pow(U256::from(decimals))?;
+            let fract_exp = U256::from(10u64).checked_pow(U256::from(decimals - fract.len()))?;
+            intpart.checked_mul(exp)?.checked_add(fract_uint.checked_mul(fract_exp)?)
+        })()
+        .filter(|raw| raw.bit_len() <= size)
+        .ok_or_else(|| ErrMode::from_external_error(input, Error::IntOverflow))?;
+
+        Ok(raw)
+    })
+}
+
 #[inline]
 fn prefixed_int<'i>(input: &mut Input<'i>) -> ModalResult<&'i str> {
     trace(
@@ -528,6 +683,23 @@ fn hex_str<'i>(input: &mut Input<'i>) -> ModalResult<&'i str> {
     .parse_next(input)
 }
 
+/// A struct field name, optionally surrounded by double quotes.
+#[cfg(feature = "eip712")]
+#[inline]
+fn field_name<'i>(input: &mut Input<'i>) -> ModalResult<&'i str> {
+    trace("field_name", |input: &mut Input<'i>| {
+        let quoted = opt(char_parser('"')).parse_next(input)?.is_some();
+        let name = take_while(1.., |c: char| c.is_alphanumeric() || matches!(c, '_' | '$'))
+            .context(StrContext::Label("struct field name"))
+            .parse_next(input)?;
+        if quoted {
+            cut_err(char_parser('"')).parse_next(input)?;
+        }
+        Ok(name)
+    })
+    .parse_next(input)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -535,7 +707,7 @@ mod tests {
         boxed::Box,
         string::{String, ToString},
     };
-    use alloy_primitives::address;
+    use alloy_primitives::{address, Fixed};
     use core::str::FromStr;
 
     fn uint_test(s: &str, expected: Result<&str, ()>) {
@@ -913,6 +1085,65 @@ mod tests {
         assert!(DynSolType::Uint(256).coerce_str("1gwei 1 gwei").is_err());
     }
 
+    #[test]
+    fn coerce_fixed() {
+        let fixed = |s: &str| DynSolType::Fixed(128, 18).coerce_str(s);
+        let raw = |x: i128| DynSolValue::Fixed(I256::try_from(x).unwrap(), 128, 18);
+        assert_eq!(fixed("0").unwrap(), raw(0));
+        assert_eq!(fixed("1.5").unwrap(), raw(1_500_000_000_000_000_000));
+        assert_eq!(fixed("-1.5").unwrap(), raw(-1_500_000_000_000_000_000));
+        assert_eq!(fixed("+0.000000000000000001").unwrap(), raw(1));
+        assert_eq!(fixed("42.000").unwrap(), raw(42_000_000_000_000_000_000));
+        assert_eq!(
+            fixed("-170141183460469231731.687303715884105728").unwrap(),
+            DynSolValue::Fixed(I256::try_from(i128::MIN).unwrap(), 128, 18)
+        );
+        assert!(fixed("170141183460469231731.687303715884105728").is_err());
+        assert!(fixed("0.0000000000000000001").is_err());
+        assert!(fixed("1.").is_err());
+        assert!(fixed(".1").is_err());
+        assert!(fixed("0x1").is_err());
+        assert!(fixed("1 ether").is_err());
+
+        let ufixed = |s: &str| DynSolType::Ufixed(8, 1).coerce_str(s);
+        assert_eq!(ufixed("25.5").unwrap(), DynSolValue::Ufixed(U256::from(255), 8, 1));
+        assert_eq!(ufixed("0").unwrap(), DynSolValue::Ufixed(U256::ZERO, 8, 1));
+        assert!(ufixed("25.6").is_err());
+        assert!(ufixed("-1").is_err());
+        assert!(ufixed("0.05").is_err());
+
+        let fixed8 = |s: &str| DynSolType::Fixed(8, 1).coerce_str(s);
+        assert_eq!(
+            fixed8("-12.8").unwrap(),
+            DynSolValue::Fixed(I256::try_from(-128).unwrap(), 8, 1)
+        );
+        assert_eq!(fixed8("12.7").unwrap(), DynSolValue::Fixed(I256::try_from(127).unwrap(), 8, 1));
+        assert!(fixed8("-12.9").is_err());
+        assert!(fixed8("-25.5").is_err());
+        assert!(fixed8("12.8").is_err());
+
+        // Same grammar as `Fixed::from_str`.
+        for s in ["1.5", "-1.5", "+2", "0.000000000000000001", "1.", ".1", "-.1", "", ".", "1e3"] {
+            assert_eq!(
+                fixed(s).ok(),
+                s.parse::<Fixed<256, 4, 18>>().ok().map(|x| DynSolValue::Fixed(
+                    x.into_raw(),
+                    128,
+                    18
+                )),
+                "{s:?}"
+            );
+        }
+
+        assert_eq!(
+            DynSolType::parse("ufixed16x2[]").unwrap().coerce_str("[1.5, 0.01]").unwrap(),
+            DynSolValue::Array(vec![
+                DynSolValue::Ufixed(U256::from(150), 16, 2),
+                DynSolValue::Ufixed(U256::from(1), 16, 2),
+            ])
+        );
+    }
+
     #[test]
     fn coerce_fixed_bytes() {
         let mk_word = |sl: &[u8]| {
@@ -1369,4 +1600,68 @@ mod tests {
         uint_test("1ex", Err(()));
         uint_test("1e", Err(()));
     }
+
+    #[test]
+    #[cfg(feature = "eip712")]
+    fn coerce_struct_fields() {
+        let transfer = DynSolType::CustomStruct {
+            name: "Transfer".to_string(),
+            prop_names: vec!["amount".to_string(), "to".to_string(), "memo".to_string()],
+            tuple: vec![DynSolType::Uint(256), DynSolType::Address, DynSolType::String],
+        };
+        let batch = DynSolType::CustomStruct {
+            name: "Batch".to_string(),
+            prop_names: vec!["transfers".to_string(), "ok".to_string()],
+            tuple: vec![DynSolType::Array(Box::new(transfer.clone())), DynSolType::Bool],
+        };
+
+        let to = address!("0x0000000000000000000000000000000000001234");
+        let expected = DynSolValue::Tuple(vec![
+            DynSolValue::Uint(U256::from(10).pow(U256::from(18)), 256),
+            DynSolValue::Address(to),
+            DynSolValue::String("hi there".to_string()),
+        ]);
+        for s in [
+            "{amount: 1 ether, to: 0x0000000000000000000000000000000000001234, memo: hi there}",
+            "{ memo: \"hi there\", \"to\":0x0000000000000000000000000000000000001234, amount:1e18 }",
+            "(1 ether, 0x0000000000000000000000000000000000001234, hi there)",
+        ] {
+            assert_eq!(transfer.coerce_str(s).unwrap(), expected, "{s}");
+        }
+
+        let value = batch
+            .coerce_str(
+                "{ok: true, transfers: [{to: 0x0000000000000000000000000000000000001234, \
+                 amount: 1 ether, memo: hi there}, (1e18, 0x0000000000000000000000000000000000001234, hi there)]}",
+            )
+            .unwrap();
+        assert_eq!(
+            value,
+            DynSolValue::Tuple(vec![
+                DynSolValue::Array(vec![expected.clone(), expected]),
+                DynSolValue::Bool(true),
+            ])
+        );
+        assert!(value.matches(&batch));
+
+        let e = transfer.coerce_str("{amount: 1, to: 0x0000000000000000000000000000000000001234}");
+        assert_error_contains(&e.unwrap_err(), &Error::MissingField("memo".into()).to_string());
+        let e = transfer.coerce_str("{amount: 1, value: 2}");
+        assert_error_contains(&e.unwrap_err(), &Error::UnknownField("value".into()).to_string());
+        let e = transfer.coerce_str("{amount: 1, amount: 2}");
+        assert_error_contains(&e.unwrap_err(), &Error::DuplicateField("amount".into()).to_string());
+        assert!(transfer.coerce_str("{amount 1}").is_err());
+        assert!(transfer.coerce_str("{amount: 1,}").is_err());
+        assert!(DynSolType::Tuple(vec![DynSolType::Bool]).coerce_str("{a: true}").is_err());
+
+        let malformed = DynSolType::CustomStruct {
+            name: "Transfer".to_string(),
+            prop_names: vec!["amount".to_string(), "to".to_string(), "memo".to_string()],
+            tuple: vec![DynSolType::Uint(256)],
+        };
+        let e = malformed.coerce_str("{memo: hi}");
+        assert_error_contains(&e.unwrap_err(), &Error::FieldCountMismatch(3, 1).to_string());
+        let e = malformed.coerce_str("{amount: 1}");
+        assert_error_contains(&e.unwrap_err(), &Error::FieldCountMismatch(3, 1).to_string());
+    }
 }
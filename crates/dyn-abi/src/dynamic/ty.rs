@@ -84,6 +84,10 @@ pub enum DynSolType {
     Int(usize),
     /// Unsigned Integer.
     Uint(usize),
+    /// Signed fixed-point number, with the number of bits and decimals.
+    Fixed(usize, usize),
+    /// Unsigned fixed-point number, with the number of bits and decimals.
+    Ufixed(usize, usize),
     /// Fixed-size bytes, up to 32.
     FixedBytes(usize),
     /// Address.
@@ -97,11 +101,11 @@ pub enum DynSolType {
     String,
 
     /// Dynamically sized array.
-    Array(Box<DynSolType>),
+    Array(Box<Self>),
     /// Fixed-sized array.
-    FixedArray(Box<DynSolType>, usize),
+    FixedArray(Box<Self>, usize),
     /// Tuple.
-    Tuple(Vec<DynSolType>),
+    Tuple(Vec<Self>),
 
     #[cfg(feature = "seismic")]
     /// Seismic shielded address
@@ -129,7 +133,7 @@ pub enum DynSolType {
         /// Prop names.
         prop_names: Vec<String>,
         /// Inner types.
-        tuple: Vec<DynSolType>,
+        tuple: Vec<Self>,
     },
 }
 
@@ -179,6 +183,8 @@ impl DynSolType {
             Self::Bool
             | Self::Int(_)
             | Self::Uint(_)
+            | Self::Fixed(..)
+            | Self::Ufixed(..)
             | Self::FixedBytes(_)
             | Self::Address
             | Self::Function
@@ -249,6 +255,12 @@ impl DynSolType {
             Self::Bool => matches!(value, DynSolValue::Bool(_)),
             Self::Int(size) => matches!(value, DynSolValue::Int(_, s) if s == size),
             Self::Uint(size) => matches!(value, DynSolValue::Uint(_, s) if s == size),
+            Self::Fixed(m, n) => {
+                matches!(value, DynSolValue::Fixed(_, vm, vn) if (vm, vn) == (m, n))
+            }
+            Self::Ufixed(m, n) => {
+                matches!(value, DynSolValue::Ufixed(_, vm, vn) if (vm, vn) == (m, n))
+            }
             Self::FixedBytes(size) => matches!(value, DynSolValue::FixedBytes(_, s) if s == size),
             Self::Address => matches!(value, DynSolValue::Address(_)),
             Self::Function => matches!(value, DynSolValue::Function(_)),
@@ -309,6 +321,14 @@ impl DynSolType {
                 Ok(DynSolValue::Uint(sol_data::Uint::<256>::detokenize(word.into()), *size))
             }
 
+            (&Self::Fixed(m, n), DynToken::Word(word)) => {
+                Ok(DynSolValue::Fixed(sol_data::Int::<256>::detokenize(word.into()), m, n))
+            }
+
+            (&Self::Ufixed(m, n), DynToken::Word(word)) => {
+                Ok(DynSolValue::Ufixed(sol_data::Uint::<256>::detokenize(word.into()), m, n))
+            }
+
             (Self::FixedBytes(size), DynToken::Word(word)) => Ok(DynSolValue::FixedBytes(
                 sol_data::FixedBytes::<32>::detokenize(word.into()),
                 *size,
@@ -447,6 +467,13 @@ impl DynSolType {
                 out.push_str(itoa::Buffer::new().format(*size));
             }
 
+            Self::Fixed(m, n) | Self::Ufixed(m, n) => {
+                out.push_str(if matches!(self, Self::Fixed(..)) { "fixed" } else { "ufixed" });
+                out.push_str(itoa::Buffer::new().format(*m));
+                out.push('x');
+                out.push_str(itoa::Buffer::new().format(*n));
+            }
+
             as_tuple!(Self tuple) => {
                 out.push('(');
                 for (i, val) in tuple.iter().enumerate() {
@@ -511,6 +538,10 @@ impl DynSolType {
             | Self::Uint(_) // 4 + 3
             => 8,
 
+            | Self::Fixed(..) // 5 + 3 + 1 + 2
+            | Self::Ufixed(..) // 6 + 3 + 1 + 2
+            => 12,
+
             | Self::Array(t) // t + 2
             | Self::FixedArray(t, _) // t + 2 + log10(len)
             => t.sol_type_name_capacity() + 8,
@@ -558,7 +589,9 @@ impl DynSolType {
             | Self::Bool
             | Self::FixedBytes(_)
             | Self::Int(_)
-            | Self::Uint(_) => DynToken::Word(Word::ZERO),
+            | Self::Uint(_)
+            | Self::Fixed(..)
+            | Self::Ufixed(..) => DynToken::Word(Word::ZERO),
 
             Self::Bytes | Self::String => DynToken::PackedSeq(&[]),
 
@@ -591,7 +624,9 @@ impl DynSolType {
             | Self::Bool
             | Self::FixedBytes(_)
             | Self::Int(_)
-            | Self::Uint(_) => self.detokenize(DynToken::Word(topic)).unwrap(),
+            | Self::Uint(_)
+            | Self::Fixed(..)
+            | Self::Ufixed(..) => self.detokenize(DynToken::Word(topic)).unwrap(),
             #[cfg(feature = "seismic")]
             Self::Saddress | Self::Sint(_) | Self::Suint(_) | Self::Sbool | Self::Sbytes(_) => {
                 self.detokenize(DynToken::Word(topic)).unwrap()
@@ -656,6 +691,8 @@ impl DynSolType {
             Self::Bool |
             Self::Int(_) |
             Self::Uint(_) |
+            Self::Fixed(..) |
+            Self::Ufixed(..) |
             Self::FixedBytes(_) |
             Self::Address |
             Self::Function |
@@ -673,6 +710,17 @@ impl DynSolType {
         }
     }
 
+    /// Returns whether this type is ABI-encoded in the tail of a sequence,
+    /// i.e. whether it is referenced by an offset word in the head.
+    pub fn is_dynamic(&self) -> bool {
+        match self {
+            Self::Bytes | Self::String | Self::Array(_) => true,
+            Self::FixedArray(inner, _) => inner.is_dynamic(),
+            as_tuple!(Self tuple) => tuple.iter().any(Self::is_dynamic),
+            _ => false,
+        }
+    }
+
     #[inline]
     #[cfg_attr(debug_assertions, track_caller)]
     pub(crate) fn abi_decode_inner<'d, F>(
@@ -70,6 +70,12 @@ pub enum DynSolValue {
     Int(I256, usize),
     /// An unsigned integer. The second parameter is the number of bits, not bytes.
     Uint(U256, usize),
+    /// A signed fixed-point number, as its raw integer value (the number
+    /// multiplied by `10^N`), followed by the number of bits and decimals.
+    Fixed(I256, usize, usize),
+    /// An unsigned fixed-point number, as its raw integer value (the number
+    /// multiplied by `10^N`), followed by the number of bits and decimals.
+    Ufixed(U256, usize, usize),
     /// A fixed-length byte array. The second parameter is the number of bytes.
     FixedBytes(Word, usize),
     /// An address.
@@ -83,11 +89,11 @@ pub enum DynSolValue {
     String(String),
 
     /// A dynamically-sized array of values.
-    Array(Vec<DynSolValue>),
+    Array(Vec<Self>),
     /// A fixed-size array of values.
-    FixedArray(Vec<DynSolValue>),
+    FixedArray(Vec<Self>),
     /// A tuple of values.
-    Tuple(Vec<DynSolValue>),
+    Tuple(Vec<Self>),
 
     #[cfg(feature = "seismic")]
     /// A seismic shielded address. Always 32 bytes
@@ -115,7 +121,7 @@ pub enum DynSolValue {
         /// The struct's prop names, in declaration order.
         prop_names: Vec<String>,
         /// The inner types.
-        tuple: Vec<DynSolValue>,
+        tuple: Vec<Self>,
     },
 }
 
@@ -225,6 +231,8 @@ impl DynSolValue {
             Self::FixedBytes(_, size) => DynSolType::FixedBytes(*size),
             Self::Int(_, size) => DynSolType::Int(*size),
             Self::Uint(_, size) => DynSolType::Uint(*size),
+            Self::Fixed(_, m, n) => DynSolType::Fixed(*m, *n),
+            Self::Ufixed(_, m, n) => DynSolType::Ufixed(*m, *n),
             Self::String(_) => DynSolType::String,
             Self::Tuple(inner) => {
                 return inner
@@ -296,6 +304,13 @@ impl DynSolValue {
                 out.push_str(itoa::Buffer::new().format(*size));
             }
 
+            Self::Fixed(_, m, n) | Self::Ufixed(_, m, n) => {
+                out.push_str(if matches!(self, Self::Fixed(..)) { "fixed" } else { "ufixed" });
+                out.push_str(itoa::Buffer::new().format(*m));
+                out.push('x');
+                out.push_str(itoa::Buffer::new().format(*n));
+            }
+
             Self::Array(values) | Self::FixedArray(values) => {
                 // SAFETY: checked in `sol_type_name_capacity`
                 debug_assert!(!values.is_empty());
@@ -362,6 +377,8 @@ impl DynSolValue {
             | Self::Bytes(_)
             | Self::String(_) => Some(8),
 
+            Self::Fixed(..) | Self::Ufixed(..) => Some(12),
+
             Self::Array(t) | Self::FixedArray(t) => {
                 t.first().and_then(Self::sol_type_name_capacity).map(|x| x + 8)
             }
@@ -419,6 +436,8 @@ impl DynSolValue {
             Self::Bool(_)
                 | Self::Int(..)
                 | Self::Uint(..)
+                | Self::Fixed(..)
+                | Self::Ufixed(..)
                 | Self::FixedBytes(..)
                 | Self::Address(_)
         )
@@ -431,6 +450,8 @@ impl DynSolValue {
             Self::Bool(b) => Some(Word::with_last_byte(b as u8)),
             Self::Int(i, _) => Some(i.into()),
             Self::Uint(u, _) => Some(u.into()),
+            Self::Fixed(i, ..) => Some(i.into()),
+            Self::Ufixed(u, ..) => Some(u.into()),
             Self::FixedBytes(w, _) => Some(w),
             Self::Address(a) => Some(a.into_word()),
             Self::Function(f) => Some(f.into_word()),
@@ -624,6 +645,8 @@ impl DynSolValue {
             | Self::Bool(_)
             | Self::Int(..)
             | Self::Uint(..)
+            | Self::Fixed(..)
+            | Self::Ufixed(..)
             | Self::FixedBytes(..) => false,
             Self::Bytes(_) | Self::String(_) | Self::Array(_) => true,
             as_fixed_seq!(tuple) => tuple.iter().any(Self::is_dynamic),
@@ -686,7 +709,9 @@ impl DynSolValue {
             | Self::Bool(_)
             | Self::FixedBytes(..)
             | Self::Int(..)
-            | Self::Uint(..) => 0,
+            | Self::Uint(..)
+            | Self::Fixed(..)
+            | Self::Ufixed(..) => 0,
 
             // `self.as_packed_seq()`
             // 1 for the length, then the body padded to the next word.
@@ -737,7 +762,9 @@ impl DynSolValue {
             | Self::Bool(_)
             | Self::FixedBytes(..)
             | Self::Int(..)
-            | Self::Uint(..) => enc.append_word(unsafe { self.as_word().unwrap_unchecked() }),
+            | Self::Uint(..)
+            | Self::Fixed(..)
+            | Self::Ufixed(..) => enc.append_word(unsafe { self.as_word().unwrap_unchecked() }),
 
             Self::String(_) | Self::Bytes(_) | Self::Array(_) => enc.append_indirection(),
 
@@ -768,7 +795,9 @@ impl DynSolValue {
             | Self::Bool(_)
             | Self::FixedBytes(..)
             | Self::Int(..)
-            | Self::Uint(..) => {}
+            | Self::Uint(..)
+            | Self::Fixed(..)
+            | Self::Ufixed(..) => {}
 
             Self::String(string) => enc.append_packed_seq(string.as_bytes()),
             Self::Bytes(bytes) => enc.append_packed_seq(bytes),
@@ -827,6 +856,16 @@ impl DynSolValue {
                 let start = 32usize.saturating_sub(byte_size);
                 buf.extend_from_slice(&num.to_be_bytes::<32>()[start..]);
             }
+            Self::Fixed(num, size, _) => {
+                let byte_size = *size / 8;
+                let start = 32usize.saturating_sub(byte_size);
+                buf.extend_from_slice(&num.to_be_bytes::<32>()[start..]);
+            }
+            Self::Ufixed(num, size, _) => {
+                let byte_size = *size / 8;
+                let start = 32usize.saturating_sub(byte_size);
+                buf.extend_from_slice(&num.to_be_bytes::<32>()[start..]);
+            }
             Self::FixedArray(inner) | Self::Array(inner) => {
                 for val in inner {
                     // Array elements are left-padded to 32 bytes.
@@ -843,7 +882,7 @@ impl DynSolValue {
                 }
             }
             #[cfg(feature = "seismic")]
-            Self::Saddress(SAddress(address)) => buf.extend_from_slice(&address.as_slice()),
+            Self::Saddress(SAddress(address)) => buf.extend_from_slice(address.as_slice()),
             #[cfg(feature = "seismic")]
             Self::Sint(SInt(num), size) => {
                 let byte_size = *size / 8;
@@ -873,7 +912,10 @@ impl DynSolValue {
             Self::String(s) => s.len(),
             Self::Bytes(b) => b.len(),
             Self::FixedBytes(_, size) => (*size).min(32),
-            Self::Int(_, size) | Self::Uint(_, size) => (size / 8).min(32),
+            Self::Int(_, size)
+            | Self::Uint(_, size)
+            | Self::Fixed(_, size, _)
+            | Self::Ufixed(_, size, _) => (size / 8).min(32),
             Self::FixedArray(inner) | Self::Array(inner) => {
                 inner.iter().map(|v| v.abi_packed_encoded_size().max(32)).sum()
             }
@@ -901,6 +943,8 @@ impl DynSolValue {
             Self::FixedBytes(buf, _) => (*buf).into(),
             Self::Int(int, _) => int.to_be_bytes::<32>().into(),
             Self::Uint(uint, _) => uint.to_be_bytes::<32>().into(),
+            Self::Fixed(int, ..) => int.to_be_bytes::<32>().into(),
+            Self::Ufixed(uint, ..) => uint.to_be_bytes::<32>().into(),
             Self::String(s) => DynToken::PackedSeq(s.as_bytes()),
             Self::Array(t) => DynToken::from_dyn_seq(t),
             as_fixed_seq!(t) => DynToken::from_fixed_seq(t),
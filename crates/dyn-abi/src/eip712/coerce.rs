@@ -1,4 +1,4 @@
-use crate::{DynSolType, DynSolValue, Error, Result, Word};
+use crate::{DynSolType, DynSolValue, Eip712Version, Error, Result, Word};
 use alloc::{
     string::{String, ToString},
     vec::Vec,
@@ -14,31 +14,66 @@ use alloy_sol_types::Sbool;
 impl DynSolType {
     /// Coerce a [`serde_json::Value`] to a [`DynSolValue`] via this type.
     pub fn coerce_json(&self, value: &serde_json::Value) -> Result<DynSolValue> {
+        self.coerce_json_inner(value, None)
+    }
+
+    /// Coerce a [`serde_json::Value`] to a [`DynSolValue`] via this type,
+    /// following the given EIP-712 signing [version](Eip712Version).
+    ///
+    /// Unlike [`coerce_json`](Self::coerce_json), `bytes` and `bytesN` values
+    /// may also be given as strings which are not `0x`-prefixed hex, which are
+    /// UTF-8 encoded, and `bytes` values as numbers, which are big-endian
+    /// encoded. Arrays are not supported by [`V3`](Eip712Version::V3).
+    pub fn coerce_json_with_version(
+        &self,
+        value: &serde_json::Value,
+        version: Eip712Version,
+    ) -> Result<DynSolValue> {
+        self.coerce_json_inner(value, Some(version))
+    }
+
+    fn coerce_json_inner(
+        &self,
+        value: &serde_json::Value,
+        version: Option<Eip712Version>,
+    ) -> Result<DynSolValue> {
         let err = || Error::eip712_coerce(self, value);
         match self {
+            Self::Bytes if version.is_some() => {
+                eip712_bytes(value).ok_or_else(err).map(DynSolValue::Bytes)
+            }
+            &Self::FixedBytes(n) if version.is_some() => {
+                eip712_fixed_bytes(n, value).ok_or_else(err).map(|x| DynSolValue::FixedBytes(x, n))
+            }
+            Self::Array(_) | Self::FixedArray(..) if version == Some(Eip712Version::V3) => {
+                Err(Error::unsupported_eip712_type(&self.to_string(), Eip712Version::V3))
+            }
+
             Self::Bool
             | Self::Int(_)
             | Self::Uint(_)
+            | Self::Fixed(..)
+            | Self::Ufixed(..)
             | Self::FixedBytes(_)
             | Self::Address
             | Self::Function
             | Self::String
             | Self::Bytes => self.coerce_json_simple(value).ok_or_else(err),
 
-            Self::Array(inner) => array(inner, value)
+            Self::Array(inner) => array(inner, value, version)
                 .ok_or_else(err)
                 .and_then(core::convert::identity)
                 .map(DynSolValue::Array),
-            Self::FixedArray(inner, n) => fixed_array(inner, *n, value)
+            Self::FixedArray(inner, n) => fixed_array(inner, *n, value, version)
                 .ok_or_else(err)
                 .and_then(core::convert::identity)
                 .map(DynSolValue::FixedArray),
-            Self::Tuple(inner) => tuple(inner, value)
+            Self::Tuple(inner) => tuple(inner, value, version)
                 .ok_or_else(err)
                 .and_then(core::convert::identity)
                 .map(DynSolValue::Tuple),
             Self::CustomStruct { name, prop_names, tuple } => {
-                custom_struct(name, prop_names, tuple, value)
+                custom_struct(name, prop_names, tuple, value, version)
             }
             #[cfg(feature = "seismic")]
             Self::Saddress | Self::Suint(_) | Self::Sint(_) | Self::Sbool | Self::Sbytes(_) => {
@@ -52,6 +87,7 @@ impl DynSolType {
             Self::Bool => bool(value).map(DynSolValue::Bool),
             &Self::Int(n) => int(n, value).map(|x| DynSolValue::Int(x, n)),
             &Self::Uint(n) => uint(n, value).map(|x| DynSolValue::Uint(x, n)),
+            Self::Fixed(..) | Self::Ufixed(..) => fixed(self, value),
             &Self::FixedBytes(n) => fixed_bytes(n, value).map(|x| DynSolValue::FixedBytes(x, n)),
             Self::Address => address(value).map(DynSolValue::Address),
             Self::Function => function(value).map(DynSolValue::Function),
@@ -72,6 +108,123 @@ impl DynSolType {
     }
 }
 
+impl DynSolValue {
+    /// Converts this value to a [`serde_json::Value`] that can be coerced
+    /// back with [`DynSolType::coerce_json`].
+    ///
+    /// Integers and fixed-point numbers are encoded as decimal strings, and
+    /// byte arrays, addresses
+    /// and function pointers as `0x`-prefixed hex strings; these are also
+    /// accepted by [`DynSolType::coerce_str`]. Arrays and tuples are encoded
+    /// as JSON arrays, and structs as JSON objects keyed by field name.
+    ///
+    /// ABI-decoded values contain plain tuples instead of structs. If
+    /// `ty_hint` is given, tuples whose type is a
+    /// [`CustomStruct`](DynSolType::CustomStruct) in the hint are encoded as
+    /// objects too. Parts of the hint that do not match the value are ignored.
+    ///
+    /// Shielded values are encoded like their unshielded counterparts.
+    ///
+    /// Struct fields without a matching entry in `prop_names` are omitted.
+    pub fn to_json(&self, ty_hint: Option<&DynSolType>) -> serde_json::Value {
+        use serde_json::Value;
+
+        let hex = |bytes: &[u8]| Value::String(hex::encode_prefixed(bytes));
+        let seq = |values: &[Self], hint: Option<&DynSolType>| {
+            Value::Array(values.iter().map(|v| v.to_json(hint)).collect())
+        };
+        let object = |prop_names: &[String], values: &[Self], hints: Option<&[DynSolType]>| {
+            let hints = hints.filter(|hints| hints.len() == values.len());
+            Value::Object(
+                core::iter::zip(prop_names, values)
+                    .enumerate()
+                    .map(|(i, (name, v))| (name.clone(), v.to_json(hints.map(|h| &h[i]))))
+                    .collect(),
+            )
+        };
+
+        match self {
+            Self::Bool(b) => Value::Bool(*b),
+            Self::Int(i, _) => Value::String(i.to_dec_string()),
+            Self::Uint(u, _) => Value::String(u.to_string()),
+            Self::Fixed(i, _, n) => {
+                let (sign, abs) = i.into_sign_and_abs();
+                Value::String(fixed_to_string(sign.is_negative(), abs, *n))
+            }
+            Self::Ufixed(u, _, n) => Value::String(fixed_to_string(false, *u, *n)),
+            Self::FixedBytes(word, size) => hex(&word[..*size]),
+            Self::Address(address) => Value::String(address.to_checksum(None)),
+            Self::Function(function) => hex(function.as_slice()),
+            Self::Bytes(bytes) => hex(bytes),
+            Self::String(s) => Value::String(s.clone()),
+            Self::Array(values) | Self::FixedArray(values) => {
+                let hint = match ty_hint {
+                    Some(DynSolType::Array(inner) | DynSolType::FixedArray(inner, _)) => {
+                        Some(&**inner)
+                    }
+                    _ => None,
+                };
+                seq(values, hint)
+            }
+            Self::Tuple(values) => match ty_hint {
+                Some(DynSolType::CustomStruct { prop_names, tuple, .. })
+                    if prop_names.len() == values.len() =>
+                {
+                    object(prop_names, values, Some(tuple))
+                }
+                Some(DynSolType::Tuple(hints)) if hints.len() == values.len() => Value::Array(
+                    core::iter::zip(values, hints).map(|(v, h)| v.to_json(Some(h))).collect(),
+                ),
+                _ => seq(values, None),
+            },
+            Self::CustomStruct { prop_names, tuple, .. } => {
+                let hints = match ty_hint {
+                    Some(DynSolType::CustomStruct { tuple, .. }) => Some(tuple.as_slice()),
+                    _ => None,
+                };
+                object(prop_names, tuple, hints)
+            }
+            #[cfg(feature = "seismic")]
+            Self::Saddress(address) => Value::String(address.0.to_checksum(None)),
+            #[cfg(feature = "seismic")]
+            Self::Sint(i, _) => Value::String(i.0.to_dec_string()),
+            #[cfg(feature = "seismic")]
+            Self::Suint(u, _) => Value::String(u.0.to_string()),
+            #[cfg(feature = "seismic")]
+            Self::Sbool(b) => Value::Bool(b.0),
+            #[cfg(feature = "seismic")]
+            Self::Sbytes(word, size) => hex(&word[..*size]),
+        }
+    }
+}
+
+/// Formats the fixed-point value `abs / 10^decimals` without trailing
+/// fractional zeros, like the [`Fixed`] and [`UFixed`] `Display` impls.
+///
+/// [`Fixed`]: alloy_primitives::Fixed
+/// [`UFixed`]: alloy_primitives::UFixed
+fn fixed_to_string(negative: bool, abs: U256, decimals: usize) -> String {
+    let digits = abs.to_string();
+    let (int, fract) = if digits.len() > decimals {
+        digits.split_at(digits.len() - decimals)
+    } else {
+        ("0", digits.as_str())
+    };
+    let fract = fract.trim_end_matches('0');
+
+    let mut s = String::with_capacity(decimals + digits.len() + 2);
+    if negative && !abs.is_zero() {
+        s.push('-');
+    }
+    s.push_str(int);
+    if !fract.is_empty() {
+        s.push('.');
+        s.extend(core::iter::repeat('0').take(decimals.saturating_sub(digits.len())));
+        s.push_str(fract);
+    }
+    s
+}
+
 fn bool(value: &serde_json::Value) -> Option<bool> {
     value.as_bool().or_else(|| value.as_str().and_then(|s| s.parse().ok()))
 }
@@ -96,6 +249,14 @@ fn uint(n: usize, value: &serde_json::Value) -> Option<U256> {
     .and_then(|x| (x.bit_len() <= n).then_some(x))
 }
 
+fn fixed(ty: &DynSolType, value: &serde_json::Value) -> Option<DynSolValue> {
+    match value {
+        serde_json::Value::String(s) => ty.coerce_str(s).ok(),
+        serde_json::Value::Number(n) => ty.coerce_str(&n.to_string()).ok(),
+        _ => None,
+    }
+}
+
 fn fixed_bytes(n: usize, value: &serde_json::Value) -> Option<Word> {
     if let Some(Ok(buf)) = value.as_str().map(hex::decode) {
         let mut word = Word::ZERO;
@@ -133,18 +294,60 @@ fn bytes(value: &serde_json::Value) -> Option<Vec<u8>> {
     Some(vec)
 }
 
-fn tuple(inner: &[DynSolType], value: &serde_json::Value) -> Option<Result<Vec<DynSolValue>>> {
+/// Byte arrays as accepted by `eth_signTypedData`.
+fn eip712_bytes(value: &serde_json::Value) -> Option<Vec<u8>> {
+    match value {
+        serde_json::Value::String(s) => Some(eip712_str_bytes(s)),
+        serde_json::Value::Number(n) => {
+            let n = n.as_u64()?;
+            let skip = (n.leading_zeros() as usize / 8).min(7);
+            Some(n.to_be_bytes()[skip..].to_vec())
+        }
+        _ => bytes(value),
+    }
+}
+
+fn eip712_fixed_bytes(n: usize, value: &serde_json::Value) -> Option<Word> {
+    let buf = eip712_str_bytes(value.as_str()?);
+    (buf.len() <= n).then(|| {
+        let mut word = Word::ZERO;
+        word[..buf.len()].copy_from_slice(&buf);
+        word
+    })
+}
+
+/// Decodes `0x`-prefixed hex strings, and UTF-8 encodes any other string.
+fn eip712_str_bytes(s: &str) -> Vec<u8> {
+    match s.strip_prefix("0x") {
+        Some(hex) if hex.bytes().all(|b| b.is_ascii_hexdigit()) => {
+            hex::decode(hex).unwrap_or_else(|_| s.as_bytes().to_vec())
+        }
+        _ => s.as_bytes().to_vec(),
+    }
+}
+
+fn tuple(
+    inner: &[DynSolType],
+    value: &serde_json::Value,
+    version: Option<Eip712Version>,
+) -> Option<Result<Vec<DynSolValue>>> {
     if let Some(arr) = value.as_array() {
         if inner.len() == arr.len() {
-            return Some(core::iter::zip(arr, inner).map(|(v, t)| t.coerce_json(v)).collect());
+            return Some(
+                core::iter::zip(arr, inner).map(|(v, t)| t.coerce_json_inner(v, version)).collect(),
+            );
         }
     }
     None
 }
 
-fn array(inner: &DynSolType, value: &serde_json::Value) -> Option<Result<Vec<DynSolValue>>> {
+fn array(
+    inner: &DynSolType,
+    value: &serde_json::Value,
+    version: Option<Eip712Version>,
+) -> Option<Result<Vec<DynSolValue>>> {
     if let Some(arr) = value.as_array() {
-        return Some(arr.iter().map(|v| inner.coerce_json(v)).collect());
+        return Some(arr.iter().map(|v| inner.coerce_json_inner(v, version)).collect());
     }
     None
 }
@@ -153,10 +356,11 @@ fn fixed_array(
     inner: &DynSolType,
     n: usize,
     value: &serde_json::Value,
+    version: Option<Eip712Version>,
 ) -> Option<Result<Vec<DynSolValue>>> {
     if let Some(arr) = value.as_array() {
         if arr.len() == n {
-            return Some(arr.iter().map(|v| inner.coerce_json(v)).collect());
+            return Some(arr.iter().map(|v| inner.coerce_json_inner(v, version)).collect());
         }
     }
     None
@@ -167,12 +371,13 @@ pub(crate) fn custom_struct(
     prop_names: &[String],
     inner: &[DynSolType],
     value: &serde_json::Value,
+    version: Option<Eip712Version>,
 ) -> Result<DynSolValue> {
     if let Some(map) = value.as_object() {
         let mut tuple = vec![];
         for (name, ty) in core::iter::zip(prop_names, inner) {
             if let Some(v) = map.get(name) {
-                tuple.push(ty.coerce_json(v)?);
+                tuple.push(ty.coerce_json_inner(v, version)?);
             } else {
                 return Err(Error::eip712_coerce(
                     &DynSolType::CustomStruct {
@@ -310,4 +515,132 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn to_json_roundtrip() {
+        let ty: DynSolType =
+            "(bool,int8,uint256,bytes4,address,function,bytes,string,uint16[],int256[2])"
+                .parse()
+                .unwrap();
+        let value = DynSolValue::Tuple(vec![
+            DynSolValue::Bool(true),
+            DynSolValue::Int(I256::try_from(-128).unwrap(), 8),
+            DynSolValue::Uint(U256::MAX, 256),
+            DynSolValue::FixedBytes(Word::right_padding_from(&[1, 2, 3, 4]), 4),
+            DynSolValue::Address(Address::repeat_byte(0xab)),
+            DynSolValue::Function(Function::repeat_byte(0x12)),
+            DynSolValue::Bytes(vec![0xde, 0xad]),
+            DynSolValue::String("hello".into()),
+            DynSolValue::Array(vec![DynSolValue::Uint(U256::from(1), 16)]),
+            DynSolValue::FixedArray(vec![
+                DynSolValue::Int(I256::MIN, 256),
+                DynSolValue::Int(I256::MAX, 256),
+            ]),
+        ]);
+
+        let j = value.to_json(None);
+        assert_eq!(j[1], json!("-128"));
+        assert_eq!(j[4], json!("0xABaBaBaBABabABabAbAbABAbABabababaBaBABaB"));
+        assert_eq!(j[6], json!("0xdead"));
+        assert_eq!(ty.coerce_json(&j).unwrap(), value);
+        assert_eq!(ty.coerce_json(&value.to_json(Some(&ty))).unwrap(), value);
+        for (value, ty) in core::iter::zip(value.as_tuple().unwrap(), ty.as_tuple().unwrap()) {
+            if let Some(s) = value.to_json(None).as_str() {
+                assert_eq!(ty.coerce_str(s).unwrap(), *value, "{s}");
+            }
+        }
+    }
+
+    #[test]
+    fn to_json_fixed() {
+        let ty: DynSolType = "(fixed128x18,ufixed8x1,fixed16x4)".parse().unwrap();
+        let value = DynSolValue::Tuple(vec![
+            DynSolValue::Fixed(I256::try_from(-1_500_000_000_000_000_000i128).unwrap(), 128, 18),
+            DynSolValue::Ufixed(U256::from(255), 8, 1),
+            DynSolValue::Fixed(I256::try_from(-5).unwrap(), 16, 4),
+        ]);
+        let j = value.to_json(None);
+        assert_eq!(j, json!(["-1.5", "25.5", "-0.0005"]));
+        assert_eq!(ty.coerce_json(&j).unwrap(), value);
+        assert_eq!(ty.coerce_json(&json!([-1.5, 25.5, "-0.0005"])).unwrap(), value);
+        assert!(ty.coerce_json(&json!(["-1.5", 25.6, "-0.0005"])).is_err());
+
+        let ufixed = |raw: u64, n| DynSolValue::Ufixed(U256::from(raw), 256, n).to_json(None);
+        assert_eq!(ufixed(1, 80), json!(format!("0.{}1", "0".repeat(79))));
+        assert_eq!(ufixed(1200, 0), json!("1200"));
+        for raw in [0, 1, 10, 123_456, u64::MAX] {
+            let expected = alloy_primitives::UFixed::<256, 4, 18>::from_raw(U256::from(raw));
+            assert_eq!(ufixed(raw, 18), json!(expected.to_string()));
+        }
+    }
+
+    #[test]
+    fn to_json_struct_hint() {
+        let person = DynSolType::CustomStruct {
+            name: "Person".to_owned(),
+            prop_names: vec!["name".to_string(), "wallet".to_string()],
+            tuple: vec![DynSolType::String, DynSolType::Address],
+        };
+        let ty = DynSolType::Array(Box::new(person.clone()));
+        let decoded = "(string,address)[]"
+            .parse::<DynSolType>()
+            .unwrap()
+            .abi_decode(
+                &DynSolValue::Array(vec![DynSolValue::Tuple(vec![
+                    DynSolValue::String("Bob".to_string()),
+                    DynSolValue::Address(Address::ZERO),
+                ])])
+                .abi_encode(),
+            )
+            .unwrap();
+
+        let j = decoded.to_json(Some(&ty));
+        assert_eq!(
+            j,
+            json!([{ "name": "Bob", "wallet": "0x0000000000000000000000000000000000000000" }])
+        );
+        let value = ty.coerce_json(&j).unwrap();
+        assert!(matches!(&value.as_array().unwrap()[0], DynSolValue::CustomStruct { .. }));
+        assert_eq!(value.to_json(None), j);
+
+        // Mismatched hints are ignored.
+        assert_eq!(decoded.to_json(Some(&person)), json!([["Bob", Address::ZERO]]));
+
+        // Fields without a name are omitted instead of panicking.
+        let short = DynSolValue::CustomStruct {
+            name: "Person".to_owned(),
+            prop_names: vec!["name".to_string()],
+            tuple: vec![
+                DynSolValue::String("Bob".to_string()),
+                DynSolValue::Address(Address::ZERO),
+            ],
+        };
+        assert_eq!(short.to_json(Some(&person)), json!({ "name": "Bob" }));
+    }
+
+    #[test]
+    #[cfg(feature = "seismic")]
+    fn to_json_shielded() {
+        let ty: DynSolType = "(saddress,sint64,suint256,sbool,sbytes8)".parse().unwrap();
+        let value = DynSolValue::Tuple(vec![
+            DynSolValue::Saddress(SAddress(Address::repeat_byte(1))),
+            DynSolValue::Sint(SInt(I256::try_from(-42).unwrap()), 64),
+            DynSolValue::Suint(SUInt(U256::from(42)), 256),
+            DynSolValue::Sbool(Sbool(true)),
+            DynSolValue::Sbytes(Word::right_padding_from(&[7; 8]), 8),
+        ]);
+
+        let j = value.to_json(None);
+        assert_eq!(
+            j,
+            json!([
+                "0x0101010101010101010101010101010101010101",
+                "-42",
+                "42",
+                true,
+                "0x0707070707070707"
+            ])
+        );
+        assert_eq!(ty.coerce_json(&j).unwrap(), value);
+    }
 }
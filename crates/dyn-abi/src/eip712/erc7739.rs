@@ -0,0 +1,301 @@
+//! [ERC-7739](https://eips.ethereum.org/EIPS/eip-7739) nested typed data.
+//!
+//! Smart accounts which validate signatures with
+//! [ERC-1271](https://eips.ethereum.org/EIPS/eip-1271) must make sure that a
+//! signature for one account cannot be replayed on another account owned by
+//! the same signer. ERC-7739 does this by nesting the signed data in a
+//! struct which contains the account's own EIP-712 domain, as returned by its
+//! [EIP-5267](https://eips.ethereum.org/EIPS/eip-5267) `eip712Domain()`:
+//!
+//! - typed data is wrapped in a `TypedDataSign` struct, signed in the
+//!   application's domain, with [`typed_data_sign`];
+//! - personal messages are wrapped in a `PersonalSign` struct, signed in the
+//!   account's domain, with [`personal_sign`].
+//!
+//! Both return regular [`TypedData`], which can be signed by wallets with
+//! `eth_signTypedData_v4`.
+
+use crate::{
+    eip712::{PropertyDef, Resolver, TypeDef, TypedData},
+    Error, Result,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use alloy_primitives::{hex, utils::EIP191_PREFIX};
+use alloy_sol_types::Eip712Domain;
+use serde_json::json;
+
+/// The name of the struct which wraps typed data.
+pub const TYPED_DATA_SIGN: &str = "TypedDataSign";
+
+/// The name of the struct which wraps personal messages.
+pub const PERSONAL_SIGN: &str = "PersonalSign";
+
+/// Wraps `contents` in a `TypedDataSign` struct, to be verified by the smart
+/// account with the given domain.
+///
+/// The returned typed data keeps the application's domain, and its message is
+/// the `contents` message together with the fields of `account_domain`. Unset
+/// account domain fields are encoded as zero values, like the ones returned
+/// by `eip712Domain()`.
+///
+/// Fails if the name of the `contents` struct is not a valid ERC-7739 contents
+/// name, or if the contents already contain a `TypedDataSign` struct.
+pub fn typed_data_sign(contents: &TypedData, account_domain: &Eip712Domain) -> Result<TypedData> {
+    let contents_name = contents.primary_type.as_str();
+    if !is_valid_contents_name(contents_name) {
+        return Err(Error::custom("invalid ERC-7739 contents name"));
+    }
+    if contents.resolver.contains_type_name(TYPED_DATA_SIGN) {
+        return Err(Error::custom("ERC-7739 contents already contain a TypedDataSign struct"));
+    }
+
+    let mut resolver = contents.resolver.clone();
+    resolver.ingest(TypeDef::new(
+        TYPED_DATA_SIGN,
+        [
+            (contents_name, "contents"),
+            ("string", "name"),
+            ("string", "version"),
+            ("uint256", "chainId"),
+            ("address", "verifyingContract"),
+            ("bytes32", "salt"),
+        ]
+        .into_iter()
+        .map(|(ty, name)| PropertyDef::new(ty, name))
+        .collect::<Result<_>>()?,
+    )?);
+
+    let message = json!({
+        "contents": contents.message,
+        "name": account_domain.name.as_deref().unwrap_or_default(),
+        "version": account_domain.version.as_deref().unwrap_or_default(),
+        "chainId": account_domain.chain_id.unwrap_or_default().to_string(),
+        "verifyingContract": account_domain.verifying_contract.unwrap_or_default(),
+        "salt": account_domain.salt.unwrap_or_default(),
+    });
+
+    Ok(TypedData {
+        domain: contents.domain.clone(),
+        resolver,
+        primary_type: TYPED_DATA_SIGN.into(),
+        message,
+    })
+}
+
+/// Wraps a personal message in a `PersonalSign` struct, to be verified by the
+/// smart account with the given domain.
+///
+/// The message is prefixed as in [EIP-191](https://eips.ethereum.org/EIPS/eip-191)
+/// personal messages.
+pub fn personal_sign(message: impl AsRef<[u8]>, account_domain: &Eip712Domain) -> TypedData {
+    let message = message.as_ref();
+    let mut prefixed = Vec::from(EIP191_PREFIX.as_bytes());
+    prefixed.extend_from_slice(message.len().to_string().as_bytes());
+    prefixed.extend_from_slice(message);
+
+    let mut resolver = Resolver::default();
+    resolver.ingest_string(account_domain.encode_type()).expect("domain string always valid");
+    resolver
+        .ingest_string("PersonalSign(bytes prefixed)")
+        .expect("PersonalSign string always valid");
+
+    TypedData {
+        domain: account_domain.clone(),
+        resolver,
+        primary_type: PERSONAL_SIGN.into(),
+        message: json!({ "prefixed": hex::encode_prefixed(prefixed) }),
+    }
+}
+
+/// Returns the `contentsDescription` of `contents`, as appended to ERC-7739
+/// signatures.
+///
+/// This is the `encodeType` of the `TypedDataSign` struct without the
+/// `TypedDataSign` struct itself, followed by the contents name if the
+/// contents type is not the first one.
+pub fn contents_description(contents: &TypedData) -> Result<String> {
+    let typed_data = typed_data_sign(contents, &Eip712Domain::default())?;
+    let encode_type = typed_data.encode_type()?;
+    let (_, contents_type) = encode_type.split_once(')').expect("encodeType contains a struct");
+
+    let contents_name = contents.primary_type.as_str();
+    let mut description = String::from(contents_type);
+    let implicit =
+        contents_type.strip_prefix(contents_name).is_some_and(|rest| rest.starts_with('('));
+    if !implicit {
+        description.push_str(contents_name);
+    }
+    Ok(description)
+}
+
+/// Encodes a signature over the `TypedDataSign` [wrapper](typed_data_sign) of
+/// `contents` in the ERC-7739 signature format:
+///
+/// `signature ‖ APP_DOMAIN_SEPARATOR ‖ contents ‖ contentsDescription ‖ uint16(contentsDescription.length)`
+///
+/// where `contents` is the `hashStruct` of the contents.
+pub fn encode_signature(signature: &[u8], contents: &TypedData) -> Result<Vec<u8>> {
+    let description = contents_description(contents)?;
+    let len = u16::try_from(description.len())
+        .map_err(|_| Error::custom("ERC-7739 contents description is too long"))?;
+
+    let mut out = Vec::with_capacity(signature.len() + 64 + description.len() + 2);
+    out.extend_from_slice(signature);
+    out.extend_from_slice(contents.domain.separator().as_slice());
+    out.extend_from_slice(contents.hash_struct()?.as_slice());
+    out.extend_from_slice(description.as_bytes());
+    out.extend_from_slice(&len.to_be_bytes());
+    Ok(out)
+}
+
+/// Contents names must be non-empty, must not start with a lowercase letter,
+/// and must not contain any of the characters which delimit `encodeType`.
+fn is_valid_contents_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_lowercase())
+        && !name.contains([' ', ',', '(', ')', '\0'])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, b256, eip191_hash_message, keccak256, B256, U256};
+    use alloy_sol_types::{eip712_domain, SolValue};
+
+    fn mail() -> TypedData {
+        serde_json::from_value(json!({
+            "types": {
+                "EIP712Domain": [
+                    { "name": "name", "type": "string" },
+                    { "name": "version", "type": "string" },
+                    { "name": "chainId", "type": "uint256" },
+                    { "name": "verifyingContract", "type": "address" }
+                ],
+                "Person": [
+                    { "name": "name", "type": "string" },
+                    { "name": "wallet", "type": "address" }
+                ],
+                "Mail": [
+                    { "name": "from", "type": "Person" },
+                    { "name": "to", "type": "Person" },
+                    { "name": "contents", "type": "string" }
+                ]
+            },
+            "primaryType": "Mail",
+            "domain": {
+                "name": "Ether Mail",
+                "version": "1",
+                "chainId": 1,
+                "verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+            },
+            "message": {
+                "from": { "name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826" },
+                "to": { "name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB" },
+                "contents": "Hello, Bob!"
+            }
+        }))
+        .unwrap()
+    }
+
+    const ACCOUNT: Eip712Domain = eip712_domain! {
+        name: "Account",
+        version: "1",
+        chain_id: 1,
+        verifying_contract: address!("0x1111111111111111111111111111111111111111"),
+    };
+
+    #[test]
+    fn typed_data_sign_mail() {
+        let contents = mail();
+        let typed_data = typed_data_sign(&contents, &ACCOUNT).unwrap();
+        assert_eq!(
+            typed_data.encode_type().unwrap(),
+            "TypedDataSign(Mail contents,string name,string version,uint256 chainId,address verifyingContract,bytes32 salt)\
+             Mail(Person from,Person to,string contents)Person(string name,address wallet)"
+        );
+
+        let hash_struct = keccak256(
+            (
+                typed_data.type_hash().unwrap(),
+                contents.hash_struct().unwrap(),
+                keccak256("Account"),
+                keccak256("1"),
+                U256::from(1),
+                ACCOUNT.verifying_contract.unwrap(),
+                B256::ZERO,
+            )
+                .abi_encode(),
+        );
+        assert_eq!(typed_data.hash_struct(), Ok(hash_struct));
+        assert_eq!(
+            typed_data.eip712_signing_hash(),
+            Ok(b256!("0xa4704f294b28eb593bb14cdee7e674e0b50c3bcfccf0ead508045318c4aefecf"))
+        );
+    }
+
+    #[test]
+    fn invalid_contents() {
+        let mut contents = mail();
+        contents.primary_type = "mail".into();
+        assert!(typed_data_sign(&contents, &ACCOUNT).is_err());
+
+        let wrapped = typed_data_sign(&mail(), &ACCOUNT).unwrap();
+        let mut contents = mail();
+        contents.resolver = wrapped.resolver;
+        assert!(typed_data_sign(&contents, &ACCOUNT).is_err());
+    }
+
+    #[test]
+    fn contents_descriptions() {
+        let contents = mail();
+        let description = contents_description(&contents).unwrap();
+        assert_eq!(
+            description,
+            "Mail(Person from,Person to,string contents)Person(string name,address wallet)"
+        );
+
+        let signature = [0xaa; 65];
+        let encoded = encode_signature(&signature, &contents).unwrap();
+        let (rest, len) = encoded.split_at(encoded.len() - 2);
+        assert_eq!(usize::from(u16::from_be_bytes([len[0], len[1]])), description.len());
+        assert_eq!(&rest[..65], signature);
+        assert_eq!(&rest[65..97], contents.domain.separator());
+        assert_eq!(&rest[97..129], contents.hash_struct().unwrap());
+        assert_eq!(&rest[129..], description.as_bytes());
+
+        // The contents name is appended if the contents type is not the first one.
+        let mut resolver = Resolver::default();
+        resolver
+            .ingest_string("Mail(Attachment file,string contents)Attachment(string name)")
+            .unwrap();
+        let contents = TypedData {
+            domain: Eip712Domain::default(),
+            resolver,
+            primary_type: "Mail".into(),
+            message: json!({}),
+        };
+        assert_eq!(
+            contents_description(&contents).unwrap(),
+            "Attachment(string name)Mail(Attachment file,string contents)Mail"
+        );
+    }
+
+    #[test]
+    fn personal_sign_message() {
+        let message = "Hello, world!";
+        let typed_data = personal_sign(message, &ACCOUNT);
+        assert_eq!(typed_data.encode_type().unwrap(), "PersonalSign(bytes prefixed)");
+
+        let hash_struct = keccak256(
+            (keccak256("PersonalSign(bytes prefixed)"), eip191_hash_message(message)).abi_encode(),
+        );
+        assert_eq!(typed_data.hash_struct(), Ok(hash_struct));
+        assert_eq!(
+            typed_data.eip712_signing_hash(),
+            Ok(b256!("0x0a23b25994be7192217657035a621bc5bd8e5082c71435ab1f940fb4770bbb45"))
+        );
+    }
+}
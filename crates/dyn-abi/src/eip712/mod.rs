@@ -9,8 +9,10 @@
 
 pub mod parser;
 
+pub mod erc7739;
+
 mod typed_data;
-pub use typed_data::{Eip712Types, TypedData};
+pub use typed_data::{Eip712Types, Eip712Version, TypedData};
 
 mod resolver;
 pub use resolver::{PropertyDef, Resolver, TypeDef};
@@ -1,6 +1,6 @@
 use crate::{
-    eip712::typed_data::Eip712Types, eip712_parser::EncodeType, DynSolType, DynSolValue, Error,
-    Result, Specifier,
+    eip712::typed_data::Eip712Types, eip712_parser::EncodeType, DynSolType, DynSolValue,
+    Eip712Version, Error, Result, Specifier,
 };
 use alloc::{
     borrow::ToOwned,
@@ -9,7 +9,7 @@ use alloc::{
     vec::Vec,
 };
 use alloy_primitives::{keccak256, B256};
-use alloy_sol_types::SolStruct;
+use alloy_sol_types::{Eip712ShieldedPolicy, SolStruct};
 use core::{cmp::Ordering, fmt};
 use parser::{RootType, TypeSpecifier, TypeStem};
 use serde::{Deserialize, Deserializer, Serialize};
@@ -212,6 +212,8 @@ pub struct Resolver {
     nodes: BTreeMap<String, TypeDef>,
     /// Edges from a type name to its dependencies.
     edges: BTreeMap<String, Vec<String>>,
+    /// How shielded members are named in `encodeType` strings.
+    shielded_policy: Eip712ShieldedPolicy,
 }
 
 impl Serialize for Resolver {
@@ -261,6 +263,23 @@ impl Resolver {
         resolver
     }
 
+    /// Returns the [policy](Eip712ShieldedPolicy) used to name shielded struct
+    /// members in `encodeType` strings, and therefore in type hashes.
+    #[inline]
+    pub const fn shielded_policy(&self) -> Eip712ShieldedPolicy {
+        self.shielded_policy
+    }
+
+    /// Sets the [policy](Eip712ShieldedPolicy) used to name shielded struct
+    /// members in `encodeType` strings, and therefore in type hashes.
+    ///
+    /// Shielded values are hashed like their public counterparts with either
+    /// policy.
+    #[inline]
+    pub fn set_shielded_policy(&mut self, policy: Eip712ShieldedPolicy) {
+        self.shielded_policy = policy;
+    }
+
     /// Detect cycles in the subgraph rooted at `type_name`
     fn detect_cycle<'a>(&'a self, type_name: &str, context: &mut DfsContext<'a>) -> bool {
         let ty = match self.nodes.get(type_name) {
@@ -331,7 +350,7 @@ impl Resolver {
         }
     }
 
-    // This function assumes that the graph is acyclic.
+    // This function does not check for cycles; each type is visited once.
     fn linearize_into<'a>(
         &'a self,
         resolution: &mut Vec<&'a TypeDef>,
@@ -420,7 +439,10 @@ impl Resolver {
     ///
     /// <https://eips.ethereum.org/EIPS/eip-712#definition-of-encodetype>
     pub fn encode_type(&self, name: &str) -> Result<String> {
-        let linear = self.linearize(name)?;
+        Ok(self.encode_linearized(&self.linearize(name)?))
+    }
+
+    fn encode_linearized(&self, linear: &[&TypeDef]) -> String {
         let first = linear.first().unwrap().eip712_encode_type();
 
         // Sort references by name (eip-712 encodeType spec)
@@ -428,10 +450,14 @@ impl Resolver {
             linear[1..].iter().map(|t| t.eip712_encode_type()).collect::<Vec<String>>();
         sorted_refs.sort();
 
-        Ok(sorted_refs.iter().fold(first, |mut acc, s| {
+        let encoded = sorted_refs.iter().fold(first, |mut acc, s| {
             acc.push_str(s);
             acc
-        }))
+        });
+        match self.shielded_policy {
+            Eip712ShieldedPolicy::Shielded => encoded,
+            policy => policy.encode_type(&encoded).into_owned(),
+        }
     }
 
     /// Compute the keccak256 hash of the EIP-712 `encodeType` string.
@@ -488,6 +514,121 @@ impl Resolver {
         Ok(keccak256(to_hash))
     }
 
+    /// Encode the data according to EIP-712 `encodeData` rules, following the
+    /// given signing [version](Eip712Version).
+    ///
+    /// Unlike [`encode_data`](Self::encode_data), this works directly on the
+    /// JSON value, and supports recursive types.
+    pub(crate) fn encode_data_with_version(
+        &self,
+        type_name: &str,
+        value: &serde_json::Value,
+        version: Eip712Version,
+    ) -> Result<Vec<u8>> {
+        let ty = self.nodes.get(type_name).ok_or_else(|| Error::missing_type(type_name))?;
+        let map = value.as_object().ok_or_else(|| Error::TypeMismatch {
+            expected: type_name.to_string(),
+            actual: value.to_string(),
+        })?;
+
+        let mut bytes = Vec::with_capacity(ty.props.len() * 32);
+        for prop in &ty.props {
+            let value = map.get(prop.name());
+            if value.is_none() && version == Eip712Version::V3 {
+                continue;
+            }
+            bytes.extend(self.eip712_data_word_with_version(
+                prop.name(),
+                prop.type_name(),
+                value,
+                version,
+            )?);
+        }
+        Ok(bytes)
+    }
+
+    /// Compute the EIP-712 `hashStruct` of a JSON value, following the given
+    /// signing [version](Eip712Version).
+    pub(crate) fn hash_struct_with_version(
+        &self,
+        type_name: &str,
+        value: &serde_json::Value,
+        version: Eip712Version,
+    ) -> Result<B256> {
+        // Recursive types are allowed here, as long as the value is finite.
+        let mut linear = vec![];
+        self.linearize_into(&mut linear, type_name.try_into()?)?;
+        let mut bytes = keccak256(self.encode_linearized(&linear)).to_vec();
+        bytes.extend(self.encode_data_with_version(type_name, value, version)?);
+        Ok(keccak256(bytes))
+    }
+
+    fn eip712_data_word_with_version(
+        &self,
+        name: &str,
+        type_name: &str,
+        value: Option<&serde_json::Value>,
+        version: Eip712Version,
+    ) -> Result<B256> {
+        let type_spec = TypeSpecifier::try_from(type_name)?;
+        if !type_spec.sizes.is_empty() {
+            if version == Eip712Version::V3 {
+                return Err(Error::unsupported_eip712_type(type_name, version));
+            }
+            let value = value.ok_or_else(|| Error::missing_field(name))?;
+            let arr = value.as_array().ok_or_else(|| Error::TypeMismatch {
+                expected: type_name.to_string(),
+                actual: value.to_string(),
+            })?;
+            let inner = &type_name[..type_name.rfind('[').unwrap()];
+            let mut bytes = Vec::with_capacity(arr.len() * 32);
+            for v in arr {
+                bytes.extend(self.eip712_data_word_with_version(name, inner, Some(v), version)?);
+            }
+            return Ok(keccak256(bytes));
+        }
+
+        if self.nodes.contains_key(type_name) {
+            return match (value, version) {
+                (None | Some(serde_json::Value::Null), Eip712Version::V4) => Ok(B256::ZERO),
+                (Some(value), _) => self.hash_struct_with_version(type_name, value, version),
+                (None, _) => Err(Error::missing_field(name)),
+            };
+        }
+
+        let value = value.ok_or_else(|| Error::missing_field(name))?;
+        let value = self.unchecked_resolve(&type_spec)?.coerce_json_with_version(value, version)?;
+        self.eip712_data_word(&value)
+    }
+
+    /// Returns the Solidity struct definitions of all types in the resolver
+    /// graph, except `EIP712Domain`.
+    ///
+    /// The output can be used as [`sol!`](alloy_sol_types::sol) input, which
+    /// generates [`SolStruct`]s whose `eip712_encode_type` is the same as
+    /// [`encode_type`](Self::encode_type). `sol!` also accepts EIP-712 types
+    /// JSON directly with the `json` feature.
+    pub fn to_sol(&self) -> String {
+        let mut sol = String::new();
+        for ty in self.nodes.values().filter(|ty| ty.type_name != "EIP712Domain") {
+            if !sol.is_empty() {
+                sol.push('\n');
+            }
+            sol.push_str("struct ");
+            sol.push_str(&ty.type_name);
+            sol.push_str(" {\n");
+            for prop in &ty.props {
+                sol.push_str("    ");
+                sol.push_str(prop.type_name());
+                sol.push(' ');
+                sol.push_str(prop.name());
+                sol.push_str(";\n");
+            }
+            sol.push_str("}\n");
+        }
+        sol
+    }
+
     /// Check if the resolver graph contains a type by its name.
     ///
     /// ## Warning
@@ -687,4 +828,52 @@ mod tests {
             vec!["Transaction"]
         );
     }
+
+    #[test]
+    fn it_emits_sol() {
+        const ENCODE_TYPE: &str = "Transaction(Person from,Person to,Asset[] txs)Asset(address token,uint256 amount)Person(address wallet,string name)";
+        let mut graph = Resolver::default();
+        graph.ingest_string(ENCODE_TYPE).unwrap();
+        graph.ingest_string("EIP712Domain(string name)").unwrap();
+        assert_eq!(
+            graph.to_sol(),
+            "\
+struct Asset {
+    address token;
+    uint256 amount;
+}
+
+struct Person {
+    address wallet;
+    string name;
+}
+
+struct Transaction {
+    Person from;
+    Person to;
+    Asset[] txs;
+}
+"
+        );
+
+        alloy_sol_types::sol! {
+            struct Asset {
+                address token;
+                uint256 amount;
+            }
+
+            struct Person {
+                address wallet;
+                string name;
+            }
+
+            struct Transaction {
+                Person from;
+                Person to;
+                Asset[] txs;
+            }
+        }
+        assert_eq!(Transaction::eip712_encode_type(), graph.encode_type("Transaction").unwrap());
+        assert_eq!(Transaction::eip712_encode_type(), ENCODE_TYPE);
+    }
 }
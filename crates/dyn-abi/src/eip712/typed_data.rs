@@ -4,7 +4,8 @@ use crate::{
 };
 use alloc::{collections::BTreeMap, string::String, vec::Vec};
 use alloy_primitives::{keccak256, B256};
-use alloy_sol_types::{Eip712Domain, SolStruct};
+use alloy_sol_types::{Eip712Domain, Eip712ShieldedPolicy, SolStruct};
+use core::fmt;
 use derive_more::{Deref, DerefMut, From, Into, IntoIterator};
 use parser::TypeSpecifier;
 use serde::{Deserialize, Serialize};
@@ -28,6 +29,42 @@ impl<'de> Deserialize<'de> for Eip712Types {
     }
 }
 
+/// The `eth_signTypedData` version used to hash and coerce [`TypedData`].
+///
+/// Wallets differ in how they interpret messages which are not fully
+/// specified, following [MetaMask's `eth-sig-util`](https://github.com/MetaMask/eth-sig-util):
+///
+/// - [`V3`](Self::V3) skips fields which are missing from the message, and
+///   does not support arrays.
+/// - [`V4`](Self::V4) supports arrays, and encodes missing or `null` struct
+///   fields, including recursive ones, as a zero word. Other missing fields
+///   are an error.
+///
+/// In both versions, `bytes` and `bytesN` values may also be given as strings
+/// which are not `0x`-prefixed hex, which are UTF-8 encoded, and `bytes`
+/// values as numbers, which are big-endian encoded.
+///
+/// Fully specified messages without arrays hash the same in both versions,
+/// and `V4` hashes fully specified messages the same as
+/// [`TypedData::eip712_signing_hash`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Eip712Version {
+    /// `eth_signTypedData_v3`.
+    V3,
+    /// `eth_signTypedData_v4`.
+    #[default]
+    V4,
+}
+
+impl fmt::Display for Eip712Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::V3 => "V3",
+            Self::V4 => "V4",
+        })
+    }
+}
+
 /// Represents the [EIP-712](https://eips.ethereum.org/EIPS/eip-712) typed data
 /// object.
 ///
@@ -150,6 +187,25 @@ impl TypedData {
         &self.domain
     }
 
+    /// Returns the [policy](Eip712ShieldedPolicy) used to name shielded struct
+    /// members in the `encodeType` string.
+    ///
+    /// See [`Resolver::shielded_policy`].
+    pub const fn shielded_policy(&self) -> Eip712ShieldedPolicy {
+        self.resolver.shielded_policy()
+    }
+
+    /// Sets the [policy](Eip712ShieldedPolicy) used to name shielded struct
+    /// members in the `encodeType` string.
+    ///
+    /// This must match the policy used by the signer, e.g.
+    /// [`Public`](Eip712ShieldedPolicy::Public) for wallets which do not know
+    /// about shielded types.
+    pub fn with_shielded_policy(mut self, policy: Eip712ShieldedPolicy) -> Self {
+        self.resolver.set_shielded_policy(policy);
+        self
+    }
+
     fn resolve(&self) -> Result<DynSolType> {
         self.resolver.resolve(&self.primary_type)
     }
@@ -161,6 +217,17 @@ impl TypedData {
         ty.coerce_json(&self.message)
     }
 
+    /// Coerce the message to the type specified by `primary_type`, following
+    /// the given signing [version](Eip712Version).
+    ///
+    /// Unlike [`eip712_signing_hash_with_version`](Self::eip712_signing_hash_with_version),
+    /// this fails if any field is missing or `null`, since these cannot be
+    /// represented as a [`DynSolValue`].
+    pub fn coerce_with_version(&self, version: Eip712Version) -> Result<DynSolValue> {
+        let ty = self.resolve()?;
+        ty.coerce_json_with_version(&self.message, version)
+    }
+
     /// Calculates the [EIP-712 `typeHash`](https://eips.ethereum.org/EIPS/eip-712#rationale-for-typehash)
     /// for this value.
     ///
@@ -191,6 +258,27 @@ impl TypedData {
         Ok(self.resolver.encode_data(&s)?.unwrap())
     }
 
+    /// Calculate the [`encodeData`] for this value, following the given signing
+    /// [version](Eip712Version).
+    ///
+    /// Unlike [`encode_data`](Self::encode_data), this supports recursive
+    /// types.
+    ///
+    /// Fails if this type is not a struct.
+    ///
+    /// [`encodeData`]: https://eips.ethereum.org/EIPS/eip-712#definition-of-encodedata
+    pub fn encode_data_with_version(&self, version: Eip712Version) -> Result<Vec<u8>> {
+        self.resolver.encode_data_with_version(&self.primary_type, &self.message, version)
+    }
+
+    /// Calculates the [`hashStruct`](https://eips.ethereum.org/EIPS/eip-712#definition-of-hashstruct)
+    /// for this value, following the given signing [version](Eip712Version).
+    ///
+    /// Fails if this type is not a struct.
+    pub fn hash_struct_with_version(&self, version: Eip712Version) -> Result<B256> {
+        self.resolver.hash_struct_with_version(&self.primary_type, &self.message, version)
+    }
+
     /// Calculate the [`encodeType`] for this value.
     ///
     /// Fails if this type is not a struct.
@@ -210,6 +298,19 @@ impl TypedData {
     #[doc(alias = "sign_typed_data")]
     #[doc(alias = "hash_typed_data")]
     pub fn eip712_signing_hash(&self) -> Result<B256> {
+        self.signing_hash_with(Self::hash_struct)
+    }
+
+    /// Calculate the [EIP-712 signing hash](https://eips.ethereum.org/EIPS/eip-712#specification-of-the-eth_signtypeddata-json-rpc)
+    /// for this value, following the given signing [version](Eip712Version).
+    ///
+    /// This can be used to verify signatures produced by wallets with
+    /// `eth_signTypedData_v3` and `eth_signTypedData_v4`.
+    pub fn eip712_signing_hash_with_version(&self, version: Eip712Version) -> Result<B256> {
+        self.signing_hash_with(|this| this.hash_struct_with_version(version))
+    }
+
+    fn signing_hash_with(&self, hash_struct: impl FnOnce(&Self) -> Result<B256>) -> Result<B256> {
         let mut buf = [0u8; 66];
         buf[0] = 0x19;
         buf[1] = 0x01;
@@ -217,7 +318,7 @@ impl TypedData {
 
         // compatibility with <https://github.com/MetaMask/eth-sig-util>
         let len = if self.primary_type != "EIP712Domain" {
-            buf[34..].copy_from_slice(self.hash_struct()?.as_slice());
+            buf[34..].copy_from_slice(hash_struct(self)?.as_slice());
             66
         } else {
             34
@@ -233,7 +334,7 @@ mod tests {
     use super::*;
     use crate::Error;
     use alloc::string::ToString;
-    use alloy_primitives::hex;
+    use alloy_primitives::{b256, hex};
     use alloy_sol_types::sol;
     use serde_json::json;
 
@@ -724,4 +825,292 @@ mod tests {
             "25c3d40a39e639a4d0b6e4d2ace5e1281e039c88494d97d8d08f99a6ea75d775",
         );
     }
+
+    fn ether_mail(types: serde_json::Value, message: serde_json::Value) -> TypedData {
+        let mut types = types;
+        types["EIP712Domain"] = json!([
+            { "name": "name", "type": "string" },
+            { "name": "version", "type": "string" },
+            { "name": "chainId", "type": "uint256" },
+            { "name": "verifyingContract", "type": "address" }
+        ]);
+        serde_json::from_value(json!({
+            "types": types,
+            "primaryType": if types.get("Mail").is_some() { "Mail" } else { "Message" },
+            "domain": {
+                "name": "Ether Mail",
+                "version": "1",
+                "chainId": 1,
+                "verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+            },
+            "message": message
+        }))
+        .unwrap()
+    }
+
+    fn mail_types() -> serde_json::Value {
+        json!({
+            "Person": [
+                { "name": "name", "type": "string" },
+                { "name": "wallet", "type": "address" }
+            ],
+            "Mail": [
+                { "name": "from", "type": "Person" },
+                { "name": "to", "type": "Person" },
+                { "name": "contents", "type": "string" }
+            ]
+        })
+    }
+
+    fn mail_message() -> serde_json::Value {
+        json!({
+            "from": { "name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826" },
+            "to": { "name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB" },
+            "contents": "Hello, Bob!"
+        })
+    }
+
+    fn signing_hashes(typed_data: &TypedData) -> [Result<B256>; 2] {
+        [Eip712Version::V3, Eip712Version::V4]
+            .map(|version| typed_data.eip712_signing_hash_with_version(version))
+    }
+
+    #[test]
+    fn eip712_version_mail() {
+        // `eth_signTypedData_v3` example from EIP-712 and `eth-sig-util`.
+        let typed_data = ether_mail(mail_types(), mail_message());
+        let expected = b256!("0xbe609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2");
+        assert_eq!(signing_hashes(&typed_data), [Ok(expected), Ok(expected)]);
+        assert_eq!(typed_data.eip712_signing_hash(), Ok(expected));
+        assert_eq!(typed_data.coerce_with_version(Eip712Version::V3), typed_data.coerce());
+    }
+
+    #[test]
+    fn eip712_version_arrays() {
+        // `eth_signTypedData_v4` example from `eth-sig-util`.
+        let types = json!({
+            "Group": [
+                { "name": "name", "type": "string" },
+                { "name": "members", "type": "Person[]" }
+            ],
+            "Person": [
+                { "name": "name", "type": "string" },
+                { "name": "wallets", "type": "address[]" }
+            ],
+            "Mail": [
+                { "name": "from", "type": "Person" },
+                { "name": "to", "type": "Person[]" },
+                { "name": "contents", "type": "string" }
+            ]
+        });
+        let mut message = json!({
+            "from": {
+                "name": "Cow",
+                "wallets": [
+                    "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826",
+                    "0xDeaDbeefdEAdbeefdEadbEEFdeadbeEFdEaDbeeF"
+                ]
+            },
+            "to": [{
+                "name": "Bob",
+                "wallets": [
+                    "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB",
+                    "0xB0BdaBea57B0BDABeA57b0bdABEA57b0BDabEa57",
+                    "0xB0B0b0b0b0b0B000000000000000000000000000"
+                ]
+            }],
+            "contents": "Hello, Bob!"
+        });
+        let typed_data = ether_mail(types.clone(), message.clone());
+        let expected = b256!("0xa85c2e2b118698e88db68a8105b794a8cc7cec074e89ef991cb4f5f533819cc2");
+        let unsupported = Error::unsupported_eip712_type("address[]", Eip712Version::V3);
+        assert_eq!(signing_hashes(&typed_data), [Err(unsupported.clone()), Ok(expected)]);
+        assert_eq!(typed_data.eip712_signing_hash(), Ok(expected));
+        assert!(typed_data.coerce_with_version(Eip712Version::V3).is_err());
+        assert!(typed_data.coerce_with_version(Eip712Version::V4).is_ok());
+
+        // `null` structs in arrays are encoded as zero in V4. This vector is not
+        // from `eth-sig-util`, see below.
+        message["to"].as_array_mut().unwrap().push(serde_json::Value::Null);
+        let typed_data = ether_mail(types, message);
+        let expected = b256!("0x5c002a67e1442d1a07953b40621922af9c8c6b501c99f501e3667777a0f798fa");
+        assert_eq!(signing_hashes(&typed_data), [Err(unsupported), Ok(expected)]);
+    }
+
+    // The vectors below are not `eth-sig-util` fixtures. They were generated with
+    // an independent port of its V3 and V4 `TypedDataUtils.eip712Hash`, which
+    // reproduces the two upstream examples above.
+    // TODO: replace them with the V3/V4 cases of `eth-sig-util`'s
+    // `src/sign-typed-data.test.ts`, with their upstream hashes.
+
+    #[test]
+    fn eip712_version_missing_fields() {
+        let mut message = mail_message();
+        message.as_object_mut().unwrap().remove("contents");
+        let typed_data = ether_mail(mail_types(), message);
+        let expected = b256!("0x188ce1e8d83bc3de40cf023da028abba2959316bd9d345fe82f10db9a812386b");
+        assert_eq!(
+            signing_hashes(&typed_data),
+            [Ok(expected), Err(Error::MissingField("contents".into()))]
+        );
+        assert!(typed_data.coerce_with_version(Eip712Version::V3).is_err());
+
+        let mut message = mail_message();
+        message["to"] = serde_json::Value::Null;
+        let typed_data = ether_mail(mail_types(), message);
+        let expected = b256!("0x56467729e7f0d32c0111820eb989210f3b1a435e3b1029f5e4dfad8ee77df6b0");
+        let [v3, v4] = signing_hashes(&typed_data);
+        assert!(v3.is_err());
+        assert_eq!(v4, Ok(expected));
+    }
+
+    #[test]
+    fn eip712_version_recursive() {
+        let mut types = mail_types();
+        types["Mail"].as_array_mut().unwrap().push(json!({ "name": "replyTo", "type": "Mail" }));
+        let mut message = mail_message();
+        message["replyTo"] = json!({
+            "from": message["to"],
+            "to": message["from"],
+            "contents": "Hello!"
+        });
+        let typed_data = ether_mail(types, message);
+        assert_eq!(
+            signing_hashes(&typed_data),
+            [
+                Ok(b256!("0x7c64383d6c5222fe195400e5492135c784fb141b7b0b20b4d5a4ac70e47767e2")),
+                Ok(b256!("0xb899e03ee51b9a22d0174f4a63b3b41e8e31bc3142b7de6771eddfef51c273f2")),
+            ]
+        );
+        assert_eq!(typed_data.eip712_signing_hash(), Err(Error::CircularDependency("Mail".into())));
+    }
+
+    #[test]
+    fn eip712_version_bytes() {
+        let types = json!({
+            "Message": [
+                { "name": "data", "type": "bytes" },
+                { "name": "tag", "type": "bytes32" },
+                { "name": "hex", "type": "bytes" }
+            ]
+        });
+
+        // Strings which are not hex are UTF-8 encoded.
+        let typed_data = ether_mail(
+            types.clone(),
+            json!({ "data": "Hello!", "tag": "Hello!", "hex": "0xdeadbeef" }),
+        );
+        let expected = b256!("0xdb528c4a5c705a52bf76fbdfc3f17ed05c9d7f4d9bf1fcd63982b04acbbae952");
+        assert_eq!(signing_hashes(&typed_data), [Ok(expected), Ok(expected)]);
+        assert!(typed_data.eip712_signing_hash().is_err());
+
+        let value = typed_data.coerce_with_version(Eip712Version::V4).unwrap();
+        let (_, _, fields) = value.as_custom_struct().unwrap();
+        assert_eq!(fields[0], DynSolValue::Bytes(b"Hello!".to_vec()));
+        assert_eq!(fields[1], DynSolValue::FixedBytes(B256::right_padding_from(b"Hello!"), 32));
+        assert_eq!(fields[2], DynSolValue::Bytes(hex!("deadbeef").to_vec()));
+
+        // Numbers are big-endian encoded.
+        let typed_data =
+            ether_mail(types, json!({ "data": 10, "tag": "0x48656c6c6f21", "hex": "0xdeadbeef" }));
+        let expected = b256!("0x8a38c31858299a3fc8a42ad1332fc51c15c5ea98d75b61fb9df62e5589895f02");
+        assert_eq!(signing_hashes(&typed_data), [Ok(expected), Ok(expected)]);
+    }
+
+    #[test]
+    #[cfg(feature = "seismic")]
+    fn shielded_policy() {
+        use alloy_primitives::{
+            address,
+            aliases::{SInt, SUInt},
+            SAddress, I256, U256,
+        };
+        use alloy_sol_types::{eip712_domain, sol_data::Sbool};
+
+        sol! {
+            struct Note {
+                bytes32 memo;
+                sbool urgent;
+                string text;
+            }
+
+            struct Transfer {
+                saddress to;
+                suint256 amount;
+                sint256 delta;
+                Note[] notes;
+            }
+        }
+
+        let typed_data: TypedData = serde_json::from_value(json!({
+            "types": {
+                "EIP712Domain": [
+                    { "name": "name", "type": "string" },
+                    { "name": "chainId", "type": "uint256" }
+                ],
+                "Transfer": [
+                    { "name": "to", "type": "saddress" },
+                    { "name": "amount", "type": "suint256" },
+                    { "name": "delta", "type": "sint256" },
+                    { "name": "notes", "type": "Note[]" }
+                ],
+                "Note": [
+                    { "name": "memo", "type": "bytes32" },
+                    { "name": "urgent", "type": "sbool" },
+                    { "name": "text", "type": "string" }
+                ]
+            },
+            "primaryType": "Transfer",
+            "domain": { "name": "Seismic", "chainId": 5124 },
+            "message": {
+                "to": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB",
+                "amount": "1000",
+                "delta": -5,
+                "notes": [
+                    { "memo": B256::repeat_byte(1), "urgent": true, "text": "rent" },
+                    { "memo": B256::repeat_byte(2), "urgent": false, "text": "food" }
+                ]
+            }
+        }))
+        .unwrap();
+        let transfer = Transfer {
+            to: SAddress(address!("0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB")),
+            amount: SUInt(U256::from(1000)),
+            delta: SInt(I256::try_from(-5).unwrap()),
+            notes: vec![
+                Note { memo: B256::repeat_byte(1), urgent: Sbool(true), text: "rent".into() },
+                Note { memo: B256::repeat_byte(2), urgent: Sbool(false), text: "food".into() },
+            ],
+        };
+        let domain = eip712_domain! { name: "Seismic", chain_id: 5124, };
+
+        // Neither vector comes from a wallet yet. The public one matches the same
+        // independent `eth-sig-util` V4 port as the tests above, run on the public
+        // types; the shielded one matches that port extended with the shielded
+        // type names.
+        assert_eq!(typed_data.shielded_policy(), Eip712ShieldedPolicy::Shielded);
+        assert_eq!(
+            typed_data.encode_type().unwrap(),
+            "Transfer(saddress to,suint256 amount,sint256 delta,Note[] notes)\
+             Note(bytes32 memo,sbool urgent,string text)"
+        );
+        let expected = b256!("0x0f9943a49876acbf49210294e48e8ca81e23e48867f60133d3510edd16725b58");
+        assert_eq!(typed_data.eip712_signing_hash(), Ok(expected));
+        assert_eq!(typed_data.eip712_signing_hash_with_version(Eip712Version::V4), Ok(expected));
+        assert_eq!(transfer.eip712_signing_hash(&domain), expected);
+
+        let typed_data = typed_data.with_shielded_policy(Eip712ShieldedPolicy::Public);
+        assert_eq!(
+            typed_data.encode_type().unwrap(),
+            "Transfer(address to,uint256 amount,int256 delta,Note[] notes)\
+             Note(bytes32 memo,bool urgent,string text)"
+        );
+        let expected = b256!("0xdddf0f4aff50461018ba201091fe4457740187c34dd53872fae09dafbe6f1f4d");
+        assert_eq!(typed_data.eip712_signing_hash(), Ok(expected));
+        assert_eq!(typed_data.eip712_signing_hash_with_version(Eip712Version::V4), Ok(expected));
+        assert_eq!(
+            transfer.eip712_signing_hash_with_policy(&domain, Eip712ShieldedPolicy::Public),
+            expected
+        );
+    }
 }
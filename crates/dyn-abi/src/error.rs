@@ -21,6 +21,17 @@ pub enum Error {
     /// Invalid property definition.
     #[cfg(feature = "eip712")]
     InvalidPropertyDefinition(String),
+    /// Missing value for a struct field.
+    #[cfg(feature = "eip712")]
+    MissingField(String),
+    /// Type not supported by an EIP-712 signing version.
+    #[cfg(feature = "eip712")]
+    UnsupportedEip712Type {
+        /// The unsupported type.
+        ty: String,
+        /// The signing version.
+        version: crate::Eip712Version,
+    },
 
     /// Type mismatch during encoding or coercion.
     TypeMismatch {
@@ -118,6 +129,12 @@ impl fmt::Display for Error {
             Self::CircularDependency(dep) => write!(f, "circular dependency: {dep}"),
             #[cfg(feature = "eip712")]
             Self::InvalidPropertyDefinition(def) => write!(f, "invalid property definition: {def}"),
+            #[cfg(feature = "eip712")]
+            Self::MissingField(name) => write!(f, "missing value for field {name}"),
+            #[cfg(feature = "eip712")]
+            Self::UnsupportedEip712Type { ty, version } => {
+                write!(f, "type {ty} is not supported by EIP-712 {version}")
+            }
 
             Self::TypeMismatch { expected, actual } => write!(
                 f,
@@ -161,6 +178,16 @@ impl Error {
         Self::InvalidPropertyDefinition(def.into())
     }
 
+    #[cfg(feature = "eip712")]
+    pub(crate) fn missing_field(name: &str) -> Self {
+        Self::MissingField(name.into())
+    }
+
+    #[cfg(feature = "eip712")]
+    pub(crate) fn unsupported_eip712_type(ty: &str, version: crate::Eip712Version) -> Self {
+        Self::UnsupportedEip712Type { ty: ty.into(), version }
+    }
+
     #[cfg(feature = "eip712")]
     pub(crate) fn missing_type(name: &str) -> Self {
         Self::MissingType(name.into())
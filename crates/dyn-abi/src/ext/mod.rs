@@ -6,3 +6,6 @@ pub use event::EventExt;
 
 mod error;
 pub use error::ErrorExt;
+
+mod signature_db;
+pub use signature_db::SignatureDbExt;
@@ -0,0 +1,128 @@
+use crate::{DecodedError, DecodedEvent, DynSolValue, ErrorExt, EventExt, JsonAbiExt};
+use alloc::vec::Vec;
+use alloy_json_abi::{Error, Event, Function, SignatureDb};
+use alloy_primitives::{LogData, Selector};
+
+#[allow(unknown_lints, unnameable_types)]
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for alloy_json_abi::SignatureDb {}
+}
+use sealed::Sealed;
+
+/// Provides decoding of calldata, revert data and logs with the matching
+/// items of a [`SignatureDb`].
+///
+/// Selectors are not unique, so every method returns all the items whose
+/// parameters successfully decode the given data, in insertion order. An
+/// empty result means that no item matched.
+///
+/// This trait is sealed and cannot be implemented for types outside of this
+/// crate. It is implemented only for [`SignatureDb`].
+pub trait SignatureDbExt: Sealed {
+    /// Decodes the given calldata, including the selector, with the matching
+    /// functions' input types.
+    fn decode_input(&self, calldata: &[u8]) -> Vec<(&Function, Vec<DynSolValue>)>;
+
+    /// Decodes the given revert data, including the selector, with the
+    /// matching errors' input types.
+    fn decode_error(&self, data: &[u8]) -> Vec<(&Error, DecodedError)>;
+
+    /// Decodes the given log with the events matching its first topic.
+    ///
+    /// Anonymous events are never matched, as they do not emit their
+    /// selector.
+    fn decode_log(&self, log: &LogData) -> Vec<(&Event, DecodedEvent)>;
+}
+
+impl SignatureDbExt for SignatureDb {
+    fn decode_input(&self, calldata: &[u8]) -> Vec<(&Function, Vec<DynSolValue>)> {
+        let Some((selector, data)) = calldata.split_first_chunk::<4>() else {
+            return Vec::new();
+        };
+        self.functions(Selector::from(*selector))
+            .iter()
+            .filter_map(|f| f.abi_decode_input(data).ok().map(|values| (f, values)))
+            .collect()
+    }
+
+    fn decode_error(&self, data: &[u8]) -> Vec<(&Error, DecodedError)> {
+        let Some(selector) = data.first_chunk::<4>() else {
+            return Vec::new();
+        };
+        self.errors(Selector::from(*selector))
+            .iter()
+            .filter_map(|e| e.decode_error(data).ok().map(|decoded| (e, decoded)))
+            .collect()
+    }
+
+    fn decode_log(&self, log: &LogData) -> Vec<(&Event, DecodedEvent)> {
+        let Some(&topic0) = log.topics().first() else {
+            return Vec::new();
+        };
+        self.events(topic0)
+            .iter()
+            .filter(|e| !e.anonymous)
+            .filter_map(|e| e.decode_log(log).ok().map(|decoded| (e, decoded)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, hex, Address, U256};
+
+    #[test]
+    fn decode() {
+        let mut db = SignatureDb::new();
+        db.extend_signatures([
+            "function transfer(address to, uint256 amount) returns (bool)",
+            "event Transfer(address indexed from, address indexed to, uint256 value)",
+            "error InsufficientBalance(uint256 available, uint256 required)",
+        ])
+        .unwrap();
+        db.extend_4byte(
+            "0xa9059cbb many_msg_babbage(bytes1)\n\
+             0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef Transfer(address,address,uint256)",
+        )
+        .unwrap();
+
+        let to = address!("0x0000000000000000000000000000000000001234");
+        let calldata = hex!(
+            "a9059cbb"
+            "0000000000000000000000000000000000000000000000000000000000001234"
+            "0000000000000000000000000000000000000000000000000000000000000064"
+        );
+        let decoded = db.decode_input(&calldata);
+        assert_eq!(decoded.len(), 2);
+        assert_eq!(decoded[0].0.name, "transfer");
+        assert_eq!(
+            decoded[0].1,
+            [DynSolValue::Address(to), DynSolValue::Uint(U256::from(100), 256)]
+        );
+        assert_eq!(decoded[1].0.name, "many_msg_babbage");
+        assert!(db.decode_input(&calldata[..3]).is_empty());
+        assert!(db.decode_input(&hex!("deadbeef")).is_empty());
+
+        let revert = hex!(
+            "cf479181"
+            "0000000000000000000000000000000000000000000000000000000000000001"
+            "0000000000000000000000000000000000000000000000000000000000000002"
+        );
+        let decoded = db.decode_error(&revert);
+        assert_eq!(decoded.len(), 1);
+        assert_eq!(decoded[0].1.body[1], DynSolValue::Uint(U256::from(2), 256));
+        assert!(db.decode_error(&revert[..35]).is_empty());
+
+        let transfer = &db.all_events().next().unwrap();
+        let log = LogData::new_unchecked(
+            vec![transfer.selector(), Address::ZERO.into_word(), to.into_word()],
+            U256::from(100).to_be_bytes_vec().into(),
+        );
+        let decoded = db.decode_log(&log);
+        assert_eq!(decoded.len(), 1);
+        assert_eq!(decoded[0].0.num_topics(), 3);
+        assert_eq!(decoded[0].1.indexed[1], DynSolValue::Address(to));
+    }
+}
@@ -0,0 +1,523 @@
+//! Heuristic inference of ABI types from encoded data.
+//!
+//! See [`InferredParams`] for more information.
+
+use crate::{DynSolType, DynSolValue};
+use alloc::{string::String, vec::Vec};
+use alloy_primitives::Selector;
+use alloy_sol_types::{abi::Decoder, utils::words_for_len, Word};
+use core::cmp::Reverse;
+
+/// The maximum nesting depth of inferred dynamic types.
+const MAX_DEPTH: usize = 8;
+
+/// The maximum number of candidates kept at each level of the inference.
+const MAX_CANDIDATES: usize = 8;
+
+/// The maximum number of words examined while looking for dynamic types, over
+/// all nesting levels. Once exhausted, the remaining data is inferred as
+/// static words.
+const MAX_STEPS: usize = 1 << 16;
+
+/// A guess of the parameter types of ABI-encoded data, along with the values
+/// decoded with those types.
+///
+/// Without an ABI, the encoding is ambiguous: any sequence of words is also a
+/// valid encoding of that many `uint256`s. The inference follows the
+/// offset/length structure of the data to find plausible dynamic types
+/// (`bytes`, `string`, arrays and tuples), and guesses static types from the
+/// shape of each word:
+///
+/// - words with 12 to 15 leading zero bytes are guessed as `address`;
+/// - words with at least 4 leading `0xff` bytes are guessed as `int256`;
+/// - words with no leading zero bytes are guessed as left-aligned `bytesN`;
+/// - everything else is guessed as `uint256`.
+///
+/// Candidates are ranked by whether they re-encode to exactly the same data,
+/// then by how much of the data they explain with dynamic structure.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_dyn_abi::{DynSolValue, InferredParams};
+/// use alloy_primitives::{address, U256};
+///
+/// let values = DynSolValue::Tuple(vec![
+///     DynSolValue::Address(address!("0x1111111111111111111111111111111111111111")),
+///     DynSolValue::Array(vec![
+///         DynSolValue::Uint(U256::from(1), 256),
+///         DynSolValue::Uint(U256::from(2), 256),
+///     ]),
+///     DynSolValue::Bytes(vec![0xde, 0xad, 0xbe, 0xef]),
+/// ]);
+/// let data = values.abi_encode_params();
+///
+/// let inferred = InferredParams::infer(&data);
+/// let best = &inferred[0];
+/// assert_eq!(best.signature("foo"), "foo(address,uint256[],bytes)");
+/// assert_eq!(best.values(), values.as_tuple().unwrap());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct InferredParams {
+    types: Vec<DynSolType>,
+    values: Vec<DynSolValue>,
+    canonical: bool,
+}
+
+impl InferredParams {
+    /// Infers the parameter types of `data`, which must not include a
+    /// selector.
+    ///
+    /// Returns all plausible candidates, most likely first. The result is
+    /// empty if the data is not a sequence of words.
+    pub fn infer(data: &[u8]) -> Vec<Self> {
+        let mut steps = MAX_STEPS;
+        let mut candidates = infer_seq(data, 0, &mut steps);
+        let mut i = 0;
+        while i < candidates.len() {
+            if candidates[..i].contains(&candidates[i]) {
+                candidates.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+
+        let mut inferred: Vec<_> =
+            candidates.into_iter().filter_map(|types| Self::decode(types, data)).collect();
+        // Stable, so equally ranked candidates stay in the order they were
+        // inferred in.
+        inferred.sort_by_key(|params| {
+            let structure = params.types.iter().map(structure_score).sum::<usize>();
+            (Reverse(params.canonical), Reverse(structure))
+        });
+        inferred
+    }
+
+    /// Splits off the selector of `calldata` and infers the parameter types
+    /// of the rest. See [`infer`](Self::infer).
+    ///
+    /// Returns `None` if the calldata is shorter than a selector.
+    pub fn infer_calldata(calldata: &[u8]) -> Option<(Selector, Vec<Self>)> {
+        let (selector, data) = calldata.split_first_chunk::<4>()?;
+        Some((Selector::from(*selector), Self::infer(data)))
+    }
+
+    fn decode(types: Vec<DynSolType>, data: &[u8]) -> Option<Self> {
+        let ty = DynSolType::Tuple(types);
+        let value = ty.abi_decode_sequence(data).ok()?;
+        let canonical = value.abi_encode_sequence().is_some_and(|encoded| encoded == data);
+        let DynSolType::Tuple(types) = ty else { unreachable!() };
+        Some(Self { types, values: value.into_fixed_seq()?, canonical })
+    }
+
+    /// Returns the inferred types.
+    #[inline]
+    pub fn types(&self) -> &[DynSolType] {
+        &self.types
+    }
+
+    /// Returns the values decoded with the inferred types.
+    #[inline]
+    pub fn values(&self) -> &[DynSolValue] {
+        &self.values
+    }
+
+    /// Consumes `self`, returning the inferred types and the decoded values.
+    #[inline]
+    pub fn into_parts(self) -> (Vec<DynSolType>, Vec<DynSolValue>) {
+        (self.types, self.values)
+    }
+
+    /// Returns whether re-encoding the decoded values yields exactly the
+    /// original data.
+    #[inline]
+    pub const fn is_canonical(&self) -> bool {
+        self.canonical
+    }
+
+    /// Returns the Solidity signature of a function with the given name and
+    /// the inferred parameter types, e.g. `foo(address,uint256[],bytes)`.
+    pub fn signature(&self, name: &str) -> String {
+        let mut sig = String::with_capacity(name.len() + 2 + self.types.len() * 8);
+        sig.push_str(name);
+        sig.push('(');
+        for (i, ty) in self.types.iter().enumerate() {
+            if i > 0 {
+                sig.push(',');
+            }
+            sig.push_str(&ty.sol_type_name());
+        }
+        sig.push(')');
+        sig
+    }
+}
+
+/// Infers candidates for a sequence of types encoded in `data`.
+fn infer_seq(data: &[u8], depth: usize, steps: &mut usize) -> Vec<Vec<DynSolType>> {
+    if data.len() % Word::len_bytes() != 0 {
+        return Vec::new();
+    }
+    let mut candidates = infer_dynamic_seq(data, depth, steps);
+    candidates.push(words(data).map(infer_word).collect());
+    candidates
+}
+
+/// Infers candidates for a sequence of types encoded in `data` which contains
+/// at least one dynamic type.
+///
+/// In canonical encodings, the first offset in the head points right past the
+/// end of the head, and the tails follow each other in head order. Every word
+/// which could be that first offset is tried, until `steps` runs out.
+fn infer_dynamic_seq(data: &[u8], depth: usize, steps: &mut usize) -> Vec<Vec<DynSolType>> {
+    let mut candidates = Vec::new();
+    if depth >= MAX_DEPTH || data.len() % Word::len_bytes() != 0 {
+        return candidates;
+    }
+    let dec = Decoder::new(data);
+    let offset_at = |i: usize, min: usize| {
+        dec.peek_offset_at(i * Word::len_bytes()).ok().filter(|&offset| {
+            offset % Word::len_bytes() == 0 && offset >= min && offset < data.len()
+        })
+    };
+
+    let n_words = data.len() / Word::len_bytes();
+    for first in 0..n_words {
+        if candidates.len() >= MAX_CANDIDATES || !take_steps(steps, 1) {
+            break;
+        }
+        let Some(head_end) = offset_at(first, (first + 1) * Word::len_bytes()) else {
+            continue;
+        };
+
+        // Classify the head words after the first offset.
+        let head_words = head_end / Word::len_bytes();
+        if !take_steps(steps, head_words - first) {
+            break;
+        }
+        let mut offsets = Vec::new();
+        let mut last = head_end;
+        for i in first..head_words {
+            let min = if offsets.is_empty() { head_end } else { last + Word::len_bytes() };
+            if let Some(offset) = offset_at(i, min) {
+                offsets.push((i, offset));
+                last = offset;
+            }
+        }
+
+        // Infer the tails, each delimited by the next one.
+        let mut slots: Vec<Vec<DynSolType>> =
+            words(&data[..head_end]).map(|word| vec![infer_word(word)]).collect();
+        let ends = offsets.iter().skip(1).map(|&(_, offset)| offset).chain([data.len()]);
+        let mut ok = true;
+        for (&(i, start), end) in offsets.iter().zip(ends) {
+            let tails = infer_tail(&data[start..end], depth + 1, steps);
+            if tails.is_empty() {
+                ok = false;
+                break;
+            }
+            slots[i] = tails;
+        }
+        if ok {
+            candidates.extend(product(slots));
+        }
+    }
+    candidates.truncate(MAX_CANDIDATES);
+    candidates
+}
+
+/// Infers candidates for a dynamic type encoded in the tail region `data`.
+fn infer_tail(data: &[u8], depth: usize, steps: &mut usize) -> Vec<DynSolType> {
+    let mut candidates = Vec::new();
+    if !take_steps(steps, data.len() / Word::len_bytes()) {
+        return candidates;
+    }
+    let dec = Decoder::new(data);
+    let Ok(len) = dec.peek_offset() else { return candidates };
+    let rest = &data[Word::len_bytes()..];
+
+    // `bytes` or `string`: the contents are right-padded to a whole word.
+    if len <= rest.len()
+        && rest.len() == words_for_len(len) * Word::len_bytes()
+        && rest[len..].iter().all(|b| *b == 0)
+    {
+        if len > 0 && is_text(&rest[..len]) {
+            candidates.push(DynSolType::String);
+        }
+        candidates.push(DynSolType::Bytes);
+    }
+
+    if len > 0 && len.checked_mul(Word::len_bytes()).is_some_and(|min| rest.len() >= min) {
+        // Arrays of dynamic elements.
+        if let Some(inner) = infer_dynamic_elements(rest, len, depth, steps) {
+            candidates.push(DynSolType::Array(inner.into()));
+        }
+
+        // Arrays of static elements, which all have the same size.
+        if rest.len() % (len * Word::len_bytes()) == 0 {
+            let elem_words = rest.len() / Word::len_bytes() / len;
+            let elems = rest.chunks_exact(elem_words * Word::len_bytes());
+            let inner = if elem_words == 1 {
+                unify(elems.map(infer_word))
+            } else {
+                let components = (0..elem_words).map(|i| {
+                    let at = i * Word::len_bytes();
+                    unify(elems.clone().map(|elem| infer_word(&elem[at..at + 32])))
+                });
+                DynSolType::Tuple(components.collect())
+            };
+            candidates.push(DynSolType::Array(inner.into()));
+        }
+    }
+
+    // Tuples containing dynamic types.
+    candidates.extend(infer_dynamic_seq(data, depth, steps).into_iter().map(DynSolType::Tuple));
+
+    candidates.truncate(MAX_CANDIDATES);
+    candidates
+}
+
+/// Infers the common type of `len` dynamic array elements, whose offsets are
+/// at the start of `data`.
+fn infer_dynamic_elements(
+    data: &[u8],
+    len: usize,
+    depth: usize,
+    steps: &mut usize,
+) -> Option<DynSolType> {
+    if depth >= MAX_DEPTH {
+        return None;
+    }
+    let dec = Decoder::new(data);
+    let mut offsets = Vec::with_capacity(len);
+    let mut min = len * Word::len_bytes();
+    for i in 0..len {
+        let offset = dec.peek_offset_at(i * Word::len_bytes()).ok()?;
+        if offset < min || offset >= data.len() || offset % Word::len_bytes() != 0 {
+            return None;
+        }
+        if i == 0 && offset != min {
+            return None;
+        }
+        offsets.push(offset);
+        min = offset + Word::len_bytes();
+    }
+
+    let ends = offsets.iter().skip(1).copied().chain([data.len()]);
+    let mut elems = offsets
+        .iter()
+        .zip(ends)
+        .map(|(&start, end)| infer_tail(&data[start..end], depth + 1, steps));
+    let first = elems.next()?;
+    let rest: Vec<_> = elems.collect();
+    first.into_iter().find(|ty| rest.iter().all(|candidates| candidates.contains(ty)))
+}
+
+/// Consumes `n` steps from the budget, returning `false` if there are not
+/// enough left.
+fn take_steps(steps: &mut usize, n: usize) -> bool {
+    let Some(left) = steps.checked_sub(n) else { return false };
+    *steps = left;
+    true
+}
+
+/// Guesses the type of a single static word.
+fn infer_word(word: &[u8]) -> DynSolType {
+    let leading_zeros = word.iter().take_while(|b| **b == 0).count();
+    if word[..4] == [0xff; 4] {
+        DynSolType::Int(256)
+    } else if (12..16).contains(&leading_zeros) {
+        DynSolType::Address
+    } else if leading_zeros == 0 {
+        let trailing_zeros = word.iter().rev().take_while(|b| **b == 0).count();
+        DynSolType::FixedBytes(32 - trailing_zeros)
+    } else {
+        DynSolType::Uint(256)
+    }
+}
+
+/// Returns the common type of static words, widening mismatched guesses.
+fn unify(mut types: impl Iterator<Item = DynSolType>) -> DynSolType {
+    let Some(first) = types.next() else { return DynSolType::Uint(256) };
+    types
+        .try_fold(first, |acc, ty| match (acc, ty) {
+            (acc, ty) if acc == ty => Some(acc),
+            (DynSolType::FixedBytes(a), DynSolType::FixedBytes(b)) => {
+                Some(DynSolType::FixedBytes(a.max(b)))
+            }
+            _ => None,
+        })
+        .unwrap_or(DynSolType::Uint(256))
+}
+
+/// Returns whether `data` is likely to be a string.
+fn is_text(data: &[u8]) -> bool {
+    core::str::from_utf8(data)
+        .is_ok_and(|s| s.chars().all(|c| !c.is_control() || matches!(c, '\n' | '\r' | '\t')))
+}
+
+/// Returns the number of dynamic types in `ty`, used to prefer candidates
+/// which explain more of the data.
+fn structure_score(ty: &DynSolType) -> usize {
+    match ty {
+        DynSolType::Bytes | DynSolType::String => 1,
+        DynSolType::Array(inner) => 1 + structure_score(inner),
+        DynSolType::FixedArray(inner, _) => structure_score(inner),
+        DynSolType::Tuple(types) => {
+            usize::from(ty.is_dynamic()) + types.iter().map(structure_score).sum::<usize>()
+        }
+        _ => 0,
+    }
+}
+
+/// Returns the cartesian product of the candidates of each slot, keeping at
+/// most [`MAX_CANDIDATES`] combinations.
+fn product(slots: Vec<Vec<DynSolType>>) -> Vec<Vec<DynSolType>> {
+    let mut combinations = vec![Vec::new()];
+    for slot in slots {
+        combinations = combinations
+            .iter()
+            .flat_map(|prefix| {
+                slot.iter().map(move |ty| {
+                    let mut combination = Vec::with_capacity(prefix.len() + 1);
+                    combination.extend_from_slice(prefix);
+                    combination.push(ty.clone());
+                    combination
+                })
+            })
+            .take(MAX_CANDIDATES)
+            .collect();
+    }
+    combinations
+}
+
+fn words(data: &[u8]) -> impl Iterator<Item = &[u8]> + Clone {
+    data.chunks_exact(Word::len_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, hex, I256, U256};
+
+    fn infer_best(values: Vec<DynSolValue>) -> InferredParams {
+        let data = DynSolValue::Tuple(values.clone()).abi_encode_params();
+        let best = InferredParams::infer(&data).swap_remove(0);
+        assert!(best.is_canonical());
+        assert_eq!(best.values(), values, "{}", best.signature("f"));
+        best
+    }
+
+    #[test]
+    fn static_words() {
+        let best = infer_best(vec![
+            DynSolValue::Address(address!("0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D")),
+            DynSolValue::Uint(U256::from(1_000_000), 256),
+            DynSolValue::Int(I256::MINUS_ONE, 256),
+            DynSolValue::FixedBytes(Word::right_padding_from(&hex!("a9059cbb")), 4),
+        ]);
+        assert_eq!(best.signature("f"), "f(address,uint256,int256,bytes4)");
+    }
+
+    #[test]
+    fn dynamic_params() {
+        let best = infer_best(vec![
+            DynSolValue::String("hello world".into()),
+            DynSolValue::Uint(U256::from(42), 256),
+            DynSolValue::Bytes(hex!("0001020304").to_vec()),
+        ]);
+        assert_eq!(best.signature("f"), "f(string,uint256,bytes)");
+    }
+
+    #[test]
+    fn nested_arrays_and_tuples() {
+        let best = infer_best(vec![
+            DynSolValue::Array(vec![
+                DynSolValue::String("a".into()),
+                DynSolValue::String("bc".into()),
+            ]),
+            DynSolValue::Tuple(vec![
+                DynSolValue::Uint(U256::from(7), 256),
+                DynSolValue::Bytes(vec![0xff; 40]),
+            ]),
+            DynSolValue::Array(vec![
+                DynSolValue::Tuple(vec![
+                    DynSolValue::Uint(U256::from(1), 256),
+                    DynSolValue::Uint(U256::from(2), 256),
+                ]),
+                DynSolValue::Tuple(vec![
+                    DynSolValue::Uint(U256::from(3), 256),
+                    DynSolValue::Uint(U256::from(4), 256),
+                ]),
+            ]),
+        ]);
+        assert_eq!(best.signature("f"), "f(string[],(uint256,bytes),(uint256,uint256)[])");
+    }
+
+    #[test]
+    fn calldata() {
+        let data = hex!(
+            "a9059cbb"
+            "0000000000000000000000007a250d5630b4cf539739df2c5dacb4c659f2488d"
+            "0000000000000000000000000000000000000000000000000de0b6b3a7640000"
+        );
+        let (selector, inferred) = InferredParams::infer_calldata(&data).unwrap();
+        assert_eq!(selector, hex!("a9059cbb"));
+        assert_eq!(inferred[0].signature("transfer"), "transfer(address,uint256)");
+
+        assert!(InferredParams::infer_calldata(&data[..3]).is_none());
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(InferredParams::infer(&[0; 31]).is_empty());
+
+        // Offsets pointing out of bounds are treated as plain numbers.
+        let data = hex!(
+            "0000000000000000000000000000000000000000000000000000000000000040"
+            "0000000000000000000000000000000000000000000000000000000000001000"
+        );
+        let inferred = InferredParams::infer(&data);
+        assert_eq!(inferred[0].types(), [DynSolType::Uint(256), DynSolType::Uint(256)]);
+
+        // Lengths which do not fit in the data.
+        let data = hex!(
+            "0000000000000000000000000000000000000000000000000000000000000020"
+            "000000000000000000000000000000000000000000000000ffffffffffffffff"
+        );
+        let inferred = InferredParams::infer(&data);
+        assert_eq!(inferred[0].types(), [DynSolType::Uint(256), DynSolType::Uint(256)]);
+    }
+
+    #[test]
+    fn bounded_work() {
+        // Every word is a plausible offset and length at every depth.
+        let mut data = Vec::new();
+        for _ in 0..4096 {
+            data.extend_from_slice(&Word::with_last_byte(0x20)[..]);
+        }
+        assert!(!InferredParams::infer(&data).is_empty());
+    }
+
+    #[cfg(all(feature = "arbitrary", not(miri)))]
+    mod proptests {
+        use super::*;
+        use proptest::prelude::*;
+
+        /// Words which are likely to be read as offsets or lengths.
+        fn word() -> impl Strategy<Value = Word> {
+            prop_oneof![
+                any::<[u8; 32]>().prop_map(Word::from),
+                (0u64..0x400).prop_map(|n| U256::from(n).into()),
+                any::<u64>().prop_map(|n| U256::from(n).into()),
+            ]
+        }
+
+        proptest! {
+            #[test]
+            fn never_panics(words in prop::collection::vec(word(), 0..32)) {
+                let data: Vec<u8> = words.iter().flat_map(|word| word.0).collect();
+                let _ = InferredParams::infer(&data);
+            }
+        }
+    }
+}
@@ -0,0 +1,607 @@
+//! Annotated layouts of ABI-encoded data.
+//!
+//! See [`AbiLayout`] for more information.
+
+use crate::{dynamic::ty::as_tuple, DynSolType, Error, Result, Specifier};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use alloy_json_abi::{Function, Param};
+use alloy_primitives::{hex, Selector};
+use alloy_sol_types::{abi::Decoder, utils::words_for_len, Word};
+use core::fmt;
+
+/// An annotated, word-by-word layout of ABI-encoded data.
+///
+/// This is mainly useful for debugging: every word of the data is labeled
+/// with the parameter it belongs to and the role it plays in the encoding,
+/// e.g. a static value in the head, an offset pointing into the tail, or the
+/// length word of a dynamic tail region.
+///
+/// The layout is produced by walking the data with the same [`Decoder`] offset
+/// logic used for decoding. In lossy mode, malformed parameters are recorded
+/// as [`LayoutKind::Invalid`] entries and the walk continues with the next
+/// parameter, so the rest of the data can still be inspected.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_dyn_abi::{AbiLayout, LayoutKind};
+/// use alloy_json_abi::Function;
+/// use alloy_primitives::hex;
+///
+/// let func = Function::parse("foo(uint256 a, bytes b)")?;
+/// let params = hex!(
+///     "0000000000000000000000000000000000000000000000000000000000000001"
+///     "0000000000000000000000000000000000000000000000000000000000000040"
+///     "0000000000000000000000000000000000000000000000000000000000000002"
+///     "1234000000000000000000000000000000000000000000000000000000000000"
+/// );
+/// let calldata = [&func.selector()[..], &params[..]].concat();
+///
+/// let layout = AbiLayout::from_function(&func, &calldata)?;
+/// let kinds = layout.entries().iter().map(|e| (e.offset, e.path.as_str(), &e.kind));
+/// assert_eq!(
+///     kinds.collect::<Vec<_>>(),
+///     [
+///         (0x00, "foo", &LayoutKind::Selector),
+///         (0x04, "a", &LayoutKind::Value(alloy_dyn_abi::DynSolType::Uint(256))),
+///         (0x24, "b", &LayoutKind::Offset { target: 0x44 }),
+///         (0x44, "b", &LayoutKind::Length(2)),
+///         (0x64, "b", &LayoutKind::Data),
+///     ]
+/// );
+/// println!("{layout}");
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct AbiLayout<'a> {
+    entries: Vec<LayoutEntry<'a>>,
+}
+
+/// A single annotated region of ABI-encoded data. See [`AbiLayout`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct LayoutEntry<'a> {
+    /// The byte offset of this entry from the start of the data.
+    pub offset: usize,
+    /// The raw bytes of this entry. This is usually a single word, but may be
+    /// shorter for the selector or for truncated data.
+    pub data: &'a [u8],
+    /// The path of the parameter owning this entry, e.g. `orders[1].amount`.
+    ///
+    /// Unnamed parameters and tuple components are referred to by their index.
+    pub path: String,
+    /// The role of this entry in the encoding.
+    pub kind: LayoutKind,
+}
+
+/// The role of a [`LayoutEntry`] in the encoding.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LayoutKind {
+    /// The 4-byte function selector.
+    Selector,
+    /// A word holding a value of the given type.
+    Value(DynSolType),
+    /// A head word holding an offset to a tail region.
+    Offset {
+        /// The byte offset of the tail region from the start of the data.
+        target: usize,
+    },
+    /// The length word of a dynamic array, `bytes` or `string`.
+    Length(usize),
+    /// A (padded) word of `bytes` or `string` contents.
+    Data,
+    /// A word that is not referenced by any parameter.
+    Unused,
+    /// A parameter that could not be walked, with the reason why.
+    ///
+    /// Only produced in lossy mode.
+    Invalid(String),
+}
+
+impl fmt::Display for AbiLayout<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for entry in &self.entries {
+            writeln!(f, "{entry}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for LayoutEntry<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#06x}: {:<64}  ", self.offset, hex::encode(self.data))?;
+        if !self.path.is_empty() {
+            write!(f, "{}: ", self.path)?;
+        }
+        self.kind.fmt(f)
+    }
+}
+
+impl fmt::Display for LayoutKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Selector => f.write_str("selector"),
+            Self::Value(ty) => ty.fmt(f),
+            Self::Offset { target } => write!(f, "offset -> {target:#06x}"),
+            Self::Length(len) => write!(f, "length = {len}"),
+            Self::Data => f.write_str("data"),
+            Self::Unused => f.write_str("unused"),
+            Self::Invalid(reason) => write!(f, "invalid: {reason}"),
+        }
+    }
+}
+
+impl<'a> AbiLayout<'a> {
+    /// Computes the layout of `data`, ABI-encoded as a sequence of `types`,
+    /// as in function parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data cannot be decoded as the given types.
+    pub fn from_types(types: &[DynSolType], data: &'a [u8]) -> Result<Self> {
+        Walker::new(data, false).walk_params(types, &[], 0).map(Walker::finish)
+    }
+
+    /// Computes the layout of `data`, ABI-encoded as a sequence of `types`,
+    /// as in function parameters.
+    ///
+    /// Malformed parameters are recorded as [`LayoutKind::Invalid`] entries
+    /// instead of failing.
+    pub fn from_types_lossy(types: &[DynSolType], data: &'a [u8]) -> Self {
+        let walker = Walker::new(data, true).walk_params(types, &[], 0);
+        walker.expect("lossy walk does not fail").finish()
+    }
+
+    /// Computes the layout of the calldata of a call to `function`, including
+    /// the selector.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the function's input types cannot be resolved, if
+    /// the selector does not match, or if the data cannot be decoded as the
+    /// function's inputs.
+    pub fn from_function(function: &Function, calldata: &'a [u8]) -> Result<Self> {
+        Self::function_inner(function, calldata, false)
+    }
+
+    /// Computes the layout of the calldata of a call to `function`, including
+    /// the selector.
+    ///
+    /// A mismatched selector and malformed parameters are recorded as
+    /// [`LayoutKind::Invalid`] entries instead of failing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if the function's input types cannot be
+    /// resolved.
+    pub fn from_function_lossy(function: &Function, calldata: &'a [u8]) -> Result<Self> {
+        Self::function_inner(function, calldata, true)
+    }
+
+    fn function_inner(function: &Function, calldata: &'a [u8], lossy: bool) -> Result<Self> {
+        let types = function.inputs.iter().map(Param::resolve).collect::<Result<Vec<_>>>()?;
+
+        let mut walker = Walker::new(calldata, lossy);
+        let expected = function.selector();
+        match calldata.get(..4) {
+            Some(selector) => {
+                walker.push(0, 4, &function.name, LayoutKind::Selector);
+                if selector != expected.as_slice() {
+                    let actual = Selector::from_slice(selector);
+                    let err = Error::SelectorMismatch { expected, actual };
+                    if !lossy {
+                        return Err(err);
+                    }
+                    walker.push(0, 4, &function.name, LayoutKind::Invalid(err.to_string()));
+                }
+            }
+            None if lossy => {
+                let reason = alloy_sol_types::Error::Overrun.to_string();
+                walker.push(0, 4, &function.name, LayoutKind::Invalid(reason));
+                return Ok(walker.finish());
+            }
+            None => return Err(alloy_sol_types::Error::Overrun.into()),
+        }
+
+        walker.walk_params(&types, &function.inputs, 4).map(Walker::finish)
+    }
+
+    /// Returns the annotated entries, ordered by offset.
+    #[inline]
+    pub fn entries(&self) -> &[LayoutEntry<'a>] {
+        &self.entries
+    }
+
+    /// Consumes the layout, returning the annotated entries.
+    #[inline]
+    pub fn into_entries(self) -> Vec<LayoutEntry<'a>> {
+        self.entries
+    }
+
+    /// Returns whether any entry is [`LayoutKind::Invalid`].
+    #[inline]
+    pub fn has_errors(&self) -> bool {
+        self.entries.iter().any(|entry| matches!(entry.kind, LayoutKind::Invalid(_)))
+    }
+}
+
+/// Walks ABI-encoded data, recording an entry for every word it consumes.
+///
+/// Positions are tracked as `base + decoder.offset()`, where `base` is the
+/// absolute offset of the start of the decoder's buffer.
+struct Walker<'a> {
+    data: &'a [u8],
+    /// The offset at which the parameters start, i.e. after the selector.
+    start: usize,
+    lossy: bool,
+    entries: Vec<LayoutEntry<'a>>,
+}
+
+impl<'a> Walker<'a> {
+    const fn new(data: &'a [u8], lossy: bool) -> Self {
+        Self { data, start: 0, lossy, entries: Vec::new() }
+    }
+
+    fn push(&mut self, offset: usize, len: usize, path: &str, kind: LayoutKind) {
+        let end = offset.saturating_add(len).min(self.data.len());
+        let data = self.data.get(offset..end).unwrap_or_default();
+        self.entries.push(LayoutEntry { offset, data, path: path.into(), kind });
+    }
+
+    fn walk_params(mut self, types: &[DynSolType], names: &[Param], start: usize) -> Result<Self> {
+        self.start = start;
+        let data = self.data;
+        let mut decoder = Decoder::new(&data[start..]);
+        let items = types.iter().enumerate().map(|(i, ty)| {
+            let (name, names) = component_names(None, names, i);
+            (ty, names, child_path("", name, i))
+        });
+        self.walk_seq(items, &mut decoder, start)?;
+        Ok(self)
+    }
+
+    /// Walks the elements of a sequence, recovering from errors in lossy mode
+    /// by skipping to the head of the next element.
+    fn walk_seq<'n>(
+        &mut self,
+        items: impl Iterator<Item = (&'n DynSolType, &'n [Param], String)>,
+        dec: &mut Decoder<'a>,
+        base: usize,
+    ) -> Result<()> {
+        for (ty, names, path) in items {
+            let head = dec.offset();
+            if let Err(e) = self.walk(ty, names, dec, base, &path) {
+                if !self.lossy {
+                    return Err(e);
+                }
+                self.push(
+                    base + head,
+                    Word::len_bytes(),
+                    &path,
+                    LayoutKind::Invalid(e.to_string()),
+                );
+                let head_words = if ty.is_dynamic() { 1 } else { ty.minimum_words() };
+                dec.set_offset(head + head_words * Word::len_bytes());
+            }
+        }
+        Ok(())
+    }
+
+    fn walk(
+        &mut self,
+        ty: &DynSolType,
+        names: &[Param],
+        dec: &mut Decoder<'a>,
+        base: usize,
+        path: &str,
+    ) -> Result<()> {
+        match ty {
+            DynSolType::Bytes | DynSolType::String => {
+                let (mut child, child_base) = self.indirection(dec, base, path)?;
+                let len = self.length(&mut child, child_base, path)?;
+                let at = child_base + child.offset();
+                child.take_slice(len)?;
+                let padded = words_for_len(len) * Word::len_bytes();
+                let padded = padded.min(self.data.len() - at);
+                for i in (0..padded).step_by(Word::len_bytes()) {
+                    self.push(at + i, (padded - i).min(Word::len_bytes()), path, LayoutKind::Data);
+                }
+            }
+            DynSolType::Array(inner) => {
+                let (mut child, child_base) = self.indirection(dec, base, path)?;
+                let len = self.length(&mut child, child_base, path)?;
+                let elems_base = child_base + child.offset();
+                let mut elems = child.raw_child()?;
+                // Same check as in `DynToken::decode_populate`. Zero-sized
+                // elements don't occupy any words, so there is nothing to walk.
+                let min_words = inner.minimum_words();
+                if min_words == 0 {
+                    return Ok(());
+                }
+                if elems.remaining_words() < min_words.saturating_mul(len) {
+                    return Err(alloy_sol_types::Error::Overrun.into());
+                }
+                let items = (0..len).map(|i| (&**inner, names, format!("{path}[{i}]")));
+                self.walk_seq(items, &mut elems, elems_base)?;
+            }
+            &DynSolType::FixedArray(ref inner, len) => {
+                let items = (0..len).map(|i| (&**inner, names, format!("{path}[{i}]")));
+                self.walk_fixed_seq(ty, items, dec, base, path)?;
+            }
+            as_tuple!(DynSolType tuple) => {
+                let items = tuple.iter().enumerate().map(|(i, component)| {
+                    let (name, names) = component_names(Some(ty), names, i);
+                    (component, names, child_path(path, name, i))
+                });
+                self.walk_fixed_seq(ty, items, dec, base, path)?;
+            }
+            _ => {
+                let at = base + dec.offset();
+                dec.take_word()?;
+                self.push(at, Word::len_bytes(), path, LayoutKind::Value(ty.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Walks a tuple or fixed array, which is encoded in place if static, or
+    /// in the tail if dynamic.
+    fn walk_fixed_seq<'n>(
+        &mut self,
+        ty: &DynSolType,
+        items: impl Iterator<Item = (&'n DynSolType, &'n [Param], String)>,
+        dec: &mut Decoder<'a>,
+        base: usize,
+        path: &str,
+    ) -> Result<()> {
+        if ty.is_dynamic() {
+            let (mut child, child_base) = self.indirection(dec, base, path)?;
+            self.walk_seq(items, &mut child, child_base)
+        } else {
+            let child_base = base + dec.offset();
+            let mut child = dec.raw_child()?;
+            self.walk_seq(items, &mut child, child_base)?;
+            dec.take_offset_from(&child);
+            Ok(())
+        }
+    }
+
+    /// Takes an offset word and follows it, returning the child decoder and
+    /// its absolute base offset.
+    fn indirection(
+        &mut self,
+        dec: &mut Decoder<'a>,
+        base: usize,
+        path: &str,
+    ) -> Result<(Decoder<'a>, usize)> {
+        let at = base + dec.offset();
+        let offset = dec.peek_offset()?;
+        let target = base + offset;
+        self.push(at, Word::len_bytes(), path, LayoutKind::Offset { target });
+        let child = dec.take_indirection()?;
+        Ok((child, target))
+    }
+
+    /// Takes a length word.
+    fn length(&mut self, dec: &mut Decoder<'a>, base: usize, path: &str) -> Result<usize> {
+        let at = base + dec.offset();
+        let len = dec.take_offset()?;
+        self.push(at, Word::len_bytes(), path, LayoutKind::Length(len));
+        Ok(len)
+    }
+
+    /// Fills in the words not covered by any entry and sorts the entries.
+    fn finish(mut self) -> AbiLayout<'a> {
+        let params = self.data.get(self.start..).unwrap_or_default();
+        let mut covered = vec![false; words_for_len(params.len())];
+        for entry in &self.entries {
+            if entry.offset < self.start || entry.data.is_empty() {
+                continue;
+            }
+            let first = (entry.offset - self.start) / Word::len_bytes();
+            let last = (entry.offset - self.start + entry.data.len() - 1) / Word::len_bytes();
+            for word in covered.iter_mut().take(last + 1).skip(first) {
+                *word = true;
+            }
+        }
+        for (i, _) in covered.into_iter().enumerate().filter(|(_, covered)| !covered) {
+            let offset = self.start + i * Word::len_bytes();
+            self.push(offset, Word::len_bytes(), "", LayoutKind::Unused);
+        }
+
+        // Stable, so entries at the same offset stay in walk order.
+        self.entries.sort_by_key(|entry| entry.offset);
+        AbiLayout { entries: self.entries }
+    }
+}
+
+/// Returns the name and the component names of the `i`th component of
+/// `parent`, preferring the JSON ABI names over custom struct property names.
+fn component_names<'n>(
+    parent: Option<&'n DynSolType>,
+    names: &'n [Param],
+    i: usize,
+) -> (Option<&'n str>, &'n [Param]) {
+    let param = names.get(i);
+    let name = param.map(|p| p.name.as_str()).filter(|name| !name.is_empty()).or_else(|| {
+        let (_, prop_names, _) = parent?.as_custom_struct()?;
+        prop_names.get(i).map(String::as_str)
+    });
+    (name, param.map(|p| &p.components[..]).unwrap_or_default())
+}
+
+fn child_path(parent: &str, name: Option<&str>, i: usize) -> String {
+    match (parent.is_empty(), name) {
+        (true, Some(name)) => name.into(),
+        (true, None) => i.to_string(),
+        (false, Some(name)) => format!("{parent}.{name}"),
+        (false, None) => format!("{parent}.{i}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::hex;
+
+    fn kinds<'l>(layout: &'l AbiLayout<'_>) -> Vec<(usize, &'l str, &'l LayoutKind)> {
+        layout.entries().iter().map(|e| (e.offset, e.path.as_str(), &e.kind)).collect()
+    }
+
+    #[test]
+    fn nested_dynamic_params() {
+        let func: Function = serde_json::from_str(
+            r#"{
+                "type": "function",
+                "name": "f",
+                "inputs": [
+                    {
+                        "name": "t",
+                        "type": "tuple",
+                        "components": [
+                            { "name": "x", "type": "uint8" },
+                            { "name": "s", "type": "string" }
+                        ]
+                    },
+                    { "name": "xs", "type": "uint16[]" }
+                ],
+                "outputs": [],
+                "stateMutability": "nonpayable"
+            }"#,
+        )
+        .unwrap();
+        let calldata = hex!(
+            "00000000"
+            "0000000000000000000000000000000000000000000000000000000000000040"
+            "00000000000000000000000000000000000000000000000000000000000000c0"
+            "0000000000000000000000000000000000000000000000000000000000000007"
+            "0000000000000000000000000000000000000000000000000000000000000040"
+            "0000000000000000000000000000000000000000000000000000000000000003"
+            "6162630000000000000000000000000000000000000000000000000000000000"
+            "0000000000000000000000000000000000000000000000000000000000000002"
+            "0000000000000000000000000000000000000000000000000000000000000001"
+            "0000000000000000000000000000000000000000000000000000000000000002"
+        );
+        let mut calldata = calldata.to_vec();
+        calldata[..4].copy_from_slice(&func.selector()[..]);
+
+        let layout = AbiLayout::from_function(&func, &calldata).unwrap();
+        assert!(!layout.has_errors());
+        assert_eq!(
+            kinds(&layout),
+            [
+                (0x00, "f", &LayoutKind::Selector),
+                (0x04, "t", &LayoutKind::Offset { target: 0x44 }),
+                (0x24, "xs", &LayoutKind::Offset { target: 0xc4 }),
+                (0x44, "t.x", &LayoutKind::Value(DynSolType::Uint(8))),
+                (0x64, "t.s", &LayoutKind::Offset { target: 0x84 }),
+                (0x84, "t.s", &LayoutKind::Length(3)),
+                (0xa4, "t.s", &LayoutKind::Data),
+                (0xc4, "xs", &LayoutKind::Length(2)),
+                (0xe4, "xs[0]", &LayoutKind::Value(DynSolType::Uint(16))),
+                (0x104, "xs[1]", &LayoutKind::Value(DynSolType::Uint(16))),
+            ]
+        );
+
+        // Strict and lossy agree on well-formed data.
+        assert_eq!(AbiLayout::from_function_lossy(&func, &calldata).unwrap(), layout);
+    }
+
+    #[test]
+    fn static_tuples_and_unused_words() {
+        let types = [
+            DynSolType::Tuple(vec![DynSolType::Bool, DynSolType::Address]),
+            DynSolType::FixedArray(Box::new(DynSolType::Uint(256)), 2),
+        ];
+        let data = hex!(
+            "0000000000000000000000000000000000000000000000000000000000000001"
+            "0000000000000000000000001111111111111111111111111111111111111111"
+            "0000000000000000000000000000000000000000000000000000000000000002"
+            "0000000000000000000000000000000000000000000000000000000000000003"
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"
+        );
+        let layout = AbiLayout::from_types(&types, &data).unwrap();
+        assert_eq!(
+            kinds(&layout),
+            [
+                (0x00, "0.0", &LayoutKind::Value(DynSolType::Bool)),
+                (0x20, "0.1", &LayoutKind::Value(DynSolType::Address)),
+                (0x40, "1[0]", &LayoutKind::Value(DynSolType::Uint(256))),
+                (0x60, "1[1]", &LayoutKind::Value(DynSolType::Uint(256))),
+                (0x80, "", &LayoutKind::Unused),
+            ]
+        );
+    }
+
+    #[test]
+    fn lossy_malformed() {
+        let types = [DynSolType::Bytes, DynSolType::Uint(256), DynSolType::String];
+        let data = hex!(
+            // offset out of bounds
+            "0000000000000000000000000000000000000000000000000000000000001000"
+            "000000000000000000000000000000000000000000000000000000000000002a"
+            "0000000000000000000000000000000000000000000000000000000000000060"
+            // length longer than the remaining data
+            "0000000000000000000000000000000000000000000000000000000000000040"
+            "6162630000000000000000000000000000000000000000000000000000000000"
+        );
+        assert!(AbiLayout::from_types(&types, &data).is_err());
+
+        let layout = AbiLayout::from_types_lossy(&types, &data);
+        assert!(layout.has_errors());
+        let overrun = alloy_sol_types::Error::Overrun.to_string();
+        assert_eq!(
+            kinds(&layout),
+            [
+                (0x00, "0", &LayoutKind::Offset { target: 0x1000 }),
+                (0x00, "0", &LayoutKind::Invalid(overrun.clone())),
+                (0x20, "1", &LayoutKind::Value(DynSolType::Uint(256))),
+                (0x40, "2", &LayoutKind::Offset { target: 0x60 }),
+                (0x40, "2", &LayoutKind::Invalid(overrun)),
+                (0x60, "2", &LayoutKind::Length(0x40)),
+                (0x80, "", &LayoutKind::Unused),
+            ]
+        );
+    }
+
+    #[test]
+    fn lossy_selector_mismatch() {
+        let func = Function::parse("f(uint256)").unwrap();
+        let calldata = hex!(
+            "deadbeef"
+            "0000000000000000000000000000000000000000000000000000000000000001"
+        );
+        assert!(matches!(
+            AbiLayout::from_function(&func, &calldata),
+            Err(Error::SelectorMismatch { .. })
+        ));
+
+        let layout = AbiLayout::from_function_lossy(&func, &calldata).unwrap();
+        assert!(layout.has_errors());
+        assert_eq!(layout.entries()[2].kind, LayoutKind::Value(DynSolType::Uint(256)));
+
+        let layout = AbiLayout::from_function_lossy(&func, &calldata[..2]).unwrap();
+        assert_eq!(layout.entries().len(), 1);
+        assert!(layout.has_errors());
+    }
+
+    #[test]
+    fn display() {
+        let data = hex!(
+            "0000000000000000000000000000000000000000000000000000000000000020"
+            "0000000000000000000000000000000000000000000000000000000000000001"
+            "6100000000000000000000000000000000000000000000000000000000000000"
+        );
+        let layout = AbiLayout::from_types(&[DynSolType::String], &data).unwrap();
+        assert_eq!(
+            layout.to_string(),
+            "\
+0x0000: 0000000000000000000000000000000000000000000000000000000000000020  0: offset -> 0x0020
+0x0020: 0000000000000000000000000000000000000000000000000000000000000001  0: length = 1
+0x0040: 6100000000000000000000000000000000000000000000000000000000000000  0: data
+"
+        );
+    }
+}
@@ -36,7 +36,13 @@ mod error;
 pub use error::{Error, Result};
 
 mod ext;
-pub use ext::{ErrorExt, EventExt, FunctionExt, JsonAbiExt};
+pub use ext::{ErrorExt, EventExt, FunctionExt, JsonAbiExt, SignatureDbExt};
+
+mod infer;
+pub use infer::InferredParams;
+
+mod layout;
+pub use layout::{AbiLayout, LayoutEntry, LayoutKind};
 
 mod specifier;
 pub use specifier::Specifier;
@@ -44,7 +50,9 @@ pub use specifier::Specifier;
 #[cfg(feature = "eip712")]
 pub mod eip712;
 #[cfg(feature = "eip712")]
-pub use eip712::{parser as eip712_parser, Eip712Types, PropertyDef, Resolver, TypeDef, TypedData};
+pub use eip712::{
+    parser as eip712_parser, Eip712Types, Eip712Version, PropertyDef, Resolver, TypeDef, TypedData,
+};
 
 #[doc(no_inline)]
 pub use alloy_sol_types::{
@@ -60,6 +60,8 @@ impl Specifier<DynSolType> for RootType<'_> {
             "bytes" => Ok(DynSolType::Bytes),
             "uint" => Ok(DynSolType::Uint(256)),
             "int" => Ok(DynSolType::Int(256)),
+            "fixed" => Ok(DynSolType::Fixed(128, 18)),
+            "ufixed" => Ok(DynSolType::Ufixed(128, 18)),
             #[cfg(feature = "seismic")]
             "saddress" => Ok(DynSolType::Saddress),
             #[cfg(feature = "seismic")]
@@ -89,6 +91,25 @@ impl Specifier<DynSolType> for RootType<'_> {
                     return Err(parser::Error::invalid_size(name).into());
                 }
 
+                if let Some((s, is_ufixed)) = name
+                    .strip_prefix("fixed")
+                    .map(|s| (s, false))
+                    .or_else(|| name.strip_prefix("ufixed").map(|s| (s, true)))
+                {
+                    if let Some((m, n)) = s.split_once('x') {
+                        if let (Ok(m), Ok(n)) = (m.parse::<usize>(), n.parse::<usize>()) {
+                            if m != 0 && m <= 256 && m % 8 == 0 && n <= 80 {
+                                return Ok(if is_ufixed {
+                                    DynSolType::Ufixed(m, n)
+                                } else {
+                                    DynSolType::Fixed(m, n)
+                                });
+                            }
+                        }
+                    }
+                    return Err(parser::Error::invalid_size(name).into());
+                }
+
                 // fast path both integer types
 
                 #[cfg(not(feature = "seismic"))]
@@ -356,6 +377,21 @@ mod tests {
         assert_eq!(parse("bytes32"), Ok(DynSolType::FixedBytes(32)));
     }
 
+    #[test]
+    fn it_parses_fixed_types() {
+        assert_eq!(parse("fixed"), Ok(DynSolType::Fixed(128, 18)));
+        assert_eq!(parse("ufixed"), Ok(DynSolType::Ufixed(128, 18)));
+        assert_eq!(parse("fixed8x0"), Ok(DynSolType::Fixed(8, 0)));
+        assert_eq!(
+            parse("ufixed256x80[]"),
+            Ok(DynSolType::Array(Box::new(DynSolType::Ufixed(256, 80))))
+        );
+        assert_eq!(parse("fixed64x10").unwrap().sol_type_name(), "fixed64x10");
+        for s in ["fixed0x1", "fixed7x1", "fixed264x1", "ufixed8x81", "fixed8", "ufixedx18"] {
+            assert!(parse(s).is_err(), "{s}");
+        }
+    }
+
     #[test]
     fn it_parses_complex_solidity_types() {
         assert_eq!(parse("uint256[]"), Ok(DynSolType::Array(Box::new(DynSolType::Uint(256)))));
@@ -34,6 +34,9 @@ pub use param::{EventParam, Param};
 
 pub use parser::{serde_state_mutability_compat, StateMutability};
 
+mod signature_db;
+pub use signature_db::SignatureDb;
+
 mod internal_type;
 pub use internal_type::InternalType;
 
@@ -0,0 +1,598 @@
+use crate::{AbiItem, Error, Event, EventParam, Function, JsonAbi, Param};
+use alloc::{borrow::Cow, collections::BTreeMap, string::String, vec::Vec};
+use alloy_primitives::{hex, Selector, B256};
+use core::str;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The magic prefix of the [binary format](SignatureDb::to_bytes).
+const MAGIC: &[u8; 6] = b"sigdb\x01";
+
+/// An offline database of function, event and error signatures.
+///
+/// Functions and errors are indexed by their [`Selector`], events by their
+/// topic 0, i.e. the [event selector](Event::selector). Selectors are not
+/// unique, so every lookup may return multiple items.
+///
+/// The database can be populated from [`JsonAbi`]s, [Human-Readable ABI]
+/// strings, and [4byte]-style text dumps. Items are deduplicated by their
+/// signature (and `indexed` parameters for events), keeping the first one
+/// inserted, so ABIs should be added before text dumps to retain parameter
+/// names.
+///
+/// The database can be stored either with [`serde`], as lists of JSON ABI
+/// items, or in a compact [binary format](Self::to_bytes).
+///
+/// See the `SignatureDbExt` trait in `alloy-dyn-abi` for decoding data with
+/// the matching items.
+///
+/// [Human-Readable ABI]: https://docs.ethers.org/v5/api/utils/abi/formats/#abi-formats--human-readable-abi
+/// [4byte]: https://www.4byte.directory
+///
+/// # Examples
+///
+/// ```
+/// # use alloy_json_abi::SignatureDb;
+/// # use alloy_primitives::hex;
+/// let mut db = SignatureDb::new();
+/// db.extend_signatures(["function transfer(address to, uint256 amount) returns (bool)"])?;
+/// db.extend_4byte(
+///     "0xa9059cbb,transfer(address,uint256)\n\
+///      0x70a08231 balanceOf(address)",
+/// )?;
+///
+/// let transfer = db.functions(hex!("a9059cbb").into());
+/// assert_eq!(transfer.len(), 1);
+/// assert_eq!(transfer[0].inputs[0].name, "to");
+/// assert_eq!(db.functions(hex!("70a08231").into())[0].name, "balanceOf");
+///
+/// let bytes = db.to_bytes();
+/// assert_eq!(SignatureDb::from_bytes(&bytes)?, db);
+/// # Ok::<(), alloy_sol_type_parser::Error>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SignatureDb {
+    functions: BTreeMap<Selector, Vec<Function>>,
+    events: BTreeMap<B256, Vec<Event>>,
+    errors: BTreeMap<Selector, Vec<Error>>,
+}
+
+impl From<&JsonAbi> for SignatureDb {
+    #[inline]
+    fn from(abi: &JsonAbi) -> Self {
+        let mut db = Self::new();
+        db.extend_abi(abi);
+        db
+    }
+}
+
+impl<'a> FromIterator<AbiItem<'a>> for SignatureDb {
+    fn from_iter<T: IntoIterator<Item = AbiItem<'a>>>(iter: T) -> Self {
+        let mut db = Self::new();
+        db.extend(iter);
+        db
+    }
+}
+
+impl<'a> Extend<AbiItem<'a>> for SignatureDb {
+    fn extend<T: IntoIterator<Item = AbiItem<'a>>>(&mut self, iter: T) {
+        for item in iter {
+            self.insert_item(item);
+        }
+    }
+}
+
+impl SignatureDb {
+    /// Creates an empty database.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the total number of items.
+    pub fn len(&self) -> usize {
+        self.functions.values().map(Vec::len).sum::<usize>()
+            + self.events.values().map(Vec::len).sum::<usize>()
+            + self.errors.values().map(Vec::len).sum::<usize>()
+    }
+
+    /// Returns true if the database contains no items.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.functions.is_empty() && self.events.is_empty() && self.errors.is_empty()
+    }
+
+    /// Returns all the functions with the given selector.
+    #[inline]
+    pub fn functions(&self, selector: Selector) -> &[Function] {
+        self.functions.get(&selector).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Returns all the events with the given topic 0.
+    #[inline]
+    pub fn events(&self, topic0: B256) -> &[Event] {
+        self.events.get(&topic0).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Returns all the errors with the given selector.
+    #[inline]
+    pub fn errors(&self, selector: Selector) -> &[Error] {
+        self.errors.get(&selector).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Returns an iterator over all the functions in the database.
+    #[inline]
+    pub fn all_functions(&self) -> impl Iterator<Item = &Function> {
+        self.functions.values().flatten()
+    }
+
+    /// Returns an iterator over all the events in the database.
+    #[inline]
+    pub fn all_events(&self) -> impl Iterator<Item = &Event> {
+        self.events.values().flatten()
+    }
+
+    /// Returns an iterator over all the errors in the database.
+    #[inline]
+    pub fn all_errors(&self) -> impl Iterator<Item = &Error> {
+        self.errors.values().flatten()
+    }
+
+    /// Inserts a function. Returns `false` if a function with the same
+    /// signature was already present.
+    pub fn insert_function(&mut self, function: Function) -> bool {
+        let functions = self.functions.entry(function.selector()).or_default();
+        let signature = function.signature();
+        if functions.iter().any(|f| f.signature() == signature) {
+            return false;
+        }
+        functions.push(function);
+        true
+    }
+
+    /// Inserts an event. Returns `false` if an event with the same signature
+    /// and `indexed` parameters was already present.
+    pub fn insert_event(&mut self, event: Event) -> bool {
+        let events = self.events.entry(event.selector()).or_default();
+        let signature = event.signature();
+        let same_indexed = |e: &Event| {
+            e.anonymous == event.anonymous
+                && e.inputs.iter().map(|p| p.indexed).eq(event.inputs.iter().map(|p| p.indexed))
+        };
+        if events.iter().any(|e| same_indexed(e) && e.signature() == signature) {
+            return false;
+        }
+        events.push(event);
+        true
+    }
+
+    /// Inserts an error. Returns `false` if an error with the same signature
+    /// was already present.
+    pub fn insert_error(&mut self, error: Error) -> bool {
+        let errors = self.errors.entry(error.selector()).or_default();
+        let signature = error.signature();
+        if errors.iter().any(|e| e.signature() == signature) {
+            return false;
+        }
+        errors.push(error);
+        true
+    }
+
+    /// Inserts a function, event or error. Other items are ignored.
+    ///
+    /// Returns `false` if the item was ignored or already present.
+    pub fn insert_item(&mut self, item: AbiItem<'_>) -> bool {
+        match item {
+            AbiItem::Function(f) => self.insert_function(f.into_owned()),
+            AbiItem::Event(e) => self.insert_event(e.into_owned()),
+            AbiItem::Error(e) => self.insert_error(e.into_owned()),
+            _ => false,
+        }
+    }
+
+    /// Inserts all the functions, events and errors of an ABI.
+    pub fn extend_abi(&mut self, abi: &JsonAbi) {
+        self.extend(abi.items());
+    }
+
+    /// Parses and inserts [Human-Readable ABI] strings, such as
+    /// `function transfer(address to, uint256 amount) returns (bool)`.
+    ///
+    /// Constructors are ignored.
+    ///
+    /// [Human-Readable ABI]: https://docs.ethers.org/v5/api/utils/abi/formats/#abi-formats--human-readable-abi
+    pub fn extend_signatures<'a, I: IntoIterator<Item = &'a str>>(
+        &mut self,
+        signatures: I,
+    ) -> parser::Result<()> {
+        for signature in signatures {
+            self.insert_item(AbiItem::parse(signature)?);
+        }
+        Ok(())
+    }
+
+    /// Parses and inserts a [4byte]-style text dump of signatures.
+    ///
+    /// Each line contains a hex-encoded selector followed by a separator
+    /// (`,`, `:`, or whitespace) and the signature, e.g.
+    /// `0xa9059cbb,transfer(address,uint256)`. 4-byte selectors are inserted
+    /// as functions, 32-byte selectors as events. Empty lines and lines
+    /// starting with `#` are skipped.
+    ///
+    /// Returns an error if a line cannot be parsed, or if the selector does
+    /// not match the signature.
+    ///
+    /// [4byte]: https://www.4byte.directory
+    pub fn extend_4byte(&mut self, text: &str) -> parser::Result<()> {
+        self.extend_text(text, false)
+    }
+
+    /// Parses and inserts a [4byte]-style text dump of error signatures.
+    ///
+    /// Errors share their selectors with functions, so dumps of error
+    /// signatures have the same format as function dumps. See
+    /// [`extend_4byte`](Self::extend_4byte) for the format.
+    ///
+    /// [4byte]: https://www.4byte.directory
+    pub fn extend_4byte_errors(&mut self, text: &str) -> parser::Result<()> {
+        self.extend_text(text, true)
+    }
+
+    fn extend_text(&mut self, text: &str, errors: bool) -> parser::Result<()> {
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let invalid = |msg: &str| {
+                parser::Error::new(format_args!("invalid signature on line {}: {msg}", i + 1))
+            };
+
+            let (hash, signature) = line
+                .split_once(|c: char| matches!(c, ',' | ':') || c.is_whitespace())
+                .ok_or_else(|| invalid("missing selector"))?;
+            let hash = hex::decode(hash).map_err(|_| invalid("invalid selector"))?;
+            let signature = signature.trim();
+            let mismatch = || invalid("selector does not match signature");
+            match hash.len() {
+                4 if errors => {
+                    let error = Error::parse(signature)?;
+                    if error.selector() != hash[..] {
+                        return Err(mismatch());
+                    }
+                    self.insert_error(error);
+                }
+                4 => {
+                    let function = Function::parse(signature)?;
+                    if function.selector() != hash[..] {
+                        return Err(mismatch());
+                    }
+                    self.insert_function(function);
+                }
+                32 if !errors => {
+                    let event = Event::parse(signature)?;
+                    if event.selector() != hash[..] {
+                        return Err(mismatch());
+                    }
+                    self.insert_event(event);
+                }
+                _ => return Err(invalid("invalid selector length")),
+            }
+        }
+        Ok(())
+    }
+
+    /// Encodes the database in a compact binary format.
+    ///
+    /// The format consists of a magic prefix, followed by the functions,
+    /// events, and errors sections. Each section is a big-endian `u32` count
+    /// followed by that many human-readable signatures, each prefixed by its
+    /// big-endian `u32` length. Selectors are recomputed when decoding.
+    ///
+    /// Signatures include top-level parameter names, `indexed`, state
+    /// mutability and function outputs, but not tuple component names or
+    /// internal types. Use the [`serde`] implementation to store those too.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(MAGIC.len() + 12 + self.len() * 64);
+        out.extend_from_slice(MAGIC);
+        write_section(&mut out, self.all_functions().map(compact_function));
+        write_section(&mut out, self.all_events().map(compact_event));
+        write_section(
+            &mut out,
+            self.all_errors()
+                .map(|e| compact_signature("error ", &e.name, e.inputs.iter().map(|p| (p, false)))),
+        );
+        out
+    }
+
+    /// Decodes a database from the [binary format](Self::to_bytes).
+    pub fn from_bytes(mut bytes: &[u8]) -> parser::Result<Self> {
+        bytes = bytes.strip_prefix(MAGIC).ok_or_else(invalid_encoding)?;
+        let functions = read_section(&mut bytes)?;
+        let events = read_section(&mut bytes)?;
+        let errors = read_section(&mut bytes)?;
+        if !bytes.is_empty() {
+            return Err(invalid_encoding());
+        }
+
+        let mut db = Self::new();
+        for signature in functions {
+            db.insert_function(Function::parse(signature)?);
+        }
+        for signature in events {
+            db.insert_event(Event::parse(signature)?);
+        }
+        for signature in errors {
+            db.insert_error(Error::parse(signature)?);
+        }
+        Ok(db)
+    }
+}
+
+fn invalid_encoding() -> parser::Error {
+    parser::Error::new("invalid signature database encoding")
+}
+
+fn write_section(out: &mut Vec<u8>, signatures: impl Iterator<Item = String>) {
+    let count_at = out.len();
+    out.extend_from_slice(&[0; 4]);
+    let mut count = 0u32;
+    for signature in signatures {
+        out.extend_from_slice(&(signature.len() as u32).to_be_bytes());
+        out.extend_from_slice(signature.as_bytes());
+        count += 1;
+    }
+    out[count_at..count_at + 4].copy_from_slice(&count.to_be_bytes());
+}
+
+fn read_section<'a>(bytes: &mut &'a [u8]) -> parser::Result<Vec<&'a str>> {
+    let count = read_u32(bytes)?;
+    let mut signatures = Vec::with_capacity(count.min(bytes.len() / 4));
+    for _ in 0..count {
+        let len = read_u32(bytes)?;
+        if bytes.len() < len {
+            return Err(invalid_encoding());
+        }
+        let (signature, rest) = bytes.split_at(len);
+        *bytes = rest;
+        signatures.push(str::from_utf8(signature).map_err(|_| invalid_encoding())?);
+    }
+    Ok(signatures)
+}
+
+fn read_u32(bytes: &mut &[u8]) -> parser::Result<usize> {
+    if bytes.len() < 4 {
+        return Err(invalid_encoding());
+    }
+    let (n, rest) = bytes.split_at(4);
+    *bytes = rest;
+    Ok(u32::from_be_bytes(n.try_into().unwrap()) as usize)
+}
+
+fn compact_function(function: &Function) -> String {
+    let inputs = function.inputs.iter().map(|p| (p, false));
+    let mut sig = compact_signature("function ", &function.name, inputs);
+    if let Some(state_mutability) = function.state_mutability.as_str() {
+        sig.push(' ');
+        sig.push_str(state_mutability);
+    }
+    if !function.outputs.is_empty() {
+        sig.push_str(&compact_signature(
+            " returns ",
+            "",
+            function.outputs.iter().map(|p| (p, false)),
+        ));
+    }
+    sig
+}
+
+fn compact_event(event: &Event) -> String {
+    let inputs = event.inputs.iter().map(|p| (p, p.indexed));
+    let mut sig = compact_signature("event ", &event.name, inputs);
+    if event.anonymous {
+        sig.push_str(" anonymous");
+    }
+    sig
+}
+
+/// Formats `{prefix}{name}({type} [indexed] [name], ...)`.
+///
+/// Tuple component names are omitted, as the signature parser does not
+/// support them.
+fn compact_signature<'a, P: ParamLike + 'a>(
+    prefix: &str,
+    name: &str,
+    params: impl Iterator<Item = (&'a P, bool)>,
+) -> String {
+    let mut sig = String::with_capacity(prefix.len() + name.len() + 64);
+    sig.push_str(prefix);
+    sig.push_str(name);
+    sig.push('(');
+    for (i, (param, indexed)) in params.enumerate() {
+        if i > 0 {
+            sig.push_str(", ");
+        }
+        sig.push_str(&param.selector_type());
+        if indexed {
+            sig.push_str(" indexed");
+        }
+        if !param.name().is_empty() {
+            sig.push(' ');
+            sig.push_str(param.name());
+        }
+    }
+    sig.push(')');
+    sig
+}
+
+/// Common accessors of [`Param`] and [`EventParam`].
+trait ParamLike {
+    fn name(&self) -> &str;
+    fn selector_type(&self) -> Cow<'_, str>;
+}
+
+impl ParamLike for Param {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn selector_type(&self) -> Cow<'_, str> {
+        self.selector_type()
+    }
+}
+
+impl ParamLike for EventParam {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn selector_type(&self) -> Cow<'_, str> {
+        self.selector_type()
+    }
+}
+
+/// The serialized representation of a [`SignatureDb`]: lists of JSON ABI
+/// items.
+#[derive(Serialize)]
+struct ReprRef<'a> {
+    functions: Vec<&'a Function>,
+    events: Vec<&'a Event>,
+    errors: Vec<&'a Error>,
+}
+
+/// See [`ReprRef`].
+#[derive(Deserialize)]
+struct Repr {
+    functions: Vec<Function>,
+    events: Vec<Event>,
+    errors: Vec<Error>,
+}
+
+impl Serialize for SignatureDb {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ReprRef {
+            functions: self.all_functions().collect(),
+            events: self.all_events().collect(),
+            errors: self.all_errors().collect(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SignatureDb {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Repr { functions, events, errors } = Repr::deserialize(deserializer)?;
+        let mut db = Self::new();
+        functions.into_iter().for_each(|f| _ = db.insert_function(f));
+        events.into_iter().for_each(|e| _ = db.insert_event(e));
+        errors.into_iter().for_each(|e| _ = db.insert_error(e));
+        Ok(db)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{b256, hex};
+
+    const TRANSFER_TOPIC: B256 =
+        b256!("0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+
+    fn db() -> SignatureDb {
+        let mut abi = JsonAbi::parse([
+            "function transfer(address to, uint256 amount) returns (bool)",
+            "event Transfer(address indexed from, address indexed to, uint256 value)",
+            "event Anon(uint256 indexed a) anonymous",
+            "error InsufficientBalance(uint256 available, uint256 required)",
+        ])
+        .unwrap();
+        let submit: Function = serde_json::from_str(
+            r#"{"type":"function","name":"submit","stateMutability":"payable","outputs":[],
+            "inputs":[{"name":"orders","type":"tuple[]","components":[
+                {"name":"id","type":"uint256"},{"name":"data","type":"bytes[]"}]}]}"#,
+        )
+        .unwrap();
+        abi.functions.insert(submit.name.clone(), vec![submit]);
+        let mut db = SignatureDb::from(&abi);
+        db.extend_4byte(
+            "# 4byte dump\n\
+             0xa9059cbb,transfer(address,uint256)\n\
+             \n\
+             a9059cbb: many_msg_babbage(bytes1)\n\
+             0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef Transfer(address,address,uint256)",
+        )
+        .unwrap();
+        db
+    }
+
+    #[test]
+    fn lookup() {
+        let db = db();
+        assert_eq!(db.len(), 7);
+
+        // Duplicate signatures keep the first item, collisions are kept.
+        let transfer = db.functions(hex!("a9059cbb").into());
+        assert_eq!(transfer.len(), 2);
+        assert_eq!(
+            transfer[0].full_signature(),
+            "function transfer(address to, uint256 amount) returns (bool)"
+        );
+        assert_eq!(transfer[1].signature(), "many_msg_babbage(bytes1)");
+
+        // Events with different `indexed` parameters are different items.
+        let events = db.events(TRANSFER_TOPIC);
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].num_topics(), 3);
+        assert_eq!(events[1].num_topics(), 1);
+
+        let error = &db.errors(hex!("cf479181").into())[0];
+        assert_eq!(error.name, "InsufficientBalance");
+        assert!(db.errors(hex!("a9059cbb").into()).is_empty());
+
+        let mut errors = SignatureDb::new();
+        errors.extend_4byte_errors("0xcf479181 InsufficientBalance(uint256,uint256)").unwrap();
+        assert_eq!(errors.errors(hex!("cf479181").into()).len(), 1);
+        assert!(errors.functions(hex!("cf479181").into()).is_empty());
+    }
+
+    #[test]
+    fn invalid_text() {
+        let mut db = SignatureDb::new();
+        let err = db.extend_4byte("0xdeadbeef,transfer(address,uint256)").unwrap_err();
+        assert!(err.to_string().contains("line 1"), "{err}");
+        assert!(db.extend_4byte("transfer(address,uint256)").is_err());
+        assert!(db.extend_4byte("0xa9059c transfer(address,uint256)").is_err());
+        assert!(db
+            .extend_4byte_errors(&format!("{TRANSFER_TOPIC} Transfer(address,address,uint256)"))
+            .is_err());
+        assert!(db.is_empty());
+    }
+
+    #[test]
+    fn roundtrip() {
+        let db = db();
+
+        let json = serde_json::to_string(&db).unwrap();
+        assert_eq!(serde_json::from_str::<SignatureDb>(&json).unwrap(), db);
+
+        // The binary format drops tuple component names.
+        let bytes = db.to_bytes();
+        let decoded = SignatureDb::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.len(), db.len());
+        for (a, b) in decoded.all_functions().zip(db.all_functions()) {
+            assert_eq!(a.signature(), b.signature());
+            assert_eq!(a.state_mutability, b.state_mutability);
+            if a.name != "submit" {
+                assert_eq!(a, b);
+            }
+        }
+        assert!(decoded.all_events().eq(db.all_events()));
+        assert!(decoded.all_errors().eq(db.all_errors()));
+        let submit = &decoded
+            .functions(db.all_functions().find(|f| f.name == "submit").unwrap().selector())[0];
+        assert_eq!(submit.signature(), "submit((uint256,bytes[])[])");
+        assert_eq!(submit.inputs[0].name, "orders");
+        assert!(SignatureDb::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        assert!(SignatureDb::from_bytes(&bytes[1..]).is_err());
+    }
+}
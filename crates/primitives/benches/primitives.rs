@@ -1,6 +1,6 @@
 #![allow(unknown_lints, clippy::incompatible_msrv, missing_docs)]
 
-use alloy_primitives::{keccak256, Address, B256};
+use alloy_primitives::{keccak256, keccak256_batch, keccak256_many, Address, B256};
 use criterion::{criterion_group, criterion_main, Criterion};
 use std::hint::black_box;
 
@@ -21,6 +21,22 @@ fn primitives(c: &mut Criterion) {
             black_box(&out);
         });
     });
+    g.bench_function("keccak256/32x4", |b| {
+        let inputs: [B256; 4] = std::array::from_fn(|_| B256::random());
+        b.iter(|| black_box(black_box(&inputs).map(keccak256)));
+    });
+    g.bench_function("keccak256_many/32x4", |b| {
+        let inputs: [B256; 4] = std::array::from_fn(|_| B256::random());
+        b.iter(|| black_box(keccak256_many(black_box(&inputs).each_ref().map(|x| x.as_slice()))));
+    });
+    g.bench_function("keccak256/32x4096", |b| {
+        let inputs: Vec<B256> = (0..4096).map(|_| B256::random()).collect();
+        b.iter(|| black_box(black_box(&inputs).iter().map(keccak256).collect::<Vec<_>>()));
+    });
+    g.bench_function("keccak256_batch/32x4096", |b| {
+        let inputs: Vec<B256> = (0..4096).map(|_| B256::random()).collect();
+        b.iter(|| black_box(keccak256_batch(black_box(&inputs))));
+    });
     g.finish();
 }
 
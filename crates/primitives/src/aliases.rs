@@ -1,6 +1,6 @@
 //! Type aliases for common primitive types.
 
-use crate::{FixedBytes, Signed, Uint};
+use crate::{Fixed, FixedBytes, Signed, UFixed, Uint};
 
 pub use ruint::aliases::{U0, U1, U1024, U2048, U320, U384, U4096, U448};
 
@@ -75,6 +75,8 @@ int_aliases! {
     Hash,
     Debug,
 )]
+#[cfg_attr(feature = "diesel", derive(diesel::AsExpression, diesel::FromSqlRow))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Binary))]
 pub struct SUInt<const BITS: usize, const LIMBS: usize>(pub Uint<BITS, LIMBS>);
 
 #[cfg(feature = "seismic")]
@@ -90,6 +92,8 @@ pub struct SUInt<const BITS: usize, const LIMBS: usize>(pub Uint<BITS, LIMBS>);
     Hash,
     Debug,
 )]
+#[cfg_attr(feature = "diesel", derive(diesel::AsExpression, diesel::FromSqlRow))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Binary))]
 pub struct SInt<const BITS: usize, const LIMBS: usize>(pub Signed<BITS, LIMBS>);
 
 #[cfg(feature = "seismic")]
@@ -106,13 +110,15 @@ macro_rules! sint_aliases {
 #[cfg(feature = "seismic")]
 #[derive(Copy, Clone, Debug, PartialEq)]
 /// Seismic-shielded address type. Preimage is an address
+#[cfg_attr(feature = "diesel", derive(diesel::AsExpression, diesel::FromSqlRow))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Binary))]
 pub struct SAddress(pub crate::Address);
 
 #[cfg(all(feature = "seismic", feature = "arbitrary"))]
 impl arbitrary::Arbitrary<'_> for SAddress {
     fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
         let arbitrary_addr = u.arbitrary::<crate::Address>()?;
-        Ok(SAddress(arbitrary_addr))
+        Ok(Self(arbitrary_addr))
     }
 }
 
@@ -120,7 +126,7 @@ impl arbitrary::Arbitrary<'_> for SAddress {
 impl<const BITS: usize, const LIMBS: usize> arbitrary::Arbitrary<'_> for SUInt<BITS, LIMBS> {
     fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
         let arbitrary_uint = u.arbitrary::<Uint<BITS, LIMBS>>()?;
-        Ok(SUInt(arbitrary_uint))
+        Ok(Self(arbitrary_uint))
     }
 }
 
@@ -128,7 +134,7 @@ impl<const BITS: usize, const LIMBS: usize> arbitrary::Arbitrary<'_> for SUInt<B
 impl<const BITS: usize, const LIMBS: usize> arbitrary::Arbitrary<'_> for SInt<BITS, LIMBS> {
     fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
         let arbitrary_signed = u.arbitrary::<Signed<BITS, LIMBS>>()?;
-        Ok(SInt(arbitrary_signed))
+        Ok(Self(arbitrary_signed))
     }
 }
 
@@ -171,6 +177,14 @@ sint_aliases! {
    SU256, SI256<256, 4>,
 }
 
+/// Solidity's `fixed` type: a [signed fixed-point number][Fixed] with 128 bits
+/// and 18 decimals.
+pub type Fixed128x18 = Fixed<128, 2, 18>;
+
+/// Solidity's `ufixed` type: an [unsigned fixed-point number][UFixed] with 128
+/// bits and 18 decimals.
+pub type UFixed128x18 = UFixed<128, 2, 18>;
+
 macro_rules! fixed_bytes_aliases {
     ($($(#[$attr:meta])* $name:ident<$N:literal>),* $(,)?) => {$(
         #[doc = concat!($N, "-byte [fixed byte-array][FixedBytes] type.")]
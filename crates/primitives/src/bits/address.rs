@@ -490,6 +490,58 @@ impl Address {
         Self::from_word(keccak256(buffer))
     }
 
+    /// Computes the `CREATE3` address of a smart contract deployed by `self`
+    /// with the given salt.
+    ///
+    /// `CREATE3` deploys a minimal proxy with `CREATE2`, which in turn deploys
+    /// the contract with `CREATE` at nonce 1, so the address does not depend
+    /// on the contract's init code:
+    ///
+    /// `proxy = keccak256(0xff ++ address ++ salt ++ keccak256(0x67363d3d37363d34f03d5260086018f3))[12:]`
+    /// `keccak256(rlp([proxy, 1]))[12:]`
+    ///
+    /// This is the scheme used by the [Solady] and [0xSequence] `CREATE3`
+    /// libraries. Factories that hash the salt with the caller before
+    /// deploying must be given the hashed salt.
+    ///
+    /// [Solady]: https://github.com/Vectorized/solady/blob/main/src/utils/CREATE3.sol
+    /// [0xSequence]: https://github.com/0xsequence/create3
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{address, Address, B256};
+    /// let deployer = address!("0x0000000000000000000000000000000000000001");
+    /// // The address only depends on the deployer and the salt.
+    /// let a = deployer.create3(B256::ZERO);
+    /// assert_ne!(a, deployer.create3(B256::with_last_byte(1)));
+    /// ```
+    #[must_use]
+    pub fn create3<S>(&self, salt: S) -> Self
+    where
+        // not `AsRef` because `[u8; N]` does not implement `AsRef<[u8; N]>`
+        S: Borrow<[u8; 32]>,
+    {
+        self._create3(salt.borrow())
+    }
+
+    // non-generic inner function
+    fn _create3(&self, salt: &[u8; 32]) -> Self {
+        /// `keccak256(0x67363d3d37363d34f03d5260086018f3)`
+        const PROXY_INIT_CODE_HASH: [u8; 32] =
+            crate::hex!("21c35dbe1b344a2488cf3321d6ce542f8e9f305544ff09e4993a62319a497c1f");
+
+        let proxy = self._create2(salt, &PROXY_INIT_CODE_HASH);
+
+        // rlp([proxy, 1])
+        let mut buffer = [0; 23];
+        buffer[0] = 0xd6;
+        buffer[1] = 0x94;
+        buffer[2..22].copy_from_slice(proxy.as_slice());
+        buffer[22] = 0x01;
+        Self::from_word(keccak256(buffer))
+    }
+
     /// Instantiate by hashing public key bytes.
     ///
     /// # Panics
@@ -829,6 +881,18 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(feature = "rlp")]
+    fn create3() {
+        let proxy_init_code_hash = keccak256(hex!("67363d3d37363d34f03d5260086018f3"));
+        for from in [Address::ZERO, Address::repeat_byte(0xee)] {
+            for salt in [[0; 32], [0x42; 32]] {
+                let proxy = from.create2(salt, proxy_init_code_hash);
+                assert_eq!(from.create3(salt), proxy.create(1));
+            }
+        }
+    }
+
     #[test]
     fn test_raw_public_key_to_address() {
         let addr = "0Ac1dF02185025F65202660F8167210A80dD5086".parse::<Address>().unwrap();
@@ -58,3 +58,223 @@ where
         Ok(Self::from_erc2098(bytes))
     }
 }
+
+#[cfg(feature = "seismic")]
+mod seismic {
+    use super::*;
+    use crate::{
+        aliases::{SInt, SUInt},
+        Address, FlaggedStorage, SAddress, Signed, Uint,
+    };
+
+    impl<Db> ToSql<Binary, Db> for SAddress
+    where
+        for<'c> Db: Backend<BindCollector<'c> = RawBytesBindCollector<Db>>,
+    {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Db>) -> SerResult {
+            <Address as ToSql<Binary, Db>>::to_sql(&self.0, out)
+        }
+    }
+
+    impl<Db: Backend> FromSql<Binary, Db> for SAddress
+    where
+        *const [u8]: FromSql<Binary, Db>,
+    {
+        fn from_sql(bytes: Db::RawValue<'_>) -> DeserResult<Self> {
+            <Address as FromSql<Binary, Db>>::from_sql(bytes).map(Self)
+        }
+    }
+
+    impl<const BITS: usize, const LIMBS: usize, Db> ToSql<Binary, Db> for SUInt<BITS, LIMBS>
+    where
+        for<'c> Db: Backend<BindCollector<'c> = RawBytesBindCollector<Db>>,
+    {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Db>) -> SerResult {
+            <Uint<BITS, LIMBS> as ToSql<Binary, Db>>::to_sql(&self.0, out)
+        }
+    }
+
+    impl<const BITS: usize, const LIMBS: usize, Db: Backend> FromSql<Binary, Db> for SUInt<BITS, LIMBS>
+    where
+        *const [u8]: FromSql<Binary, Db>,
+    {
+        fn from_sql(bytes: Db::RawValue<'_>) -> DeserResult<Self> {
+            <Uint<BITS, LIMBS> as FromSql<Binary, Db>>::from_sql(bytes).map(Self)
+        }
+    }
+
+    /// Big-endian two's complement, like the raw [`Uint`] representation.
+    impl<const BITS: usize, const LIMBS: usize, Db> ToSql<Binary, Db> for SInt<BITS, LIMBS>
+    where
+        for<'c> Db: Backend<BindCollector<'c> = RawBytesBindCollector<Db>>,
+    {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Db>) -> SerResult {
+            out.write_all(&self.0.into_raw().to_be_bytes_vec())?;
+            Ok(IsNull::No)
+        }
+    }
+
+    impl<const BITS: usize, const LIMBS: usize, Db: Backend> FromSql<Binary, Db> for SInt<BITS, LIMBS>
+    where
+        *const [u8]: FromSql<Binary, Db>,
+    {
+        fn from_sql(bytes: Db::RawValue<'_>) -> DeserResult<Self> {
+            <Uint<BITS, LIMBS> as FromSql<Binary, Db>>::from_sql(bytes)
+                .map(|raw| Self(Signed::from_raw(raw)))
+        }
+    }
+
+    /// See [`FlaggedStorage::to_be_bytes`].
+    impl<Db> ToSql<Binary, Db> for FlaggedStorage
+    where
+        for<'c> Db: Backend<BindCollector<'c> = RawBytesBindCollector<Db>>,
+    {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Db>) -> SerResult {
+            out.write_all(&self.to_be_bytes())?;
+            Ok(IsNull::No)
+        }
+    }
+
+    impl<Db: Backend> FromSql<Binary, Db> for FlaggedStorage
+    where
+        *const [u8]: FromSql<Binary, Db>,
+    {
+        fn from_sql(bytes: Db::RawValue<'_>) -> DeserResult<Self> {
+            let bytes: *const [u8] = FromSql::<Binary, Db>::from_sql(bytes)?;
+            let bytes = unsafe { &*bytes };
+            Self::try_from_be_slice(bytes).ok_or_else(|| "Invalid flagged storage".into())
+        }
+    }
+}
+
+/// Round trips through the raw byte representation of the MySQL and Postgres
+/// backends, without a database.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::B256;
+    use diesel::{
+        mysql::{Mysql, MysqlType, MysqlValue},
+        pg::{Pg, PgMetadataLookup, PgTypeMetadata, PgValue},
+        query_builder::BindCollector,
+    };
+    use std::num::NonZeroU32;
+
+    /// A backend whose values can be built from raw bytes.
+    trait RawBackend: Backend + Sized {
+        fn to_bytes<T: ToSql<Binary, Self>>(value: &T) -> Vec<u8>;
+
+        fn from_bytes<T: FromSql<Binary, Self>>(bytes: &[u8]) -> DeserResult<T>;
+
+        fn roundtrip<T: ToSql<Binary, Self> + FromSql<Binary, Self>>(value: T) -> T {
+            Self::from_bytes(&Self::to_bytes(&value)).unwrap()
+        }
+    }
+
+    impl RawBackend for Mysql {
+        fn to_bytes<T: ToSql<Binary, Self>>(value: &T) -> Vec<u8> {
+            let mut collector = RawBytesBindCollector::<Self>::new();
+            collector.push_bound_value::<Binary, T>(value, &mut ()).unwrap();
+            collector.binds.pop().unwrap().unwrap()
+        }
+
+        fn from_bytes<T: FromSql<Binary, Self>>(bytes: &[u8]) -> DeserResult<T> {
+            T::from_sql(MysqlValue::new(bytes, MysqlType::Blob))
+        }
+    }
+
+    /// Binary values have a static type OID, so no lookup is ever made.
+    struct NoLookup;
+
+    impl PgMetadataLookup for NoLookup {
+        fn lookup_type(&mut self, _type_name: &str, _schema: Option<&str>) -> PgTypeMetadata {
+            unreachable!()
+        }
+    }
+
+    impl RawBackend for Pg {
+        fn to_bytes<T: ToSql<Binary, Self>>(value: &T) -> Vec<u8> {
+            let mut collector = RawBytesBindCollector::<Self>::new();
+            collector.push_bound_value::<Binary, T>(value, &mut NoLookup).unwrap();
+            collector.binds.pop().unwrap().unwrap()
+        }
+
+        fn from_bytes<T: FromSql<Binary, Self>>(bytes: &[u8]) -> DeserResult<T> {
+            // `bytea`
+            let oid = NonZeroU32::new(17).unwrap();
+            T::from_sql(PgValue::new(bytes, &oid))
+        }
+    }
+
+    fn roundtrip<Db>()
+    where
+        *const [u8]: FromSql<Binary, Db>,
+        for<'c> Db: RawBackend + Backend<BindCollector<'c> = RawBytesBindCollector<Db>>,
+    {
+        let hash = B256::repeat_byte(0xa1);
+        assert_eq!(Db::roundtrip(hash), hash);
+        let signature = Signature::from_scalars_and_parity(hash, B256::with_last_byte(7), true);
+        assert_eq!(Db::to_bytes(&signature), signature.as_erc2098());
+        assert_eq!(Db::roundtrip(signature), signature);
+        assert!(Db::from_bytes::<Signature>(&[0; 65]).is_err());
+    }
+
+    #[cfg(feature = "seismic")]
+    fn roundtrip_seismic<Db>()
+    where
+        *const [u8]: FromSql<Binary, Db>,
+        for<'c> Db: RawBackend + Backend<BindCollector<'c> = RawBytesBindCollector<Db>>,
+    {
+        use crate::{
+            aliases::{SInt, SUInt},
+            Address, FlaggedStorage, SAddress, I256, U256,
+        };
+
+        let address = SAddress(Address::repeat_byte(0xd8));
+        assert_eq!(Db::to_bytes(&address), address.0.as_slice());
+        assert_eq!(Db::roundtrip(address), address);
+
+        let uint = SUInt(U256::from(0x1234));
+        assert_eq!(Db::to_bytes(&uint), U256::from(0x1234).to_be_bytes::<32>());
+        assert_eq!(Db::roundtrip(uint), uint);
+
+        let int = SInt(I256::try_from(-42).unwrap());
+        assert_eq!(Db::to_bytes(&int), int.0.into_raw().to_be_bytes::<32>());
+        assert_eq!(Db::roundtrip(int), int);
+
+        for storage in
+            [FlaggedStorage::new(U256::MAX, true), FlaggedStorage::new(U256::from(7), false)]
+        {
+            let mut bytes = Db::to_bytes(&storage);
+            assert_eq!(bytes, storage.to_be_bytes());
+            assert_eq!(Db::from_bytes::<FlaggedStorage>(&bytes).unwrap(), storage);
+
+            bytes[32] = 2;
+            assert!(Db::from_bytes::<FlaggedStorage>(&bytes).is_err());
+        }
+        assert!(Db::from_bytes::<FlaggedStorage>(&[0; 32]).is_err());
+        assert!(Db::from_bytes::<FlaggedStorage>(&[]).is_err());
+    }
+
+    #[test]
+    fn mysql_roundtrip() {
+        roundtrip::<Mysql>();
+    }
+
+    #[test]
+    fn postgres_roundtrip() {
+        roundtrip::<Pg>();
+    }
+
+    #[test]
+    #[cfg(feature = "seismic")]
+    fn mysql_roundtrip_seismic() {
+        roundtrip_seismic::<Mysql>();
+    }
+
+    #[test]
+    #[cfg(feature = "seismic")]
+    fn postgres_roundtrip_seismic() {
+        roundtrip_seismic::<Pg>();
+    }
+}
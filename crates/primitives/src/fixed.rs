@@ -0,0 +1,284 @@
+//! Fixed-point decimal numbers.
+
+use crate::{Sign, Signed, Uint};
+use alloc::string::{String, ToString};
+use core::{fmt, str::FromStr};
+
+/// The error type that is returned when parsing a fixed-point number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseFixedError {
+    /// The string is empty or contains a character that is not a decimal
+    /// digit, a single decimal point, or a leading sign.
+    InvalidDigit,
+    /// The number has more fractional digits than the type's decimals.
+    TooManyDecimals,
+    /// The number does not fit in the underlying integer.
+    Overflow,
+}
+
+impl core::error::Error for ParseFixedError {}
+
+impl fmt::Display for ParseFixedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::InvalidDigit => "invalid digit found in fixed-point number",
+            Self::TooManyDecimals => "too many decimals for fixed-point number",
+            Self::Overflow => "number does not fit in the fixed-point type",
+        })
+    }
+}
+
+/// Signed fixed-point decimal number, with `DECIMALS` decimal places, backed
+/// by a [`Signed`] integer.
+///
+/// This is the Rust representation of the Solidity `fixedMxN` type, where the
+/// value is `raw / 10^N`.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{aliases::Fixed128x18, I128};
+///
+/// let x: Fixed128x18 = "-1.5".parse()?;
+/// assert_eq!(x.into_raw(), I128::try_from(-1_500_000_000_000_000_000i128).unwrap());
+/// assert_eq!(x.to_string(), "-1.5");
+/// # Ok::<_, alloy_primitives::ParseFixedError>(())
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed<const BITS: usize, const LIMBS: usize, const DECIMALS: usize>(Signed<BITS, LIMBS>);
+
+/// Unsigned fixed-point decimal number, with `DECIMALS` decimal places,
+/// backed by a [`Uint`].
+///
+/// This is the Rust representation of the Solidity `ufixedMxN` type, where
+/// the value is `raw / 10^N`.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{aliases::UFixed128x18, U128};
+///
+/// let x: UFixed128x18 = "0.25".parse()?;
+/// assert_eq!(x.into_raw(), U128::from(250_000_000_000_000_000u128));
+/// assert_eq!(x.to_string(), "0.25");
+/// # Ok::<_, alloy_primitives::ParseFixedError>(())
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UFixed<const BITS: usize, const LIMBS: usize, const DECIMALS: usize>(Uint<BITS, LIMBS>);
+
+impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> Fixed<BITS, LIMBS, DECIMALS> {
+    /// The number of decimal places.
+    pub const DECIMALS: usize = DECIMALS;
+
+    /// Zero.
+    pub const ZERO: Self = Self(Signed::ZERO);
+
+    /// The minimum value.
+    pub const MIN: Self = Self(Signed::MIN);
+
+    /// The maximum value.
+    pub const MAX: Self = Self(Signed::MAX);
+
+    /// Creates a new fixed-point number from its raw integer representation,
+    /// i.e. the value multiplied by `10^DECIMALS`.
+    #[inline]
+    pub const fn from_raw(raw: Signed<BITS, LIMBS>) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw integer representation.
+    #[inline]
+    pub const fn into_raw(self) -> Signed<BITS, LIMBS> {
+        self.0
+    }
+
+    /// Returns `true` if the number is negative.
+    #[inline]
+    pub const fn is_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> UFixed<BITS, LIMBS, DECIMALS> {
+    /// The number of decimal places.
+    pub const DECIMALS: usize = DECIMALS;
+
+    /// Zero.
+    pub const ZERO: Self = Self(Uint::ZERO);
+
+    /// The maximum value.
+    pub const MAX: Self = Self(Uint::MAX);
+
+    /// Creates a new fixed-point number from its raw integer representation,
+    /// i.e. the value multiplied by `10^DECIMALS`.
+    #[inline]
+    pub const fn from_raw(raw: Uint<BITS, LIMBS>) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw integer representation.
+    #[inline]
+    pub const fn into_raw(self) -> Uint<BITS, LIMBS> {
+        self.0
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> fmt::Display
+    for Fixed<BITS, LIMBS, DECIMALS>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (sign, abs) = self.0.into_sign_and_abs();
+        sign.fmt(f)?;
+        fmt_abs(abs, DECIMALS, f)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> fmt::Display
+    for UFixed<BITS, LIMBS, DECIMALS>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_abs(self.0, DECIMALS, f)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> fmt::Debug
+    for Fixed<BITS, LIMBS, DECIMALS>
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> fmt::Debug
+    for UFixed<BITS, LIMBS, DECIMALS>
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> FromStr
+    for Fixed<BITS, LIMBS, DECIMALS>
+{
+    type Err = ParseFixedError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (sign, s) = match s.as_bytes().first() {
+            Some(b'+') => (Sign::Positive, &s[1..]),
+            Some(b'-') => (Sign::Negative, &s[1..]),
+            _ => (Sign::Positive, s),
+        };
+        let abs = parse_abs(s, DECIMALS)?;
+        Signed::checked_from_sign_and_abs(sign, abs).map(Self).ok_or(ParseFixedError::Overflow)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, const DECIMALS: usize> FromStr
+    for UFixed<BITS, LIMBS, DECIMALS>
+{
+    type Err = ParseFixedError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_abs(s.strip_prefix('+').unwrap_or(s), DECIMALS).map(Self)
+    }
+}
+
+/// Formats `abs / 10^decimals` without trailing fractional zeros.
+fn fmt_abs<const BITS: usize, const LIMBS: usize>(
+    abs: Uint<BITS, LIMBS>,
+    decimals: usize,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    let digits = abs.to_string();
+    let (int, fract) = if digits.len() > decimals {
+        digits.split_at(digits.len() - decimals)
+    } else {
+        ("0", digits.as_str())
+    };
+    f.write_str(int)?;
+
+    let fract = fract.trim_end_matches('0');
+    if !fract.is_empty() {
+        f.write_str(".")?;
+        for _ in digits.len()..decimals {
+            f.write_str("0")?;
+        }
+        f.write_str(fract)?;
+    }
+    Ok(())
+}
+
+/// Parses an unsigned decimal number into its raw representation with
+/// `decimals` decimal places.
+fn parse_abs<const BITS: usize, const LIMBS: usize>(
+    s: &str,
+    decimals: usize,
+) -> Result<Uint<BITS, LIMBS>, ParseFixedError> {
+    // `[0-9]+(\.[0-9]+)?`: there must be digits on both sides of the point.
+    let (int, fract) = match s.split_once('.') {
+        Some((int, fract)) => (int, Some(fract)),
+        None => (s, None),
+    };
+    let is_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    if !is_digits(int) || fract.is_some_and(|fract| !is_digits(fract)) {
+        return Err(ParseFixedError::InvalidDigit);
+    }
+    let fract = fract.unwrap_or_default();
+    if fract.len() > decimals {
+        return Err(ParseFixedError::TooManyDecimals);
+    }
+
+    let mut digits = String::with_capacity(int.len() + decimals + 1);
+    digits.push_str(int);
+    digits.push_str(fract);
+    digits.extend(core::iter::repeat('0').take(decimals - fract.len()));
+    Uint::from_str_radix(&digits, 10).map_err(|_| ParseFixedError::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aliases::{Fixed128x18, UFixed128x18, I128};
+
+    #[test]
+    fn display() {
+        let f = |raw: i128| Fixed128x18::from_raw(I128::try_from(raw).unwrap()).to_string();
+        assert_eq!(f(0), "0");
+        assert_eq!(f(1), "0.000000000000000001");
+        assert_eq!(f(-1), "-0.000000000000000001");
+        assert_eq!(f(1_500_000_000_000_000_000), "1.5");
+        assert_eq!(f(-42_000_000_000_000_000_000), "-42");
+        assert_eq!(Fixed128x18::MIN.to_string(), "-170141183460469231731.687303715884105728");
+        assert_eq!(UFixed128x18::MAX.to_string(), "340282366920938463463.374607431768211455");
+        assert_eq!(UFixed::<8, 1, 0>::MAX.to_string(), "255");
+        assert_eq!(UFixed::<8, 1, 3>::MAX.to_string(), "0.255");
+    }
+
+    #[test]
+    fn parse() {
+        for s in ["0", "1", "-1", "0.5", "-0.000000000000000001", "123456.789"] {
+            assert_eq!(s.parse::<Fixed128x18>().unwrap().to_string(), s);
+        }
+        assert_eq!("+1.50".parse::<Fixed128x18>().unwrap().to_string(), "1.5");
+        assert_eq!(Fixed128x18::MIN.to_string().parse::<Fixed128x18>().unwrap(), Fixed128x18::MIN);
+        assert_eq!(
+            UFixed128x18::MAX.to_string().parse::<UFixed128x18>().unwrap(),
+            UFixed128x18::MAX
+        );
+
+        let err = |s: &str| s.parse::<UFixed<8, 1, 1>>().unwrap_err();
+        assert_eq!(err(""), ParseFixedError::InvalidDigit);
+        assert_eq!(err("."), ParseFixedError::InvalidDigit);
+        assert_eq!(err(".5"), ParseFixedError::InvalidDigit);
+        assert_eq!(err("5."), ParseFixedError::InvalidDigit);
+        assert_eq!(err("-1"), ParseFixedError::InvalidDigit);
+        assert_eq!(err("1.2.3"), ParseFixedError::InvalidDigit);
+        assert_eq!(err("0x1"), ParseFixedError::InvalidDigit);
+        assert_eq!(err("0.05"), ParseFixedError::TooManyDecimals);
+        assert_eq!(err("25.6"), ParseFixedError::Overflow);
+        assert_eq!("-12.9".parse::<Fixed<8, 1, 1>>().unwrap_err(), ParseFixedError::Overflow);
+        assert_eq!("-12.8".parse::<Fixed<8, 1, 1>>().unwrap(), Fixed::MIN);
+    }
+}
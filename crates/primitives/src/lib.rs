@@ -22,6 +22,9 @@ pub mod postgres;
 #[cfg(feature = "diesel")]
 pub mod diesel;
 
+#[cfg(feature = "sqlx")]
+pub mod sqlx;
+
 pub mod aliases;
 #[doc(no_inline)]
 pub use aliases::{
@@ -44,8 +47,11 @@ pub use self::bytes_::Bytes;
 mod common;
 pub use common::TxKind;
 
+mod fixed;
+pub use fixed::{Fixed, ParseFixedError, UFixed};
+
 mod log;
-pub use log::{logs_bloom, IntoLogData, Log, LogData};
+pub use log::{logs_bloom, IntoLogData, Log, LogData, LogFilter};
 
 #[cfg(feature = "map")]
 pub mod map;
@@ -59,10 +65,18 @@ pub use signed::{BigIntConversionError, ParseSignedError, Sign, Signed};
 mod signature;
 #[allow(deprecated)]
 pub use signature::PrimitiveSignature;
-pub use signature::{normalize_v, to_eip155_v, Signature, SignatureError};
+pub use signature::{
+    from_eip155_v, normalize_v, to_eip155_v, Signature, SignatureError, VEncoding,
+};
 
 pub mod utils;
-pub use utils::{eip191_hash_message, keccak256, Keccak256, KECCAK256_EMPTY};
+pub use utils::{
+    eip191_hash_message, keccak256, keccak256_batch, keccak256_many, Eip191Message, Keccak256,
+    KECCAK256_EMPTY,
+};
+
+mod vanity;
+pub use vanity::{AddressPattern, CreateScheme, MinedSalt, PatternError, SaltMiner};
 
 #[doc(hidden)] // Use `hex` directly instead!
 pub mod hex_literal;
@@ -130,6 +144,8 @@ pub mod private {
 
 #[cfg(feature = "seismic")]
 pub mod storage;
+#[cfg(feature = "seismic-crypto")]
+pub mod seismic_crypto;
 #[cfg(feature = "seismic")]
 pub use aliases::{
     SAddress, SI128, SI16, SI256, SI32, SI64, SI8, SU128, SU16, SU256, SU32, SU64, SU8,
@@ -0,0 +1,178 @@
+use crate::{Address, Bloom, BloomInput, Log, B256};
+use alloc::collections::BTreeSet;
+
+/// A filter for [`Log`]s, with the same semantics as the `eth_getLogs` RPC
+/// method.
+///
+/// A log matches if its address is in [`address`](Self::address), and, for
+/// each position `i`, its `i`-th topic is in [`topics[i]`](Self::topics).
+/// An empty set is a wildcard that matches any value, but a non-empty topic
+/// set does not match logs with fewer than `i + 1` topics.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{address, b256, Log, LogFilter};
+///
+/// let weth = address!("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2");
+/// let transfer = b256!("0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+/// let filter = LogFilter::new().address(weth).event_signature(transfer);
+///
+/// let log = Log::new_unchecked(weth, vec![transfer, Default::default()], Default::default());
+/// assert!(filter.matches(&log));
+/// assert!(!filter.matches(&Log::new_unchecked(weth, vec![], Default::default())));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct LogFilter {
+    /// The addresses to match. Empty matches any address.
+    pub address: BTreeSet<Address>,
+    /// The topics to match, per position. Empty sets match any topic.
+    pub topics: [BTreeSet<B256>; 4],
+}
+
+impl LogFilter {
+    /// Creates a new filter that matches every log.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            address: BTreeSet::new(),
+            topics: [BTreeSet::new(), BTreeSet::new(), BTreeSet::new(), BTreeSet::new()],
+        }
+    }
+
+    /// Adds an address to the set of matched addresses.
+    #[inline]
+    pub fn address(mut self, address: Address) -> Self {
+        self.address.insert(address);
+        self
+    }
+
+    /// Adds addresses to the set of matched addresses.
+    #[inline]
+    pub fn addresses(mut self, addresses: impl IntoIterator<Item = Address>) -> Self {
+        self.address.extend(addresses);
+        self
+    }
+
+    /// Adds an event signature hash to the set of matched first topics.
+    #[inline]
+    pub fn event_signature(self, hash: B256) -> Self {
+        self.topic(0, hash)
+    }
+
+    /// Adds a topic to the set of matched topics at the given position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 3.
+    #[inline]
+    #[track_caller]
+    pub fn topic(mut self, index: usize, topic: impl Into<B256>) -> Self {
+        self.topics[index].insert(topic.into());
+        self
+    }
+
+    /// Adds topics to the set of matched topics at the given position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 3.
+    #[inline]
+    #[track_caller]
+    pub fn topics<T: Into<B256>>(
+        mut self,
+        index: usize,
+        topics: impl IntoIterator<Item = T>,
+    ) -> Self {
+        self.topics[index].extend(topics.into_iter().map(Into::into));
+        self
+    }
+
+    /// Returns `true` if this filter matches every log.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.address.is_empty() && self.topics.iter().all(BTreeSet::is_empty)
+    }
+
+    /// Returns `true` if the given log matches this filter.
+    #[inline]
+    pub fn matches(&self, log: &Log) -> bool {
+        self.matches_raw(log.address, log.topics())
+    }
+
+    /// Returns `true` if a log with the given address and topics matches this
+    /// filter.
+    pub fn matches_raw(&self, address: Address, topics: &[B256]) -> bool {
+        if !self.address.is_empty() && !self.address.contains(&address) {
+            return false;
+        }
+        self.topics.iter().enumerate().all(|(i, set)| {
+            set.is_empty() || topics.get(i).is_some_and(|topic| set.contains(topic))
+        })
+    }
+
+    /// Returns `true` if a block or receipt with the given bloom may contain
+    /// logs that match this filter.
+    ///
+    /// This is meant as a fast pre-check before matching individual logs with
+    /// [`matches`](Self::matches). It admits false positives, but never false
+    /// negatives.
+    pub fn matches_bloom(&self, bloom: &Bloom) -> bool {
+        let contains = |raw: &[u8]| bloom.contains_input(BloomInput::Raw(raw));
+        (self.address.is_empty() || self.address.iter().any(|a| contains(a.as_slice())))
+            && self
+                .topics
+                .iter()
+                .all(|set| set.is_empty() || set.iter().any(|t| contains(t.as_slice())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{logs_bloom, Bytes};
+
+    #[test]
+    fn matches() {
+        let a = Address::with_last_byte(1);
+        let b = Address::with_last_byte(2);
+        let t = |x: u8| B256::with_last_byte(x);
+        let log =
+            |address, topics: &[B256]| Log::new_unchecked(address, topics.to_vec(), Bytes::new());
+
+        let filter = LogFilter::new();
+        assert!(filter.is_empty());
+        assert!(filter.matches(&log(a, &[])));
+
+        let filter = LogFilter::new().addresses([a, b]);
+        assert!(filter.matches(&log(a, &[t(1)])));
+        assert!(filter.matches(&log(b, &[])));
+        assert!(!filter.matches(&log(Address::ZERO, &[t(1)])));
+
+        let filter = LogFilter::new().event_signature(t(1)).topics(2, [t(3), t(4)]);
+        assert!(!filter.is_empty());
+        assert!(filter.matches(&log(a, &[t(1), t(9), t(3)])));
+        assert!(filter.matches(&log(a, &[t(1), t(9), t(4), t(9)])));
+        assert!(!filter.matches(&log(a, &[t(1), t(9), t(5)])));
+        assert!(!filter.matches(&log(a, &[t(2), t(9), t(3)])));
+        assert!(!filter.matches(&log(a, &[t(1), t(9)])));
+    }
+
+    #[test]
+    fn matches_bloom() {
+        let a = Address::with_last_byte(1);
+        let t = |x: u8| B256::with_last_byte(x);
+        let logs = [
+            Log::new_unchecked(a, vec![t(1), t(2)], Bytes::new()),
+            Log::new_unchecked(Address::ZERO, vec![t(3)], Bytes::new()),
+        ];
+        let bloom = logs_bloom(&logs);
+
+        assert!(LogFilter::new().matches_bloom(&bloom));
+        assert!(LogFilter::new().address(a).event_signature(t(1)).matches_bloom(&bloom));
+        assert!(LogFilter::new().topic(1, t(2)).matches_bloom(&bloom));
+        assert!(LogFilter::new().topics(0, [t(9), t(3)]).matches_bloom(&bloom));
+        assert!(!LogFilter::new().topic(0, t(9)).matches_bloom(&bloom));
+        assert!(!LogFilter::new().address(Address::with_last_byte(9)).matches_bloom(&bloom));
+    }
+}
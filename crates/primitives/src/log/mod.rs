@@ -1,6 +1,9 @@
 use crate::{Address, Bloom, Bytes, B256};
 use alloc::vec::Vec;
 
+mod filter;
+pub use filter::LogFilter;
+
 #[cfg(feature = "serde")]
 mod serde;
 
@@ -8,6 +8,7 @@ use bytes::{BufMut, BytesMut};
 use derive_more::Display;
 use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type, WrongType};
 use std::{
+    error::Error,
     iter,
     str::{from_utf8, FromStr},
 };
@@ -346,6 +347,107 @@ impl<'a, const BITS: usize, const LIMBS: usize> FromSql<'a> for Signed<BITS, LIM
     }
 }
 
+#[cfg(feature = "seismic")]
+mod seismic {
+    use super::*;
+    use crate::{
+        aliases::{SInt, SUInt},
+        Address, FlaggedStorage, SAddress, Uint,
+    };
+
+    /// Converts `SAddress` to Postgres Bytea Type.
+    impl ToSql for SAddress {
+        fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxedError> {
+            self.0 .0.to_sql(ty, out)
+        }
+
+        accepts!(BYTEA);
+
+        to_sql_checked!();
+    }
+
+    /// Converts `SAddress` From Postgres Bytea Type.
+    impl<'a> FromSql<'a> for SAddress {
+        accepts!(BYTEA);
+
+        fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, BoxedError> {
+            FixedBytes::from_sql(ty, raw).map(|bytes| Self(Address(bytes)))
+        }
+    }
+
+    /// Converts `SUInt` to Postgres types, like the underlying [`Uint`].
+    impl<const BITS: usize, const LIMBS: usize> ToSql for SUInt<BITS, LIMBS> {
+        fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxedError> {
+            self.0.to_sql(ty, out)
+        }
+
+        fn accepts(ty: &Type) -> bool {
+            <Uint<BITS, LIMBS> as ToSql>::accepts(ty)
+        }
+
+        to_sql_checked!();
+    }
+
+    /// Converts `SUInt` from Postgres types, like the underlying [`Uint`].
+    impl<'a, const BITS: usize, const LIMBS: usize> FromSql<'a> for SUInt<BITS, LIMBS> {
+        fn accepts(ty: &Type) -> bool {
+            <Uint<BITS, LIMBS> as FromSql<'_>>::accepts(ty)
+        }
+
+        fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, BoxedError> {
+            Uint::from_sql(ty, raw).map(Self)
+        }
+    }
+
+    /// Converts `SInt` to Postgres types, like the underlying [`Signed`].
+    impl<const BITS: usize, const LIMBS: usize> ToSql for SInt<BITS, LIMBS> {
+        fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxedError> {
+            self.0.to_sql(ty, out)
+        }
+
+        fn accepts(ty: &Type) -> bool {
+            <Signed<BITS, LIMBS> as ToSql>::accepts(ty)
+        }
+
+        to_sql_checked!();
+    }
+
+    /// Converts `SInt` from Postgres types, like the underlying [`Signed`].
+    impl<'a, const BITS: usize, const LIMBS: usize> FromSql<'a> for SInt<BITS, LIMBS> {
+        fn accepts(ty: &Type) -> bool {
+            <Signed<BITS, LIMBS> as FromSql<'_>>::accepts(ty)
+        }
+
+        fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, BoxedError> {
+            Signed::from_sql(ty, raw).map(Self)
+        }
+    }
+
+    /// Converts `FlaggedStorage` to Postgres Bytea Type, as encoded by
+    /// [`FlaggedStorage::to_be_bytes`].
+    impl ToSql for FlaggedStorage {
+        fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, BoxedError> {
+            out.put_slice(&self.to_be_bytes());
+            Ok(IsNull::No)
+        }
+
+        accepts!(BYTEA);
+
+        to_sql_checked!();
+    }
+
+    /// Converts `FlaggedStorage` From Postgres Bytea Type, as decoded by
+    /// [`FlaggedStorage::try_from_be_slice`].
+    impl<'a> FromSql<'a> for FlaggedStorage {
+        accepts!(BYTEA);
+
+        fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, BoxedError> {
+            Self::try_from_be_slice(raw)
+                .ok_or_else(|| Box::new(FromSqlError::ParseError(ty.clone())) as BoxedError)
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -419,4 +521,43 @@ mod test {
             ],
         );
     }
+
+    #[cfg(feature = "seismic")]
+    #[test]
+    fn shielded_roundtrip() {
+        use crate::{
+            aliases::{SInt, SUInt},
+            Address, FlaggedStorage, SAddress, U256,
+        };
+
+        fn roundtrip<T: ToSql + for<'a> FromSql<'a> + PartialEq + core::fmt::Debug>(
+            value: T,
+            ty: Type,
+        ) -> BytesMut {
+            let mut bytes = BytesMut::new();
+            value.to_sql_checked(&ty, &mut bytes).unwrap();
+            assert_eq!(T::from_sql(&ty, &bytes).unwrap(), value);
+            bytes
+        }
+
+        let address = SAddress(Address::with_last_byte(1));
+        assert_eq!(*roundtrip(address, Type::BYTEA), *address.0);
+
+        roundtrip(SUInt(U256::from(12345)), Type::NUMERIC);
+        roundtrip(SUInt(U256::MAX), Type::BYTEA);
+        let mut expected = BytesMut::new();
+        I256::MINUS_ONE.to_sql(&Type::NUMERIC, &mut expected).unwrap();
+        assert_eq!(roundtrip(SInt(I256::MINUS_ONE), Type::NUMERIC), expected);
+        roundtrip(SInt(I256::MIN), Type::BYTEA);
+
+        let storage = FlaggedStorage::new(U256::from(42), true);
+        let bytes = roundtrip(storage, Type::BYTEA);
+        assert_eq!(bytes.len(), 33);
+        assert_eq!(bytes[31..], [42, 1]);
+        assert!(FlaggedStorage::from_sql(&Type::BYTEA, &bytes[..32]).is_err());
+        let mut invalid = bytes.to_vec();
+        invalid[32] = 2;
+        assert!(FlaggedStorage::from_sql(&Type::BYTEA, &invalid).is_err());
+        assert!(!<FlaggedStorage as ToSql>::accepts(&Type::NUMERIC));
+    }
 }
@@ -0,0 +1,239 @@
+//! Seismic calldata encryption.
+//!
+//! Calls touching shielded parameters are sent with their calldata encrypted
+//! with AES-256-GCM, under a key derived from a secp256k1 ECDH shared secret
+//! between the sender and the network:
+//!
+//! 1. the shared secret is the SHA-256 hash of the compressed ECDH point, like
+//!    `libsecp256k1`'s default ECDH hash function;
+//! 2. the key is derived from the shared secret with HKDF-SHA256, without salt,
+//!    and with `"aes-gcm key"` as info.
+
+use crate::{FixedBytes, B256};
+use aes_gcm::{
+    aead::{Aead, Payload},
+    Aes256Gcm, KeyInit,
+};
+use alloc::vec::Vec;
+use core::fmt;
+use k256::{elliptic_curve::sec1::ToEncodedPoint, PublicKey, SecretKey};
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// The HKDF info used to derive an [`EncryptionKey`] from a shared secret.
+pub const KEY_DERIVATION_INFO: &[u8] = b"aes-gcm key";
+
+/// An AES-GCM nonce.
+///
+/// A nonce must never be reused with the same key.
+pub type EncryptionNonce = FixedBytes<12>;
+
+/// The error returned when decrypting invalid or tampered ciphertext, or
+/// ciphertext encrypted with a different key, nonce or associated data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DecryptionError;
+
+impl core::error::Error for DecryptionError {}
+
+impl fmt::Display for DecryptionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("failed to decrypt ciphertext")
+    }
+}
+
+/// Returns the ECDH shared secret of a secret key and a public key.
+///
+/// This is the SHA-256 hash of the compressed shared point, and is the same for
+/// both sides of the exchange.
+pub fn shared_secret(secret: &SecretKey, public: &PublicKey) -> B256 {
+    let point = (public.to_projective() * *secret.to_nonzero_scalar()).to_affine();
+    B256::new(Sha256::digest(point.to_encoded_point(true).as_bytes()).into())
+}
+
+/// An AES-256-GCM key used to encrypt calldata and return data.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::seismic_crypto::{EncryptionKey, EncryptionNonce};
+/// use k256::SecretKey;
+///
+/// let alice = SecretKey::from_slice(&[0x11; 32]).unwrap();
+/// let bob = SecretKey::from_slice(&[0x22; 32]).unwrap();
+///
+/// let key = EncryptionKey::derive(&alice, &bob.public_key());
+/// assert_eq!(key, EncryptionKey::derive(&bob, &alice.public_key()));
+///
+/// let nonce = EncryptionNonce::repeat_byte(1);
+/// let ciphertext = key.encrypt(&nonce, b"calldata", &[]);
+/// assert_eq!(key.decrypt(&nonce, &ciphertext, &[]).unwrap(), b"calldata");
+/// ```
+///
+/// Keys are compared in constant time, and zeroed when dropped.
+#[derive(Clone)]
+pub struct EncryptionKey([u8; 32]);
+
+impl PartialEq for EncryptionKey {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.ct_eq(&other.0).into()
+    }
+}
+
+impl Eq for EncryptionKey {}
+
+impl Drop for EncryptionKey {
+    #[inline]
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for EncryptionKey {}
+
+impl fmt::Debug for EncryptionKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("EncryptionKey(<redacted>)")
+    }
+}
+
+impl From<[u8; 32]> for EncryptionKey {
+    #[inline]
+    fn from(bytes: [u8; 32]) -> Self {
+        Self::new(bytes)
+    }
+}
+
+impl EncryptionKey {
+    /// Creates a new key from raw bytes.
+    #[inline]
+    pub const fn new(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    /// Derives the key shared by a secret key and a public key.
+    ///
+    /// Both sides of the exchange derive the same key. See the
+    /// [module-level documentation](self) for details.
+    pub fn derive(secret: &SecretKey, public: &PublicKey) -> Self {
+        Self::from_shared_secret(&shared_secret(secret, public))
+    }
+
+    /// Derives a key from an ECDH shared secret with HKDF-SHA256.
+    pub fn from_shared_secret(shared_secret: &B256) -> Self {
+        let mut key = [0u8; 32];
+        hkdf::Hkdf::<Sha256>::new(None, shared_secret.as_slice())
+            .expand(KEY_DERIVATION_INFO, &mut key)
+            .expect("32 is a valid HKDF-SHA256 output length");
+        Self(key)
+    }
+
+    /// Returns the raw bytes of the key.
+    #[inline]
+    pub const fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Encrypts `plaintext` with the given nonce and additional authenticated
+    /// data.
+    ///
+    /// The returned ciphertext is followed by the 16-byte authentication tag.
+    pub fn encrypt(&self, nonce: &EncryptionNonce, plaintext: &[u8], aad: &[u8]) -> Vec<u8> {
+        self.cipher()
+            .encrypt(nonce.as_slice().into(), Payload { msg: plaintext, aad })
+            .expect("plaintext is not too long")
+    }
+
+    /// Decrypts and authenticates `ciphertext` with the given nonce and
+    /// additional authenticated data.
+    pub fn decrypt(
+        &self,
+        nonce: &EncryptionNonce,
+        ciphertext: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>, DecryptionError> {
+        self.cipher()
+            .decrypt(nonce.as_slice().into(), Payload { msg: ciphertext, aad })
+            .map_err(|_| DecryptionError)
+    }
+
+    fn cipher(&self) -> Aes256Gcm {
+        Aes256Gcm::new(&self.0.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hex;
+
+    fn keys() -> (SecretKey, SecretKey) {
+        (SecretKey::from_slice(&[0x11; 32]).unwrap(), SecretKey::from_slice(&[0x22; 32]).unwrap())
+    }
+
+    // Known-answer vectors, generated with the Python `cryptography` package.
+    const SHARED_SECRET: B256 =
+        b256!("0xb36b6d195982c5be874d6d542dc268234379e1ae4ff1709402135b7de5cf0766");
+    const KEY: [u8; 32] = hex!("707efac21d4fe72c7201c910c9e927dd0db33cb079e1e86e762578100f6a0525");
+    const NONCE: EncryptionNonce = fixed_bytes!("0x000102030405060708090a0b");
+
+    #[test]
+    fn derive_key() {
+        let (alice, bob) = keys();
+        assert_eq!(
+            bob.public_key().to_encoded_point(true).as_bytes(),
+            hex!("02466d7fcae563e5cb09a0d1870bb580344804617879a14949cf22285f1bae3f27")
+        );
+        assert_eq!(shared_secret(&alice, &bob.public_key()), SHARED_SECRET);
+        assert_eq!(shared_secret(&bob, &alice.public_key()), SHARED_SECRET);
+        assert_eq!(EncryptionKey::from_shared_secret(&SHARED_SECRET).as_bytes(), &KEY);
+        assert_eq!(EncryptionKey::derive(&alice, &bob.public_key()), EncryptionKey::new(KEY));
+    }
+
+    #[test]
+    fn encrypt_decrypt() {
+        let key = EncryptionKey::new(KEY);
+
+        // `transfer(0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045, 1000000)`
+        let calldata = hex!(
+            "a9059cbb000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045"
+            "00000000000000000000000000000000000000000000000000000000000f4240"
+        );
+        let ciphertext = hex!(
+            "25ecff5511fe6395c5a2e7b13f9f464dc60a591d6f63dea405f706551c07e383"
+            "5218d9d3707fdd5e86ba4b8745b905f5db9d9fce638134a2ed3fc6c4c5116104"
+            "9ae9519907fbf0e3c010b31b84966eeab81b5843"
+        );
+        assert_eq!(key.encrypt(&NONCE, &calldata, &[]), ciphertext);
+        assert_eq!(key.decrypt(&NONCE, &ciphertext, &[]).unwrap(), calldata);
+
+        let ciphertext = hex!("e48c0f827ec4f0a8194f2b41bad62c765dd1dac261");
+        assert_eq!(key.encrypt(&NONCE, b"hello", b"aad"), ciphertext);
+        assert_eq!(key.decrypt(&NONCE, &ciphertext, b"aad").unwrap(), b"hello");
+        assert_eq!(key.decrypt(&NONCE, &ciphertext, &[]), Err(DecryptionError));
+        assert_eq!(key.decrypt(&EncryptionNonce::ZERO, &ciphertext, b"aad"), Err(DecryptionError));
+        assert_eq!(
+            EncryptionKey::new([0; 32]).decrypt(&NONCE, &ciphertext, b"aad"),
+            Err(DecryptionError)
+        );
+        assert_eq!(key.decrypt(&NONCE, &ciphertext[1..], b"aad"), Err(DecryptionError));
+    }
+
+    #[test]
+    fn key_eq() {
+        fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+        assert_zeroize_on_drop::<EncryptionKey>();
+
+        let key = EncryptionKey::new(KEY);
+        assert_eq!(key.clone(), key);
+        let mut other = KEY;
+        other[31] ^= 1;
+        assert_ne!(key, EncryptionKey::new(other));
+    }
+
+    #[test]
+    fn debug_redacts_key() {
+        assert_eq!(format!("{:?}", EncryptionKey::new(KEY)), "EncryptionKey(<redacted>)");
+    }
+}
@@ -13,6 +13,23 @@ pub enum SignatureError {
     /// Invalid parity.
     InvalidParity(u64),
 
+    /// The `v` value is a valid parity encoding, but not the expected one.
+    NonCanonicalV(u64),
+
+    /// The chain ID is too large to be encoded in an
+    /// [EIP-155](https://eips.ethereum.org/EIPS/eip-155) `v` value.
+    InvalidChainId(u64),
+
+    /// The `r` value is zero.
+    ZeroR,
+
+    /// The `s` value is zero.
+    ZeroS,
+
+    /// The `s` value is greater than half the curve order, which is rejected by
+    /// [EIP-2](https://eips.ethereum.org/EIPS/eip-2) to prevent signature malleability.
+    HighS,
+
     /// k256 error
     #[cfg(feature = "k256")]
     K256(k256::ecdsa::Error),
@@ -51,6 +68,11 @@ impl fmt::Display for SignatureError {
             Self::FromBytes(e) => f.write_str(e),
             Self::FromHex(e) => e.fmt(f),
             Self::InvalidParity(v) => write!(f, "invalid parity: {v}"),
+            Self::NonCanonicalV(v) => write!(f, "non-canonical v value: {v}"),
+            Self::InvalidChainId(id) => write!(f, "chain ID too large for EIP-155: {id}"),
+            Self::ZeroR => f.write_str("signature r value is zero"),
+            Self::ZeroS => f.write_str("signature s value is zero"),
+            Self::HighS => f.write_str("signature s value is greater than half the curve order"),
         }
     }
 }
@@ -2,7 +2,7 @@ mod error;
 pub use error::SignatureError;
 
 mod utils;
-pub use utils::{normalize_v, to_eip155_v};
+pub use utils::{from_eip155_v, normalize_v, to_eip155_v, VEncoding};
 
 mod sig;
 pub use sig::Signature;
@@ -1,6 +1,8 @@
 #![allow(clippy::missing_const_for_fn)] // On purpose for forward compatibility.
 
-use crate::{hex, normalize_v, signature::SignatureError, uint, B256, U256};
+use crate::{
+    hex, normalize_v, signature::SignatureError, to_eip155_v, uint, ChainId, VEncoding, B256, U256,
+};
 use alloc::vec::Vec;
 use core::{fmt::Display, str::FromStr};
 
@@ -138,6 +140,55 @@ impl Signature {
         Ok(Self::from_bytes_and_parity(bytes, parity))
     }
 
+    /// Parses a 65-byte long raw signature, rejecting non-canonical and malleable signatures.
+    ///
+    /// Unlike [`from_raw`](Self::from_raw), this requires the `v` value to be in the given
+    /// encoding, and the signature to pass [`validate_strict`](Self::validate_strict).
+    #[inline]
+    pub fn from_raw_strict(bytes: &[u8], v_encoding: VEncoding) -> Result<Self, SignatureError> {
+        let bytes: &[u8; 65] =
+            bytes.try_into().map_err(|_| SignatureError::FromBytes("expected exactly 65 bytes"))?;
+        let [rs @ .., v] = bytes;
+        let parity = v_encoding.decode(*v as u64)?;
+        let sig = Self::from_bytes_and_parity(rs, parity);
+        sig.validate_strict()?;
+        Ok(sig)
+    }
+
+    /// Instantiates a new signature from `r`, `s`, and `v` values, rejecting non-canonical and
+    /// malleable signatures.
+    ///
+    /// See [`from_raw_strict`](Self::from_raw_strict).
+    #[inline]
+    pub fn from_rsv_strict(
+        r: U256,
+        s: U256,
+        v: u64,
+        v_encoding: VEncoding,
+    ) -> Result<Self, SignatureError> {
+        let sig = Self::new(r, s, v_encoding.decode(v)?);
+        sig.validate_strict()?;
+        Ok(sig)
+    }
+
+    /// Checks that `r` and `s` are not zero, and that `s` is not greater than half the curve
+    /// order, as required by [EIP-2](https://eips.ethereum.org/EIPS/eip-2).
+    ///
+    /// Signatures with a high `s` can be converted with [`normalize_s`](Self::normalize_s).
+    #[inline]
+    pub fn validate_strict(&self) -> Result<(), SignatureError> {
+        if self.r.is_zero() {
+            return Err(SignatureError::ZeroR);
+        }
+        if self.s.is_zero() {
+            return Err(SignatureError::ZeroS);
+        }
+        if self.s > SECP256K1N_ORDER >> 1 {
+            return Err(SignatureError::HighS);
+        }
+        Ok(())
+    }
+
     /// Parses a signature from a byte slice, with a v value
     ///
     /// # Panics
@@ -234,6 +285,50 @@ impl Signature {
         Self { y_parity: v, r, s }
     }
 
+    /// Signs the given prehashed message.
+    ///
+    /// The nonce is derived deterministically according to [RFC 6979], and the resulting
+    /// signature has a normalized low `s`, like [`normalized_s`](Self::normalized_s).
+    ///
+    /// [RFC 6979]: https://www.rfc-editor.org/rfc/rfc6979
+    #[cfg(feature = "k256")]
+    pub fn sign_prehash(
+        key: &k256::ecdsa::SigningKey,
+        prehash: &B256,
+    ) -> Result<Self, SignatureError> {
+        let (sig, recid) = key.sign_prehash_recoverable(prehash.as_slice())?;
+        Ok(Self::from_signature_and_parity(sig, recid.is_y_odd()))
+    }
+
+    /// Signs the given message by first prefixing and hashing it according to
+    /// [EIP-191](crate::eip191_hash_message).
+    ///
+    /// See [`sign_prehash`](Self::sign_prehash) for more details.
+    #[cfg(feature = "k256")]
+    #[inline]
+    pub fn sign_message<T: AsRef<[u8]>>(
+        key: &k256::ecdsa::SigningKey,
+        msg: T,
+    ) -> Result<Self, SignatureError> {
+        Self::sign_prehash(key, &crate::eip191_hash_message(msg))
+    }
+
+    /// Signs an [EIP-712] typed data message, given its domain separator and `hashStruct`.
+    ///
+    /// See [`sign_prehash`](Self::sign_prehash) for more details.
+    ///
+    /// [EIP-712]: https://eips.ethereum.org/EIPS/eip-712
+    #[cfg(feature = "k256")]
+    #[inline]
+    pub fn sign_typed_data(
+        key: &k256::ecdsa::SigningKey,
+        domain_separator: B256,
+        struct_hash: B256,
+    ) -> Result<Self, SignatureError> {
+        let msg = crate::Eip191Message::TypedData { domain_separator, struct_hash };
+        Self::sign_prehash(key, &msg.hash())
+    }
+
     /// Creates a [`Signature`] from the serialized `r` and `s` scalar values, which
     /// comprise the ECDSA signature, alongside a `v` value, used to determine the recovery ID.
     #[inline]
@@ -293,6 +388,19 @@ impl Signature {
         self.recover_from_msg(msg).map(|vk| Address::from_public_key(&vk))
     }
 
+    /// Recovers an [`Address`] from this signature and the given [EIP-191] message of any
+    /// version.
+    ///
+    /// [EIP-191]: crate::Eip191Message
+    #[cfg(feature = "k256")]
+    #[inline]
+    pub fn recover_address_from_eip191(
+        &self,
+        msg: &crate::Eip191Message,
+    ) -> Result<Address, SignatureError> {
+        self.recover_from_eip191(msg).map(|vk| Address::from_public_key(&vk))
+    }
+
     /// Recovers an [`Address`] from this signature and the given prehashed message.
     #[cfg(feature = "k256")]
     #[inline]
@@ -313,6 +421,20 @@ impl Signature {
         self.recover_from_prehash(&crate::eip191_hash_message(msg))
     }
 
+    /// Recovers a [`VerifyingKey`] from this signature and the given [EIP-191] message of any
+    /// version.
+    ///
+    /// [`VerifyingKey`]: k256::ecdsa::VerifyingKey
+    /// [EIP-191]: crate::Eip191Message
+    #[cfg(feature = "k256")]
+    #[inline]
+    pub fn recover_from_eip191(
+        &self,
+        msg: &crate::Eip191Message,
+    ) -> Result<k256::ecdsa::VerifyingKey, SignatureError> {
+        self.recover_from_prehash(&msg.hash())
+    }
+
     /// Recovers a [`VerifyingKey`] from this signature and the given prehashed message.
     ///
     /// [`VerifyingKey`]: k256::ecdsa::VerifyingKey
@@ -331,6 +453,48 @@ impl Signature {
         .map_err(Into::into)
     }
 
+    /// Returns `true` if this signature over the given prehashed message was produced by the
+    /// `expected` signer.
+    ///
+    /// This compares the hash of the recovered public key with `expected` directly, without
+    /// constructing the recovered [`Address`]. Returns `false` if recovery fails.
+    #[cfg(feature = "k256")]
+    pub fn verify(&self, prehash: &B256, expected: &Address) -> bool {
+        use k256::elliptic_curve::sec1::ToEncodedPoint;
+
+        let Ok(vk) = self.recover_from_prehash(prehash) else { return false };
+        let affine: &k256::AffinePoint = vk.as_ref();
+        let encoded = affine.to_encoded_point(false);
+        crate::keccak256(&encoded.as_bytes()[1..])[12..] == expected[..]
+    }
+
+    /// Recovers the signer [`Address`]es of many `(signature, prehash)` pairs.
+    ///
+    /// The results are returned in the same order as the input. With the `rayon` feature, the
+    /// signers are recovered in parallel.
+    #[cfg(feature = "k256")]
+    pub fn recover_batch(items: &[(Self, B256)]) -> Vec<Result<Address, SignatureError>> {
+        let recover = |(sig, prehash): &(Self, B256)| sig.recover_address_from_prehash(prehash);
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+            items.par_iter().map(recover).collect()
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            items.iter().map(recover).collect()
+        }
+    }
+
+    /// Returns the [EIP-155](https://eips.ethereum.org/EIPS/eip-155) `v` value of this signature
+    /// for the given chain ID.
+    ///
+    /// Returns an error if the chain ID is too large to be encoded.
+    #[inline]
+    pub fn v_eip155(&self, chain_id: ChainId) -> Result<u64, SignatureError> {
+        to_eip155_v(self.y_parity as u8, chain_id)
+    }
+
     /// Returns the `r` component of this signature.
     #[inline]
     pub fn r(&self) -> U256 {
@@ -499,6 +663,131 @@ mod tests {
         ).expect("could not parse signature");
         let expected = address!("0x2c7536E3605D9C16a7a3D7b1898e529396a65c23");
         assert_eq!(sig.recover_address_from_msg("Some data").unwrap(), expected);
+        let msg = crate::Eip191Message::PersonalSign(crate::Bytes::from_static(b"Some data"));
+        assert_eq!(sig.recover_address_from_eip191(&msg).unwrap(), expected);
+    }
+
+    #[test]
+    #[cfg(feature = "k256")]
+    fn recover_eip712_signature() {
+        // Mail example from EIP-712.
+        let sig = Signature::new(
+            U256::from_str("0x4355c47d63924e8a72e509b65029052eb6c299d53a04e167c5775fd466751c9d")
+                .unwrap(),
+            U256::from_str("0x07299936d304c153f6443dfa05f40ff007d72911b6f72307f996231605b91562")
+                .unwrap(),
+            true,
+        );
+        let msg = crate::Eip191Message::TypedData {
+            domain_separator: b256!(
+                "0xf2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f"
+            ),
+            struct_hash: b256!(
+                "0xc52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e"
+            ),
+        };
+        let expected = address!("0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826");
+        assert_eq!(sig.recover_address_from_eip191(&msg).unwrap(), expected);
+    }
+
+    #[test]
+    #[cfg(feature = "k256")]
+    fn sign() {
+        let key = k256::ecdsa::SigningKey::from_slice(&[0x42; 32]).unwrap();
+        let signer = Address::from_private_key(&key);
+        let hash = b256!("0x5eb4f5a33c621f32a8622d5f943b6b102994dfe4e5aebbefe69bb1b2aa0fc93e");
+
+        let sig = Signature::sign_prehash(&key, &hash).unwrap();
+        assert_eq!(Signature::sign_prehash(&key, &hash).unwrap(), sig);
+        assert_eq!(sig.normalize_s(), None);
+        assert!(sig.verify(&hash, &signer));
+
+        let sig = Signature::sign_message(&key, "Some data").unwrap();
+        assert_eq!(sig.recover_address_from_msg("Some data").unwrap(), signer);
+
+        let (domain_separator, struct_hash) = (B256::repeat_byte(1), B256::repeat_byte(2));
+        let sig = Signature::sign_typed_data(&key, domain_separator, struct_hash).unwrap();
+        let msg = crate::Eip191Message::TypedData { domain_separator, struct_hash };
+        assert_eq!(sig.recover_address_from_eip191(&msg).unwrap(), signer);
+
+        // test vector taken from:
+        // https://web3js.readthedocs.io/en/v1.2.2/web3-eth-accounts.html#sign
+        let key = k256::ecdsa::SigningKey::from_slice(&hex!(
+            "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318"
+        ))
+        .unwrap();
+        let expected = Signature::from_str(
+            "b91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a0291c"
+        ).unwrap();
+        assert_eq!(Signature::sign_message(&key, "Some data").unwrap(), expected);
+    }
+
+    #[test]
+    #[cfg(feature = "k256")]
+    fn recover_batch_and_verify() {
+        let sig = Signature::from_str("48b55bfa915ac795c431978d8a6a992b628d557da5ff759b307d495a36649353efffd310ac743f371de3b9f7f9cb56c0b28ad43601b4ab949f53faa07bd2c8041b").unwrap();
+        let hash = b256!("0x5eb4f5a33c621f32a8622d5f943b6b102994dfe4e5aebbefe69bb1b2aa0fc93e");
+        let expected = address!("0x0f65fe9276bc9a24ae7083ae28e2660ef72df99e");
+        assert!(sig.verify(&hash, &expected));
+        assert!(!sig.verify(&hash, &Address::ZERO));
+        assert!(!sig.verify(&B256::ZERO, &expected));
+
+        let invalid = Signature::new(U256::ZERO, U256::ZERO, false);
+        assert!(!invalid.verify(&hash, &expected));
+
+        let results = Signature::recover_batch(&[(sig, hash), (invalid, hash), (sig, hash)]);
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap(), &expected);
+        assert!(results[1].is_err());
+        assert_eq!(results[2].as_ref().unwrap(), &expected);
+        assert!(Signature::recover_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn strict_parsing() {
+        let raw = hex!("b91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a0291c");
+        let sig = Signature::from_raw(&raw).unwrap();
+        assert_eq!(Signature::from_raw_strict(&raw, VEncoding::Electrum).unwrap(), sig);
+        assert!(matches!(
+            Signature::from_raw_strict(&raw, VEncoding::Parity),
+            Err(SignatureError::NonCanonicalV(28))
+        ));
+        assert!(matches!(
+            Signature::from_raw_strict(&raw[..64], VEncoding::Parity),
+            Err(SignatureError::FromBytes(_))
+        ));
+
+        let v = sig.v_eip155(1).unwrap();
+        assert_eq!(v, 38);
+        assert_eq!(crate::from_eip155_v(v), Some((true, 1)));
+        assert_eq!(
+            Signature::from_rsv_strict(sig.r(), sig.s(), v, VEncoding::Eip155(1)).unwrap(),
+            sig
+        );
+        assert!(matches!(
+            Signature::from_rsv_strict(sig.r(), sig.s(), v, VEncoding::Eip155(5)),
+            Err(SignatureError::NonCanonicalV(38))
+        ));
+
+        let high_s = Signature::new(sig.r(), SECP256K1N_ORDER - sig.s(), !sig.v());
+        assert_eq!(high_s.normalize_s(), Some(sig));
+        assert!(Signature::from_raw(&high_s.as_bytes()).is_ok());
+        assert!(matches!(
+            Signature::from_raw_strict(&high_s.as_bytes(), VEncoding::Electrum),
+            Err(SignatureError::HighS)
+        ));
+        assert!(matches!(
+            Signature::from_rsv_strict(U256::ZERO, sig.s(), 0, VEncoding::Parity),
+            Err(SignatureError::ZeroR)
+        ));
+        assert!(matches!(
+            Signature::from_rsv_strict(sig.r(), U256::ZERO, 0, VEncoding::Parity),
+            Err(SignatureError::ZeroS)
+        ));
+        assert!(matches!(
+            Signature::from_rsv_strict(sig.r(), sig.s(), 2, VEncoding::Parity),
+            Err(SignatureError::InvalidParity(2))
+        ));
     }
 
     #[test]
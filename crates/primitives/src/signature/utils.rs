@@ -1,9 +1,70 @@
-use crate::ChainId;
+use crate::{signature::SignatureError, ChainId};
+
+/// An encoding of the `y_parity` of a signature in its `v` value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum VEncoding {
+    /// `0` or `1`, as in typed transactions.
+    Parity,
+    /// `27` or `28`, as in pre-EIP-155 legacy transactions and `eth_sign`.
+    Electrum,
+    /// `35 + chain_id * 2` or `36 + chain_id * 2`, as in
+    /// [EIP-155](https://eips.ethereum.org/EIPS/eip-155) legacy transactions.
+    Eip155(ChainId),
+}
+
+impl VEncoding {
+    /// Encodes the given parity as a `v` value.
+    ///
+    /// Returns an error if the chain ID is too large to be encoded in an
+    /// [EIP-155](https://eips.ethereum.org/EIPS/eip-155) `v` value.
+    #[inline]
+    pub const fn encode(self, y_parity: bool) -> Result<u64, SignatureError> {
+        match self {
+            Self::Parity => Ok(y_parity as u64),
+            Self::Electrum => Ok(27 + y_parity as u64),
+            Self::Eip155(chain_id) => to_eip155_v(y_parity as u8, chain_id),
+        }
+    }
+
+    /// Decodes the parity from the given `v` value.
+    ///
+    /// Unlike [`normalize_v`], this rejects `v` values in any other encoding, including
+    /// [EIP-155](https://eips.ethereum.org/EIPS/eip-155) values for a different chain ID.
+    #[inline]
+    pub fn decode(self, v: u64) -> Result<bool, SignatureError> {
+        let Some(parity) = normalize_v(v) else { return Err(SignatureError::InvalidParity(v)) };
+        if self.encode(parity)? != v {
+            return Err(SignatureError::NonCanonicalV(v));
+        }
+        Ok(parity)
+    }
+}
 
 /// Applies [EIP-155](https://eips.ethereum.org/EIPS/eip-155).
+///
+/// Returns an error if the resulting `v` value does not fit in a `u64`.
+#[inline]
+pub const fn to_eip155_v(v: u8, chain_id: ChainId) -> Result<u64, SignatureError> {
+    match chain_id.checked_mul(2) {
+        Some(doubled) => match doubled.checked_add(v as u64 + 35) {
+            Some(v) => Ok(v),
+            None => Err(SignatureError::InvalidChainId(chain_id)),
+        },
+        None => Err(SignatureError::InvalidChainId(chain_id)),
+    }
+}
+
+/// Reverts [EIP-155](https://eips.ethereum.org/EIPS/eip-155), returning the parity and the chain ID
+/// encoded in the given `v` value.
+///
+/// Returns `None` if the value is not an EIP-155 `v` value, i.e. it is less than 35.
 #[inline]
-pub const fn to_eip155_v(v: u8, chain_id: ChainId) -> ChainId {
-    (v as u64) + 35 + chain_id * 2
+pub const fn from_eip155_v(v: u64) -> Option<(bool, ChainId)> {
+    if v < 35 {
+        return None;
+    }
+    let v = v - 35;
+    Some((v % 2 == 1, v / 2))
 }
 
 /// Attempts to normalize the v value to a boolean parity value.
@@ -70,4 +131,56 @@ mod test {
             assert_eq!(normalize_v(v), Some((v - 35) % 2 != 0));
         }
     }
+
+    #[test]
+    fn eip155_v() {
+        assert_eq!(from_eip155_v(27), None);
+        assert_eq!(from_eip155_v(34), None);
+        assert_eq!(from_eip155_v(35), Some((false, 0)));
+        assert_eq!(from_eip155_v(38), Some((true, 1)));
+        for chain_id in [1, 5, 137, 5124, u32::MAX as u64] {
+            for parity in [false, true] {
+                let v = to_eip155_v(parity as u8, chain_id).unwrap();
+                assert_eq!(from_eip155_v(v), Some((parity, chain_id)));
+            }
+        }
+
+        let max_chain_id = (u64::MAX - 35) / 2;
+        assert_eq!(to_eip155_v(0, max_chain_id).unwrap(), u64::MAX);
+        assert_eq!(from_eip155_v(u64::MAX), Some((false, max_chain_id)));
+        assert!(matches!(
+            to_eip155_v(1, max_chain_id),
+            Err(SignatureError::InvalidChainId(id)) if id == max_chain_id
+        ));
+        assert!(matches!(
+            to_eip155_v(0, max_chain_id + 1),
+            Err(SignatureError::InvalidChainId(id)) if id == max_chain_id + 1
+        ));
+        assert!(matches!(to_eip155_v(0, u64::MAX), Err(SignatureError::InvalidChainId(u64::MAX))));
+    }
+
+    #[test]
+    fn v_encoding() {
+        assert!(VEncoding::Parity.decode(1).unwrap());
+        assert!(!VEncoding::Electrum.decode(27).unwrap());
+        assert!(VEncoding::Eip155(1).decode(38).unwrap());
+        assert!(matches!(VEncoding::Parity.decode(28), Err(SignatureError::NonCanonicalV(28))));
+        assert!(matches!(VEncoding::Electrum.decode(0), Err(SignatureError::NonCanonicalV(0))));
+        assert!(matches!(VEncoding::Eip155(1).decode(39), Err(SignatureError::NonCanonicalV(39))));
+        assert!(matches!(VEncoding::Eip155(1).decode(27), Err(SignatureError::NonCanonicalV(27))));
+        assert!(matches!(VEncoding::Electrum.decode(3), Err(SignatureError::InvalidParity(3))));
+        for encoding in [VEncoding::Parity, VEncoding::Electrum, VEncoding::Eip155(5124)] {
+            for parity in [false, true] {
+                assert_eq!(encoding.decode(encoding.encode(parity).unwrap()).unwrap(), parity);
+            }
+        }
+        assert!(matches!(
+            VEncoding::Eip155(u64::MAX).encode(false),
+            Err(SignatureError::InvalidChainId(u64::MAX))
+        ));
+        assert!(matches!(
+            VEncoding::Eip155(u64::MAX).decode(35),
+            Err(SignatureError::InvalidChainId(u64::MAX))
+        ));
+    }
 }
@@ -0,0 +1,497 @@
+//! Support for the [`sqlx`](https://crates.io/crates/sqlx) crate.
+//!
+//! The `sqlx` feature stores values as big-endian binary strings (`BYTEA` in
+//! Postgres, `BLOB` in SQLite, `VARBINARY` or `BLOB` in MySQL), for every
+//! database supported by `sqlx`:
+//!
+//! - [`FixedBytes`], [`Address`], [`Bloom`] and [`Bytes`] as their raw bytes;
+//! - [`Uint`] through `ruint`'s own `sqlx` support;
+//! - [`Signature`] in its 64-byte [EIP-2098] compact form, like the `diesel`
+//!   integration.
+//!
+//! [`Signed`] integers are supported by the databases whose feature is
+//! enabled:
+//!
+//! - `sqlx-postgres` stores them as `NUMERIC`, so that they can be compared
+//!   and used in arithmetic by the database;
+//! - `sqlx-mysql` and `sqlx-sqlite` store them as their two's complement
+//!   binary representation, since MySQL `DECIMAL`s have at most 65 digits and
+//!   SQLite has no arbitrary precision numbers.
+//!
+//! Any integer, including [`Uint`], can also be stored as `NUMERIC` or
+//! `DECIMAL` by wrapping it in [`Numeric`]. For this, the `sqlx` feature
+//! enables the `bigdecimal` feature of `sqlx-core`.
+//!
+//! [EIP-2098]: https://eips.ethereum.org/EIPS/eip-2098
+
+use crate::{Address, Bloom, Bytes, FixedBytes, Signature, SignatureError, Signed, Uint};
+use core::{fmt, str::FromStr};
+use sqlx_core::{
+    database::Database,
+    decode::Decode,
+    encode::{Encode, IsNull},
+    error::BoxDynError,
+    types::{BigDecimal, Type},
+};
+
+/// Implements the `sqlx` traits for `$t` by converting to and from `$inner`,
+/// either for all databases or only for `$db`.
+macro_rules! impl_sqlx {
+    (for $db:ty, [$($gen:tt)*] $t:ty as $inner:ty, $to:expr, $from:expr) => {
+        impl<$($gen)*> Type<$db> for $t {
+            fn type_info() -> <$db as Database>::TypeInfo {
+                <$inner as Type<$db>>::type_info()
+            }
+
+            fn compatible(ty: &<$db as Database>::TypeInfo) -> bool {
+                <$inner as Type<$db>>::compatible(ty)
+            }
+        }
+
+        impl<'a, $($gen)*> Encode<'a, $db> for $t {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <$db as Database>::ArgumentBuffer<'a>,
+            ) -> Result<IsNull, BoxDynError> {
+                let to: fn(&Self) -> $inner = $to;
+                <$inner as Encode<'a, $db>>::encode_by_ref(&to(self), buf)
+            }
+        }
+
+        impl<'r, $($gen)*> Decode<'r, $db> for $t {
+            fn decode(value: <$db as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+                let from: fn($inner) -> Result<Self, BoxDynError> = $from;
+                from(<$inner as Decode<'r, $db>>::decode(value)?)
+            }
+        }
+    };
+    ([$($gen:tt)*] $t:ty as $inner:ty, $to:expr, $from:expr) => {
+        impl<$($gen)* DB: Database> Type<DB> for $t
+        where
+            $inner: Type<DB>,
+        {
+            fn type_info() -> DB::TypeInfo {
+                <$inner as Type<DB>>::type_info()
+            }
+
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                <$inner as Type<DB>>::compatible(ty)
+            }
+        }
+
+        impl<'a, $($gen)* DB: Database> Encode<'a, DB> for $t
+        where
+            $inner: Encode<'a, DB>,
+        {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <DB as Database>::ArgumentBuffer<'a>,
+            ) -> Result<IsNull, BoxDynError> {
+                let to: fn(&Self) -> $inner = $to;
+                to(self).encode_by_ref(buf)
+            }
+        }
+
+        impl<'r, $($gen)* DB: Database> Decode<'r, DB> for $t
+        where
+            $inner: Decode<'r, DB>,
+        {
+            fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+                let from: fn($inner) -> Result<Self, BoxDynError> = $from;
+                from(<$inner as Decode<'r, DB>>::decode(value)?)
+            }
+        }
+    };
+}
+
+impl_sqlx!([const N: usize,] FixedBytes<N> as Vec<u8>, |x| x.to_vec(), |bytes| {
+    Ok(Self::try_from(bytes.as_slice())?)
+});
+
+impl_sqlx!([] Address as FixedBytes<20>, |x| x.0, |x| Ok(Self(x)));
+
+impl_sqlx!([] Bloom as FixedBytes<256>, |x| x.0, |x| Ok(Self(x)));
+
+impl_sqlx!([] Bytes as Vec<u8>, |x| x.to_vec(), |bytes| Ok(bytes.into()));
+
+impl_sqlx!([] Signature as Vec<u8>, |x| x.as_erc2098().to_vec(), |bytes| {
+    if bytes.len() != 64 {
+        return Err(SignatureError::FromBytes("Invalid length").into());
+    }
+    Ok(Self::from_erc2098(&bytes))
+});
+
+/// Implements the `sqlx` traits for the signed and shielded integers in `$db`,
+/// stored either as `NUMERIC` through [`Numeric`] or as binary strings.
+#[allow(unused_macros)]
+macro_rules! impl_sqlx_ints {
+    ($db:ty, numeric) => {
+        impl_sqlx!(
+            for $db,
+            [const BITS: usize, const LIMBS: usize,] Signed<BITS, LIMBS> as Numeric<Signed<BITS, LIMBS>>,
+            |x| Numeric(*x),
+            |x| Ok(x.0)
+        );
+
+        #[cfg(feature = "seismic")]
+        impl_sqlx!(
+            for $db,
+            [const BITS: usize, const LIMBS: usize,] crate::aliases::SUInt<BITS, LIMBS> as Numeric<Uint<BITS, LIMBS>>,
+            |x| Numeric(x.0),
+            |x| Ok(Self(x.0))
+        );
+
+        #[cfg(feature = "seismic")]
+        impl_sqlx!(
+            for $db,
+            [const BITS: usize, const LIMBS: usize,] crate::aliases::SInt<BITS, LIMBS> as Numeric<Signed<BITS, LIMBS>>,
+            |x| Numeric(x.0),
+            |x| Ok(Self(x.0))
+        );
+    };
+    ($db:ty, binary) => {
+        impl_sqlx!(
+            for $db,
+            [const BITS: usize, const LIMBS: usize,] Signed<BITS, LIMBS> as Uint<BITS, LIMBS>,
+            |x| x.into_raw(),
+            |x| Ok(Self::from_raw(x))
+        );
+
+        #[cfg(feature = "seismic")]
+        impl_sqlx!(
+            for $db,
+            [const BITS: usize, const LIMBS: usize,] crate::aliases::SUInt<BITS, LIMBS> as Uint<BITS, LIMBS>,
+            |x| x.0,
+            |x| Ok(Self(x))
+        );
+
+        #[cfg(feature = "seismic")]
+        impl_sqlx!(
+            for $db,
+            [const BITS: usize, const LIMBS: usize,] crate::aliases::SInt<BITS, LIMBS> as Uint<BITS, LIMBS>,
+            |x| x.0.into_raw(),
+            |x| Ok(Self(Signed::from_raw(x)))
+        );
+    };
+}
+
+#[cfg(feature = "sqlx-postgres")]
+impl_sqlx_ints!(sqlx_postgres::Postgres, numeric);
+
+#[cfg(feature = "sqlx-mysql")]
+impl_sqlx_ints!(sqlx_mysql::MySql, binary);
+
+#[cfg(feature = "sqlx-sqlite")]
+impl_sqlx_ints!(sqlx_sqlite::Sqlite, binary);
+
+/// An integer stored as a `NUMERIC` (Postgres) or `DECIMAL` (MySQL) value.
+///
+/// Unlike the default binary encoding, this can be compared and used in
+/// arithmetic by the database. Values which are not integers or do not fit in
+/// the integer type fail to decode.
+///
+/// # Examples
+///
+/// ```ignore
+/// use alloy_primitives::{sqlx::Numeric, U256};
+///
+/// sqlx::query("INSERT INTO balances (balance) VALUES ($1)")
+///     .bind(Numeric(U256::from(100)))
+///     .execute(&mut conn)
+///     .await?;
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Numeric<T>(pub T);
+
+/// Implements the `sqlx` traits for `Numeric<$t>` through the decimal
+/// representation of `$inner`.
+macro_rules! impl_numeric {
+    ([$($gen:tt)*] $t:ty as $inner:ty, $to:expr, $from:expr) => {
+        impl<$($gen)* DB: Database> Type<DB> for Numeric<$t>
+        where
+            BigDecimal: Type<DB>,
+        {
+            fn type_info() -> DB::TypeInfo {
+                <BigDecimal as Type<DB>>::type_info()
+            }
+
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                <BigDecimal as Type<DB>>::compatible(ty)
+            }
+        }
+
+        impl<'a, $($gen)* DB: Database> Encode<'a, DB> for Numeric<$t>
+        where
+            BigDecimal: Encode<'a, DB>,
+        {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <DB as Database>::ArgumentBuffer<'a>,
+            ) -> Result<IsNull, BoxDynError> {
+                let to: fn(&$t) -> $inner = $to;
+                to_decimal(to(&self.0))?.encode_by_ref(buf)
+            }
+        }
+
+        impl<'r, $($gen)* DB: Database> Decode<'r, DB> for Numeric<$t>
+        where
+            BigDecimal: Decode<'r, DB>,
+        {
+            fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+                let from: fn($inner) -> $t = $from;
+                from_decimal(<BigDecimal as Decode<'r, DB>>::decode(value)?).map(|x| Self(from(x)))
+            }
+        }
+    };
+}
+
+/// Converts an integer to a [`BigDecimal`].
+fn to_decimal(int: impl fmt::Display) -> Result<BigDecimal, BoxDynError> {
+    BigDecimal::parse_bytes(int.to_string().as_bytes(), 10).ok_or_else(|| "invalid integer".into())
+}
+
+/// Converts a [`BigDecimal`] to an integer, failing if it has a fractional
+/// part or does not fit.
+fn from_decimal<T>(decimal: BigDecimal) -> Result<T, BoxDynError>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    if !decimal.is_integer() {
+        return Err(format!("{decimal} is not an integer").into());
+    }
+    let (digits, _) = decimal.with_scale(0).into_bigint_and_exponent();
+    Ok(digits.to_string().parse()?)
+}
+
+impl_numeric!(
+    [const BITS: usize, const LIMBS: usize,] Uint<BITS, LIMBS> as Uint<BITS, LIMBS>,
+    |x| *x,
+    |x| x
+);
+
+impl_numeric!(
+    [const BITS: usize, const LIMBS: usize,] Signed<BITS, LIMBS> as Signed<BITS, LIMBS>,
+    |x| *x,
+    |x| x
+);
+
+#[cfg(feature = "seismic")]
+mod seismic {
+    use super::*;
+    use crate::{
+        aliases::{SInt, SUInt},
+        FlaggedStorage, SAddress,
+    };
+
+    impl_sqlx!([] SAddress as Address, |x| x.0, |x| Ok(Self(x)));
+
+    impl_numeric!(
+        [const BITS: usize, const LIMBS: usize,] SUInt<BITS, LIMBS> as Uint<BITS, LIMBS>,
+        |x| x.0,
+        SUInt
+    );
+
+    impl_numeric!(
+        [const BITS: usize, const LIMBS: usize,] SInt<BITS, LIMBS> as Signed<BITS, LIMBS>,
+        |x| x.0,
+        SInt
+    );
+
+    impl_sqlx!([] FlaggedStorage as FixedBytes<33>, |x| FixedBytes(x.to_be_bytes()), |bytes| {
+        Self::try_from_be_slice(bytes.as_slice()).ok_or_else(|| "invalid flagged storage".into())
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{I256, U256};
+
+    #[test]
+    fn numeric_conversions() {
+        let parse = |s: &str| BigDecimal::parse_bytes(s.as_bytes(), 10).unwrap();
+
+        assert_eq!(to_decimal(U256::MAX).unwrap(), parse(&U256::MAX.to_string()));
+        assert_eq!(from_decimal::<U256>(to_decimal(U256::MAX).unwrap()).unwrap(), U256::MAX);
+        assert_eq!(from_decimal::<I256>(to_decimal(I256::MIN).unwrap()).unwrap(), I256::MIN);
+        assert_eq!(from_decimal::<I256>(parse("-42.000")).unwrap(), I256::try_from(-42).unwrap());
+        assert_eq!(from_decimal::<U256>(parse("1e3")).unwrap(), U256::from(1000));
+        assert_eq!(from_decimal::<U256>(parse("0")).unwrap(), U256::ZERO);
+
+        assert!(from_decimal::<U256>(parse("1.5")).is_err());
+        assert!(from_decimal::<U256>(parse("-1")).is_err());
+        assert!(from_decimal::<U256>(to_decimal(U256::MAX).unwrap() + parse("1")).is_err());
+        assert!(from_decimal::<I256>(to_decimal(I256::MIN).unwrap() - parse("1")).is_err());
+    }
+
+    /// Encodes `value` for `DB`, returning the debug representation of the
+    /// argument buffer.
+    #[cfg(any(feature = "sqlx-postgres", feature = "sqlx-mysql", feature = "sqlx-sqlite"))]
+    fn encode<'q, DB: Database, T: Encode<'q, DB> + Type<DB>>(value: T) -> String
+    where
+        DB::ArgumentBuffer<'q>: Default + fmt::Debug,
+    {
+        let mut buf = Default::default();
+        assert!(!value.encode_by_ref(&mut buf).unwrap().is_null());
+        format!("{buf:?}")
+    }
+
+    #[test]
+    #[cfg(feature = "sqlx-postgres")]
+    fn postgres_numeric() {
+        use sqlx_postgres::Postgres;
+
+        let numeric = <BigDecimal as Type<Postgres>>::type_info();
+        assert_eq!(<I256 as Type<Postgres>>::type_info(), numeric);
+        assert_eq!(<Numeric<U256> as Type<Postgres>>::type_info(), numeric);
+
+        for int in [I256::ZERO, I256::MINUS_ONE, I256::MIN, I256::MAX] {
+            let decimal = to_decimal(int).unwrap();
+            assert_eq!(encode::<Postgres, _>(int), encode::<Postgres, _>(decimal.clone()));
+            assert_eq!(encode::<Postgres, _>(Numeric(int)), encode::<Postgres, _>(decimal));
+        }
+        let decimal = to_decimal(U256::MAX).unwrap();
+        assert_eq!(encode::<Postgres, _>(Numeric(U256::MAX)), encode::<Postgres, _>(decimal));
+
+        #[cfg(feature = "seismic")]
+        {
+            use crate::aliases::{SInt, SUInt};
+
+            assert_eq!(<SUInt<256, 4> as Type<Postgres>>::type_info(), numeric);
+            assert_eq!(<SInt<256, 4> as Type<Postgres>>::type_info(), numeric);
+            assert_eq!(
+                encode::<Postgres, _>(SUInt(U256::MAX)),
+                encode::<Postgres, _>(to_decimal(U256::MAX).unwrap())
+            );
+            assert_eq!(
+                encode::<Postgres, _>(SInt(I256::MIN)),
+                encode::<Postgres, _>(to_decimal(I256::MIN).unwrap())
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "sqlx-mysql")]
+    fn mysql_binary() {
+        use sqlx_mysql::MySql;
+
+        let int = I256::try_from(-42).unwrap();
+        assert_eq!(encode::<MySql, _>(int), encode::<MySql, _>(int.into_raw()));
+        assert_eq!(<I256 as Type<MySql>>::type_info(), <U256 as Type<MySql>>::type_info());
+        assert_eq!(encode::<MySql, _>(Numeric(int)), encode::<MySql, _>(to_decimal(int).unwrap()));
+
+        #[cfg(feature = "seismic")]
+        {
+            use crate::aliases::{SInt, SUInt};
+
+            assert_eq!(encode::<MySql, _>(SInt(int)), encode::<MySql, _>(int.into_raw()));
+            assert_eq!(encode::<MySql, _>(SUInt(U256::MAX)), encode::<MySql, _>(U256::MAX));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "sqlx-sqlite")]
+    fn sqlite_binary() {
+        use sqlx_sqlite::Sqlite;
+
+        let int = I256::try_from(-42).unwrap();
+        assert_eq!(encode::<Sqlite, _>(int), encode::<Sqlite, _>(int.into_raw()));
+        assert_eq!(<I256 as Type<Sqlite>>::type_info(), <U256 as Type<Sqlite>>::type_info());
+
+        #[cfg(feature = "seismic")]
+        {
+            use crate::aliases::{SInt, SUInt};
+
+            assert_eq!(encode::<Sqlite, _>(SInt(int)), encode::<Sqlite, _>(int.into_raw()));
+            assert_eq!(encode::<Sqlite, _>(SUInt(U256::MAX)), encode::<Sqlite, _>(U256::MAX));
+        }
+    }
+}
+
+/// Tests against the `sqlx` database drivers. These pull in SQLite and Tokio,
+/// so they only run with `RUSTFLAGS="--cfg alloy_db_tests"`.
+#[cfg(all(test, alloy_db_tests))]
+mod db_tests {
+    use super::*;
+    use crate::{B256, I256, U256};
+    use sqlx::{Connection, Row, SqliteConnection};
+
+    #[test]
+    fn numeric_impls() {
+        fn assert_numeric<DB: Database, T>()
+        where
+            T: for<'a> Encode<'a, DB> + for<'r> Decode<'r, DB> + Type<DB>,
+        {
+        }
+
+        assert_numeric::<sqlx::Postgres, Numeric<U256>>();
+        assert_numeric::<sqlx::Postgres, Numeric<I256>>();
+        assert_numeric::<sqlx::MySql, Numeric<U256>>();
+        assert_numeric::<sqlx::MySql, Numeric<I256>>();
+        #[cfg(feature = "seismic")]
+        {
+            assert_numeric::<sqlx::Postgres, Numeric<crate::aliases::SUInt<256, 4>>>();
+            assert_numeric::<sqlx::MySql, Numeric<crate::aliases::SInt<256, 4>>>();
+        }
+    }
+
+    async fn roundtrip<T>(conn: &mut SqliteConnection, value: T) -> T
+    where
+        T: for<'a> Encode<'a, sqlx::Sqlite>
+            + for<'r> Decode<'r, sqlx::Sqlite>
+            + Type<sqlx::Sqlite>
+            + Send
+            + 'static,
+    {
+        sqlx::query("DELETE FROM t").execute(&mut *conn).await.unwrap();
+        sqlx::query("INSERT INTO t (v) VALUES (?)").bind(value).execute(&mut *conn).await.unwrap();
+        sqlx::query("SELECT v FROM t").fetch_one(&mut *conn).await.unwrap().get(0)
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn sqlite_roundtrip() {
+        let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+        sqlx::query("CREATE TABLE t (v BLOB)").execute(&mut conn).await.unwrap();
+
+        let address = Address::repeat_byte(0xd8);
+        assert_eq!(roundtrip(&mut conn, address).await, address);
+        let hash = B256::repeat_byte(0xa1);
+        assert_eq!(roundtrip(&mut conn, hash).await, hash);
+        let bloom = Bloom::repeat_byte(0x42);
+        assert_eq!(roundtrip(&mut conn, bloom).await, bloom);
+        let bytes = Bytes::from_static(&[0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(roundtrip(&mut conn, bytes.clone()).await, bytes);
+        let int = I256::try_from(-42).unwrap();
+        #[cfg(feature = "sqlx-sqlite")]
+        assert_eq!(roundtrip(&mut conn, int).await, int);
+        let uint = U256::from(42);
+        assert_eq!(roundtrip(&mut conn, uint).await, uint);
+
+        let signature = Signature::from_scalars_and_parity(hash, B256::with_last_byte(7), true);
+        assert_eq!(roundtrip(&mut conn, signature).await, signature);
+
+        #[cfg(feature = "seismic")]
+        {
+            use crate::{
+                aliases::{SInt, SUInt},
+                FlaggedStorage, SAddress,
+            };
+
+            assert_eq!(roundtrip(&mut conn, SAddress(address)).await, SAddress(address));
+            #[cfg(feature = "sqlx-sqlite")]
+            {
+                assert_eq!(roundtrip(&mut conn, SUInt(uint)).await, SUInt(uint));
+                assert_eq!(roundtrip(&mut conn, SInt(int)).await, SInt(int));
+            }
+            let storage = FlaggedStorage::new(uint, true);
+            assert_eq!(roundtrip(&mut conn, storage).await, storage);
+        }
+
+        sqlx::query("INSERT INTO t (v) VALUES (x'0102')").execute(&mut conn).await.unwrap();
+        let row =
+            sqlx::query("SELECT v FROM t WHERE length(v) = 2").fetch_one(&mut conn).await.unwrap();
+        assert!(row.try_get::<Address, _>(0).is_err());
+        assert!(row.try_get::<Signature, _>(0).is_err());
+    }
+}
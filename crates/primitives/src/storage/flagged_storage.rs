@@ -10,6 +10,8 @@ use crate::{FixedBytes, U256};
 #[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "arbitrary", derive(Arbitrary))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "diesel", derive(diesel::AsExpression, diesel::FromSqlRow))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Binary))]
 pub struct FlaggedStorage {
     /// The value of the storage.
     pub value: U256,
@@ -27,19 +29,19 @@ where
 }
 
 impl From<FlaggedStorage> for FixedBytes<32> {
-    fn from(storage: FlaggedStorage) -> FixedBytes<32> {
-        FixedBytes::<32>::from(storage.value)
+    fn from(storage: FlaggedStorage) -> Self {
+        Self::from(storage.value)
     }
 }
 
 impl From<FlaggedStorage> for U256 {
-    fn from(storage: FlaggedStorage) -> U256 {
+    fn from(storage: FlaggedStorage) -> Self {
         storage.value
     }
 }
 
 impl From<&FlaggedStorage> for U256 {
-    fn from(storage: &FlaggedStorage) -> U256 {
+    fn from(storage: &FlaggedStorage) -> Self {
         storage.value
     }
 }
@@ -79,7 +81,7 @@ impl FlaggedStorage {
     /// Collect the values from a HashMap of FlaggedStorage values.
     #[cfg(feature = "std")]
     pub fn collect_value<S: core::hash::BuildHasher + Default>(
-        container: std::collections::HashMap<crate::B256, FlaggedStorage, S>,
+        container: std::collections::HashMap<crate::B256, Self, S>,
     ) -> std::collections::HashMap<crate::B256, U256, S> {
         container.into_iter().map(|(key, flagged_storage)| (key, flagged_storage.value)).collect()
     }
@@ -96,7 +98,7 @@ impl FlaggedStorage {
 
     /// Set the visibility of the storage.
     pub fn set_visibility(&self, is_private: bool) -> Self {
-        FlaggedStorage { value: self.value, is_private }
+        Self { value: self.value, is_private }
     }
 
     /// Mark the storage as private.
@@ -120,6 +122,33 @@ impl FlaggedStorage {
     pub fn equals_u256(&self, other: &U256) -> bool {
         self.value == *other && !self.is_private
     }
+
+    /// Encodes the storage as its 32-byte big-endian value followed by a
+    /// privacy flag byte, `0x01` if private and `0x00` otherwise.
+    ///
+    /// This is the representation used by the `postgres`, `diesel` and `sqlx`
+    /// integrations.
+    pub fn to_be_bytes(&self) -> [u8; 33] {
+        let mut bytes = [0; 33];
+        bytes[..32].copy_from_slice(&self.value.to_be_bytes::<32>());
+        bytes[32] = self.is_private as u8;
+        bytes
+    }
+
+    /// Decodes the storage from its [`to_be_bytes`](Self::to_be_bytes)
+    /// representation.
+    ///
+    /// Returns `None` if the slice is not 33 bytes long or the privacy flag
+    /// byte is neither `0x00` nor `0x01`.
+    pub fn try_from_be_slice(bytes: &[u8]) -> Option<Self> {
+        let (value, &[flag]) = bytes.split_first_chunk::<32>()? else { return None };
+        let is_private = match flag {
+            0 => false,
+            1 => true,
+            _ => return None,
+        };
+        Some(Self { value: U256::from_be_bytes(*value), is_private })
+    }
 }
 
 #[cfg(feature = "rlp")]
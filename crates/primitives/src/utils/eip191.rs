@@ -0,0 +1,133 @@
+use super::{Keccak256, EIP191_PREFIX};
+use crate::{Address, Bytes, B256};
+use alloc::vec::Vec;
+
+/// An [EIP-191] signed data message.
+///
+/// Every version is prefixed with `0x19` and a version byte, which makes the
+/// encoded message an invalid RLP item and thus distinct from any signed
+/// transaction.
+///
+/// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Eip191Message {
+    /// Version `0x00`: data with an intended validator, usually the contract
+    /// that verifies the signature.
+    ///
+    /// Encoded as `0x19 || 0x00 || validator || data`.
+    Validator {
+        /// The address of the intended validator.
+        validator: Address,
+        /// The data to sign.
+        data: Bytes,
+    },
+    /// Version `0x01`: [EIP-712] structured data.
+    ///
+    /// Encoded as `0x19 || 0x01 || domainSeparator || hashStruct(message)`.
+    ///
+    /// [EIP-712]: https://eips.ethereum.org/EIPS/eip-712
+    TypedData {
+        /// The EIP-712 domain separator.
+        domain_separator: B256,
+        /// The EIP-712 struct hash of the message.
+        struct_hash: B256,
+    },
+    /// Version `0x45` (`E`): a `personal_sign` message.
+    ///
+    /// Encoded as `"\x19Ethereum Signed Message:\n" || len(message) || message`.
+    /// See [`eip191_message`](super::eip191_message).
+    PersonalSign(Bytes),
+}
+
+impl Eip191Message {
+    /// Returns the version byte of this message.
+    #[inline]
+    pub const fn version(&self) -> u8 {
+        match self {
+            Self::Validator { .. } => 0x00,
+            Self::TypedData { .. } => 0x01,
+            Self::PersonalSign(_) => 0x45,
+        }
+    }
+
+    /// Encodes this message into the bytes that are hashed for signing.
+    pub fn encode(&self) -> Vec<u8> {
+        match self {
+            Self::Validator { validator, data } => {
+                let mut out = Vec::with_capacity(2 + 20 + data.len());
+                out.extend_from_slice(&[0x19, 0x00]);
+                out.extend_from_slice(validator.as_slice());
+                out.extend_from_slice(data);
+                out
+            }
+            Self::TypedData { domain_separator, struct_hash } => {
+                let mut out = Vec::with_capacity(2 + 32 + 32);
+                out.extend_from_slice(&[0x19, 0x01]);
+                out.extend_from_slice(domain_separator.as_slice());
+                out.extend_from_slice(struct_hash.as_slice());
+                out
+            }
+            Self::PersonalSign(message) => super::eip191_message(message),
+        }
+    }
+
+    /// Returns the [Keccak-256](super::keccak256) hash of the
+    /// [encoded](Self::encode) message, which is the digest that is signed.
+    pub fn hash(&self) -> B256 {
+        let mut hasher = Keccak256::new();
+        match self {
+            Self::Validator { validator, data } => {
+                hasher.update([0x19, 0x00]);
+                hasher.update(validator);
+                hasher.update(data);
+            }
+            Self::TypedData { domain_separator, struct_hash } => {
+                hasher.update([0x19, 0x01]);
+                hasher.update(domain_separator);
+                hasher.update(struct_hash);
+            }
+            Self::PersonalSign(message) => {
+                hasher.update(EIP191_PREFIX);
+                hasher.update(itoa::Buffer::new().format(message.len()));
+                hasher.update(message);
+            }
+        }
+        hasher.finalize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{eip191_hash_message, eip191_message, keccak256};
+
+    #[test]
+    fn encode_and_hash() {
+        let validator = address!("0x000000000000000000000000000000000000dEaD");
+        let msg = Eip191Message::Validator { validator, data: bytes!("cafe") };
+        assert_eq!(msg.version(), 0x00);
+        assert_eq!(msg.encode(), [&[0x19, 0x00][..], validator.as_slice(), &[0xca, 0xfe]].concat());
+        assert_eq!(msg.hash(), keccak256(msg.encode()));
+
+        // Mail example from EIP-712.
+        let msg = Eip191Message::TypedData {
+            domain_separator: b256!(
+                "0xf2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f"
+            ),
+            struct_hash: b256!(
+                "0xc52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e"
+            ),
+        };
+        assert_eq!(msg.version(), 0x01);
+        assert_eq!(msg.encode().len(), 66);
+        assert_eq!(
+            msg.hash(),
+            b256!("0xbe609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2")
+        );
+
+        let msg = Eip191Message::PersonalSign(Bytes::from_static(b"Hello World"));
+        assert_eq!(msg.version(), 0x45);
+        assert_eq!(msg.encode(), eip191_message("Hello World"));
+        assert_eq!(msg.hash(), eip191_hash_message("Hello World"));
+    }
+}
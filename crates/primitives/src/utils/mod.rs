@@ -8,6 +8,9 @@ use core::{
     mem::{ManuallyDrop, MaybeUninit},
 };
 
+mod eip191;
+pub use eip191::Eip191Message;
+
 mod units;
 pub use units::{
     format_ether, format_units, format_units_with, parse_ether, parse_units, DecimalSeparator,
@@ -108,19 +111,21 @@ pub fn vec_try_from_elem<T: Clone>(elem: T, n: usize) -> Result<Vec<T>, TryReser
     Ok(vec)
 }
 
-/// Hash a message according to [EIP-191] (version `0x01`).
+/// Hash a message according to [EIP-191] (version `0x45`).
 ///
 /// The final message is a UTF-8 string, encoded as follows:
 /// `"\x19Ethereum Signed Message:\n" + message.length + message`
 ///
 /// This message is then hashed using [Keccak-256](keccak256).
 ///
+/// See [`Eip191Message`] for the other versions.
+///
 /// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
 pub fn eip191_hash_message<T: AsRef<[u8]>>(message: T) -> B256 {
     keccak256(eip191_message(message))
 }
 
-/// Constructs a message according to [EIP-191] (version `0x01`).
+/// Constructs a message according to [EIP-191] (version `0x45`).
 ///
 /// The final message is a UTF-8 string, encoded as follows:
 /// `"\x19Ethereum Signed Message:\n" + message.length + message`
@@ -188,6 +193,51 @@ pub fn keccak256<T: AsRef<[u8]>>(bytes: T) -> B256 {
     keccak256(bytes.as_ref())
 }
 
+/// Computes the [Keccak-256](keccak256) hashes of many inputs.
+///
+/// This is equivalent to `inputs.iter().map(keccak256).collect()`, but with the
+/// `rayon` feature, large batches are hashed in parallel.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{keccak256, keccak256_batch};
+///
+/// let inputs: [&[u8]; 2] = [b"hello", b"world"];
+/// assert_eq!(keccak256_batch(&inputs), [keccak256("hello"), keccak256("world")]);
+/// ```
+pub fn keccak256_batch<T: AsRef<[u8]> + Sync>(inputs: &[T]) -> Vec<B256> {
+    cfg_if! {
+        if #[cfg(feature = "rayon")] {
+            /// Below this, spawning tasks costs more than hashing sequentially.
+            const PARALLEL_THRESHOLD: usize = 1024;
+
+            if inputs.len() >= PARALLEL_THRESHOLD {
+                use rayon::prelude::*;
+                return inputs.par_iter().map(keccak256).collect();
+            }
+        }
+    }
+    inputs.iter().map(keccak256).collect()
+}
+
+/// Computes the [Keccak-256](keccak256) hashes of a fixed number of inputs.
+///
+/// This is the array version of [`keccak256_batch`], which does not allocate.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{keccak256, keccak256_many};
+///
+/// let [a, b] = keccak256_many([&b"hello"[..], b"world"]);
+/// assert_eq!((a, b), (keccak256("hello"), keccak256("world")));
+/// ```
+#[inline]
+pub fn keccak256_many<const N: usize>(inputs: [&[u8]; N]) -> [B256; N] {
+    inputs.map(keccak256)
+}
+
 mod keccak256_state {
     cfg_if::cfg_if! {
         if #[cfg(all(feature = "asm-keccak", not(miri)))] {
@@ -351,6 +401,18 @@ mod tests {
         assert_eq!(hash, expected);
     }
 
+    #[test]
+    fn keccak256_batched() {
+        let inputs: Vec<Vec<u8>> = (0..2000u32).map(|i| vec![i as u8; i as usize % 300]).collect();
+        let expected: Vec<B256> = inputs.iter().map(keccak256).collect();
+        assert_eq!(keccak256_batch(&inputs), expected);
+        assert_eq!(keccak256_batch(&inputs[..3]), expected[..3]);
+        assert!(keccak256_batch::<&[u8]>(&[]).is_empty());
+
+        let [a, b, c] = keccak256_many([&inputs[0], &inputs[1], &inputs[1999]]);
+        assert_eq!([a, b, c], [expected[0], expected[1], expected[1999]]);
+    }
+
     #[test]
     fn test_try_boxing() {
         let x = Box::new(42);
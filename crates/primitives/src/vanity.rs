@@ -0,0 +1,411 @@
+//! Vanity address mining for `CREATE2` and `CREATE3` deployments.
+
+use crate::{Address, B256};
+use alloc::vec::Vec;
+use core::{fmt, ops::ControlFlow};
+
+/// The error type that is returned when building an [`AddressPattern`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PatternError {
+    /// The prefix or suffix contains a character that is not a hex digit.
+    InvalidHexCharacter {
+        /// The invalid character.
+        c: char,
+        /// The index of the character in the prefix or suffix.
+        index: usize,
+    },
+    /// The pattern does not fit in an address.
+    TooLong,
+}
+
+impl core::error::Error for PatternError {}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidHexCharacter { c, index } => {
+                write!(f, "invalid character {c:?} at position {index}")
+            }
+            Self::TooLong => f.write_str("pattern does not fit in an address"),
+        }
+    }
+}
+
+/// A pattern that vanity addresses must match.
+///
+/// An address matches if it starts with the given number of zero bytes and
+/// its hex representation starts with the prefix and ends with the suffix.
+/// Hex digits are compared case-insensitively, unless
+/// [`checksum_case`](Self::checksum_case) is set, in which case the letters of
+/// the prefix and suffix must also match the case of the [EIP-55] checksummed
+/// address.
+///
+/// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{address, AddressPattern};
+///
+/// let address = address!("0x000000000000000000000000000000000000dEaD");
+/// let pattern = AddressPattern::new().leading_zeros(4)?.suffix("dead")?;
+/// assert!(pattern.matches(&address));
+/// assert!(!pattern.checksum_case(true).matches(&address));
+/// assert!(AddressPattern::new().suffix("dEaD")?.checksum_case(true).matches(&address));
+/// # Ok::<_, alloy_primitives::PatternError>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AddressPattern {
+    /// ASCII hex digits, as given.
+    prefix: Vec<u8>,
+    /// ASCII hex digits, as given.
+    suffix: Vec<u8>,
+    /// In bytes.
+    leading_zeros: usize,
+    checksum_case: bool,
+}
+
+impl AddressPattern {
+    /// Creates a new pattern that matches every address.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { prefix: Vec::new(), suffix: Vec::new(), leading_zeros: 0, checksum_case: false }
+    }
+
+    /// Sets the hex prefix, with or without `0x`.
+    pub fn prefix(mut self, prefix: &str) -> Result<Self, PatternError> {
+        self.prefix = parse_hex_pattern(prefix)?;
+        self.check_len()
+    }
+
+    /// Sets the hex suffix.
+    pub fn suffix(mut self, suffix: &str) -> Result<Self, PatternError> {
+        self.suffix = parse_hex_pattern(suffix)?;
+        self.check_len()
+    }
+
+    /// Sets the number of leading zero bytes.
+    pub fn leading_zeros(mut self, bytes: usize) -> Result<Self, PatternError> {
+        self.leading_zeros = bytes;
+        self.check_len()
+    }
+
+    /// Sets whether the letters in the prefix and suffix must match the case
+    /// of the [EIP-55] checksummed address.
+    ///
+    /// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+    #[inline]
+    pub const fn checksum_case(mut self, yes: bool) -> Self {
+        self.checksum_case = yes;
+        self
+    }
+
+    /// Returns `true` if the given address matches this pattern.
+    pub fn matches(&self, address: &Address) -> bool {
+        if address[..self.leading_zeros].iter().any(|&b| b != 0) {
+            return false;
+        }
+
+        let nibble = |i: usize| {
+            let byte = address[i / 2];
+            let nibble = if i % 2 == 0 { byte >> 4 } else { byte & 0xf };
+            b"0123456789abcdef"[nibble as usize]
+        };
+        let suffix_start = 40 - self.suffix.len();
+        let nibbles_match =
+            self.prefix.iter().enumerate().all(|(i, c)| c.to_ascii_lowercase() == nibble(i))
+                && self
+                    .suffix
+                    .iter()
+                    .enumerate()
+                    .all(|(i, c)| c.to_ascii_lowercase() == nibble(suffix_start + i));
+        if !nibbles_match {
+            return false;
+        }
+
+        if !self.checksum_case {
+            return true;
+        }
+        let checksum = address.to_checksum_buffer(None);
+        let checksum = &checksum.as_str().as_bytes()[2..];
+        checksum.starts_with(&self.prefix) && checksum.ends_with(&self.suffix)
+    }
+
+    fn check_len(self) -> Result<Self, PatternError> {
+        if (self.leading_zeros * 2).max(self.prefix.len()) + self.suffix.len() > 40 {
+            return Err(PatternError::TooLong);
+        }
+        Ok(self)
+    }
+}
+
+fn parse_hex_pattern(s: &str) -> Result<Vec<u8>, PatternError> {
+    let s = s.strip_prefix("0x").unwrap_or(s);
+    if let Some((index, c)) = s.char_indices().find(|(_, c)| !c.is_ascii_hexdigit()) {
+        return Err(PatternError::InvalidHexCharacter { c, index });
+    }
+    Ok(s.as_bytes().to_vec())
+}
+
+/// How the contract whose address is mined is deployed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CreateScheme {
+    /// `CREATE2` with the given init code hash. See [`Address::create2`].
+    Create2 {
+        /// The hash of the contract's init code.
+        init_code_hash: B256,
+    },
+    /// `CREATE3`. See [`Address::create3`].
+    Create3,
+}
+
+/// A salt found by [`SaltMiner`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MinedSalt {
+    /// The salt.
+    pub salt: B256,
+    /// The address the contract is deployed at with this salt.
+    pub address: Address,
+    /// The number of salts that were tried, including this one.
+    pub attempts: u64,
+}
+
+/// Searches for `CREATE2` or `CREATE3` salts that deploy a contract at an
+/// address matching an [`AddressPattern`].
+///
+/// The `i`-th candidate salt is the [base salt](Self::base_salt) with its
+/// last 8 bytes replaced by `i` in big-endian order, starting from
+/// [`start`](Self::start). Candidates are searched in chunks of
+/// [`chunk_size`](Self::chunk_size), and in parallel within each chunk if the
+/// `rayon` feature is enabled. The first matching candidate is always
+/// returned, so the result does not depend on the number of threads.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{address, AddressPattern, CreateScheme, SaltMiner};
+///
+/// let deployer = address!("0x4e59b44847b379578588920cA78FbF26c0B4956C");
+/// let pattern = AddressPattern::new().prefix("00")?;
+/// let mined = SaltMiner::new(deployer, CreateScheme::Create3, pattern).mine().unwrap();
+/// assert_eq!(mined.address, deployer.create3(mined.salt));
+/// assert!(mined.address.to_string().starts_with("0x00"));
+/// # Ok::<_, alloy_primitives::PatternError>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SaltMiner {
+    deployer: Address,
+    scheme: CreateScheme,
+    pattern: AddressPattern,
+    base_salt: B256,
+    start: u64,
+    max_attempts: u64,
+    chunk_size: u64,
+}
+
+impl SaltMiner {
+    /// The default number of candidates that are searched between progress
+    /// reports.
+    pub const DEFAULT_CHUNK_SIZE: u64 = 1 << 16;
+
+    /// Creates a new miner for contracts deployed by `deployer`.
+    #[inline]
+    pub const fn new(deployer: Address, scheme: CreateScheme, pattern: AddressPattern) -> Self {
+        Self {
+            deployer,
+            scheme,
+            pattern,
+            base_salt: B256::ZERO,
+            start: 0,
+            max_attempts: u64::MAX,
+            chunk_size: Self::DEFAULT_CHUNK_SIZE,
+        }
+    }
+
+    /// Sets the base salt. Its last 8 bytes are replaced by the candidate
+    /// counter.
+    #[inline]
+    pub const fn base_salt(mut self, salt: B256) -> Self {
+        self.base_salt = salt;
+        self
+    }
+
+    /// Sets the first candidate counter. Defaults to 0.
+    ///
+    /// This can be used to resume a search, or to split it across machines.
+    #[inline]
+    pub const fn start(mut self, counter: u64) -> Self {
+        self.start = counter;
+        self
+    }
+
+    /// Sets the maximum number of candidates to try. Unlimited by default.
+    #[inline]
+    pub const fn max_attempts(mut self, attempts: u64) -> Self {
+        self.max_attempts = attempts;
+        self
+    }
+
+    /// Sets the number of candidates searched between progress reports.
+    /// Defaults to [`DEFAULT_CHUNK_SIZE`](Self::DEFAULT_CHUNK_SIZE).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    #[inline]
+    #[track_caller]
+    pub const fn chunk_size(mut self, chunk_size: u64) -> Self {
+        assert!(chunk_size > 0, "chunk size must not be zero");
+        self.chunk_size = chunk_size;
+        self
+    }
+
+    /// Returns the candidate salt for the given counter.
+    #[inline]
+    pub fn salt(&self, counter: u64) -> B256 {
+        let mut salt = self.base_salt;
+        salt[24..].copy_from_slice(&counter.to_be_bytes());
+        salt
+    }
+
+    /// Returns the deployment address for the given salt.
+    #[inline]
+    pub fn address(&self, salt: &B256) -> Address {
+        match &self.scheme {
+            CreateScheme::Create2 { init_code_hash } => self.deployer.create2(salt, init_code_hash),
+            CreateScheme::Create3 => self.deployer.create3(salt),
+        }
+    }
+
+    /// Searches for a matching salt until one is found or
+    /// [`max_attempts`](Self::max_attempts) candidates have been tried.
+    #[inline]
+    pub fn mine(&self) -> Option<MinedSalt> {
+        self.mine_with_progress(|_| ControlFlow::Continue(()))
+    }
+
+    /// Searches for a matching salt, calling `progress` with the total
+    /// number of candidates tried after each chunk without a match.
+    ///
+    /// The search is cancelled if `progress` returns [`ControlFlow::Break`].
+    /// Returns `None` if the search was cancelled or no salt was found within
+    /// [`max_attempts`](Self::max_attempts).
+    pub fn mine_with_progress(
+        &self,
+        mut progress: impl FnMut(u64) -> ControlFlow<()>,
+    ) -> Option<MinedSalt> {
+        let end = self.start.saturating_add(self.max_attempts);
+        let mut chunk_start = self.start;
+        while chunk_start < end {
+            let chunk_end = chunk_start.saturating_add(self.chunk_size).min(end);
+            if let Some(counter) = self.search(chunk_start, chunk_end) {
+                let salt = self.salt(counter);
+                let address = self.address(&salt);
+                return Some(MinedSalt { salt, address, attempts: counter - self.start + 1 });
+            }
+            chunk_start = chunk_end;
+            if progress(chunk_end - self.start).is_break() {
+                break;
+            }
+        }
+        None
+    }
+
+    fn search(&self, start: u64, end: u64) -> Option<u64> {
+        let is_match = |&counter: &u64| self.pattern.matches(&self.address(&self.salt(counter)));
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+            (start..end).into_par_iter().find_first(is_match)
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            (start..end).find(is_match)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pattern() {
+        let address = address!("0x00000000219ab540356cBB839Cbe05303d7705Fa");
+        assert!(AddressPattern::new().matches(&address));
+        assert!(AddressPattern::new().leading_zeros(4).unwrap().matches(&address));
+        assert!(!AddressPattern::new().leading_zeros(5).unwrap().matches(&address));
+        assert!(AddressPattern::new().prefix("0x0000000021").unwrap().matches(&address));
+        assert!(AddressPattern::new().prefix("00000000219AB5").unwrap().matches(&address));
+        assert!(AddressPattern::new().suffix("7705fA").unwrap().matches(&address));
+        assert!(!AddressPattern::new().suffix("7705fb").unwrap().matches(&address));
+
+        let checksum = |p: &str, s: &str| {
+            AddressPattern::new().prefix(p).unwrap().suffix(s).unwrap().checksum_case(true)
+        };
+        assert!(checksum("00000000219ab540356cBB", "05Fa").matches(&address));
+        assert!(!checksum("00000000219ab540356cbb", "").matches(&address));
+        assert!(!checksum("", "05FA").matches(&address));
+
+        assert_eq!(
+            AddressPattern::new().prefix("0xdeadbeeg"),
+            Err(PatternError::InvalidHexCharacter { c: 'g', index: 7 })
+        );
+        assert_eq!(AddressPattern::new().leading_zeros(21), Err(PatternError::TooLong));
+        let pattern = AddressPattern::new().prefix(&"0".repeat(30)).unwrap();
+        assert_eq!(pattern.clone().suffix(&"0".repeat(10)).map(drop), Ok(()));
+        assert_eq!(pattern.suffix(&"0".repeat(11)), Err(PatternError::TooLong));
+    }
+
+    #[test]
+    fn mine() {
+        let deployer = address!("0x4e59b44847b379578588920cA78FbF26c0B4956C");
+        let init_code_hash = B256::repeat_byte(0x42);
+        let pattern = AddressPattern::new().prefix("ab").unwrap();
+        let miner =
+            SaltMiner::new(deployer, CreateScheme::Create2 { init_code_hash }, pattern.clone())
+                .base_salt(B256::repeat_byte(0x11))
+                .chunk_size(7);
+
+        let expected = (0..)
+            .find(|&i| pattern.matches(&deployer.create2(miner.salt(i), init_code_hash)))
+            .unwrap();
+        let mined = miner.mine().unwrap();
+        assert_eq!(mined.attempts, expected + 1);
+        assert_eq!(mined.salt, miner.salt(expected));
+        assert_eq!(mined.salt[..24], [0x11; 24]);
+        assert_eq!(mined.address, deployer.create2(mined.salt, init_code_hash));
+        assert!(pattern.matches(&mined.address));
+
+        let resumed = miner.clone().start(expected + 1).mine().unwrap();
+        assert!(resumed.salt > mined.salt);
+
+        assert_eq!(miner.max_attempts(expected).mine(), None);
+    }
+
+    #[test]
+    fn mine_progress() {
+        let pattern = AddressPattern::new().leading_zeros(20).unwrap();
+        let miner = SaltMiner::new(Address::ZERO, CreateScheme::Create3, pattern).chunk_size(100);
+
+        let mut reports = Vec::new();
+        let mined = miner.clone().max_attempts(250).mine_with_progress(|n| {
+            reports.push(n);
+            ControlFlow::Continue(())
+        });
+        assert_eq!(mined, None);
+        assert_eq!(reports, [100, 200, 250]);
+
+        reports.clear();
+        let mined = miner.mine_with_progress(|n| {
+            reports.push(n);
+            if n >= 300 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        });
+        assert_eq!(mined, None);
+        assert_eq!(reports, [100, 200, 300]);
+    }
+}
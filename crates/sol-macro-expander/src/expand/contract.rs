@@ -36,6 +36,9 @@ pub(super) fn expand(cx: &mut ExpCtxt<'_>, contract: &ItemContract) -> Result<To
     let extra_methods = sol_attrs.extra_methods.or(cx.attrs.extra_methods).unwrap_or(false);
     let rpc = sol_attrs.rpc.or(cx.attrs.rpc).unwrap_or(false);
     let abi = sol_attrs.abi.or(cx.attrs.abi).unwrap_or(false);
+    let dispatch = sol_attrs.dispatch.or(cx.attrs.dispatch).unwrap_or(false);
+    let display = sol_attrs.display.or(cx.attrs.display).unwrap_or(false);
+    let unknown_variant = sol_attrs.unknown_variant.or(cx.attrs.unknown_variant).unwrap_or(false);
     let docs = sol_attrs.docs.or(cx.attrs.docs).unwrap_or(true);
 
     let bytecode = sol_attrs.bytecode.map(|lit| {
@@ -84,6 +87,8 @@ pub(super) fn expand(cx: &mut ExpCtxt<'_>, contract: &ItemContract) -> Result<To
         attrs.into_iter().partition::<Vec<_>, _>(|a| a.path().is_ident("doc"));
     mod_attrs.extend(item_attrs.iter().filter(|a| !a.path().is_ident("derive")).cloned());
 
+    // `display` also applies to the contract's items, unless they override it.
+    let prev_display = cx.attrs.display.replace(display);
     let mut item_tokens = TokenStream::new();
     for item in body {
         match item {
@@ -138,8 +143,15 @@ pub(super) fn expand(cx: &mut ExpCtxt<'_>, contract: &ItemContract) -> Result<To
             item_tokens.extend(cx.expand_item(&item)?);
         }
     }
-
-    let enum_expander = CallLikeExpander { cx, contract_name: name.clone(), extra_methods };
+    cx.attrs.display = prev_display;
+
+    let enum_expander = CallLikeExpander {
+        cx,
+        contract_name: name.clone(),
+        extra_methods,
+        display,
+        unknown_variant,
+    };
     // Remove any `Default` derives.
     let mut enum_attrs = item_attrs;
     for attr in &mut enum_attrs {
@@ -162,26 +174,32 @@ pub(super) fn expand(cx: &mut ExpCtxt<'_>, contract: &ItemContract) -> Result<To
         attr.meta = parse_quote! { derive(#(#derives),*) };
     }
 
-    let functions_enum = (!functions.is_empty()).then(|| {
-        let mut attrs = enum_attrs.clone();
-        let doc_str = format!("Container for all the [`{name}`](self) function calls.");
-        attrs.push(parse_quote!(#[doc = #doc_str]));
-        enum_expander.expand(ToExpand::Functions(&functions), attrs)
-    });
-
-    let errors_enum = (!errors.is_empty()).then(|| {
-        let mut attrs = enum_attrs.clone();
-        let doc_str = format!("Container for all the [`{name}`](self) custom errors.");
-        attrs.push(parse_quote!(#[doc = #doc_str]));
-        enum_expander.expand(ToExpand::Errors(&errors), attrs)
-    });
-
-    let events_enum = (!events.is_empty()).then(|| {
-        let mut attrs = enum_attrs;
-        let doc_str = format!("Container for all the [`{name}`](self) events.");
-        attrs.push(parse_quote!(#[doc = #doc_str]));
-        enum_expander.expand(ToExpand::Events(&events), attrs)
-    });
+    let functions_enum = (!functions.is_empty())
+        .then(|| {
+            let mut attrs = enum_attrs.clone();
+            let doc_str = format!("Container for all the [`{name}`](self) function calls.");
+            attrs.push(parse_quote!(#[doc = #doc_str]));
+            enum_expander.expand(ToExpand::Functions(&functions), attrs)
+        })
+        .transpose()?;
+
+    let errors_enum = (!errors.is_empty())
+        .then(|| {
+            let mut attrs = enum_attrs.clone();
+            let doc_str = format!("Container for all the [`{name}`](self) custom errors.");
+            attrs.push(parse_quote!(#[doc = #doc_str]));
+            enum_expander.expand(ToExpand::Errors(&errors), attrs)
+        })
+        .transpose()?;
+
+    let events_enum = (!events.is_empty())
+        .then(|| {
+            let mut attrs = enum_attrs;
+            let doc_str = format!("Container for all the [`{name}`](self) events.");
+            attrs.push(parse_quote!(#[doc = #doc_str]));
+            enum_expander.expand(ToExpand::Events(&events), attrs)
+        })
+        .transpose()?;
 
     let mod_descr_doc = (docs && docs_str(&mod_attrs).trim().is_empty())
         .then(|| mk_doc("Module containing a contract's types and functions."));
@@ -252,6 +270,9 @@ pub(super) fn expand(cx: &mut ExpCtxt<'_>, contract: &ItemContract) -> Result<To
         }
     });
 
+    let dispatch = (dispatch && !functions.is_empty())
+        .then(|| expand_dispatch(cx, name, &functions, !errors.is_empty(), unknown_variant));
+
     let rpc = rpc.then(|| {
         let contract_name = name;
         let name = format_ident!("{contract_name}Instance");
@@ -505,6 +526,8 @@ pub(super) fn expand(cx: &mut ExpCtxt<'_>, contract: &ItemContract) -> Result<To
 
             #abi
 
+            #dispatch
+
             #rpc
         }
     };
@@ -521,6 +544,9 @@ pub(super) fn expand(cx: &mut ExpCtxt<'_>, contract: &ItemContract) -> Result<To
 ///
 /// pub enum #name {
 ///    #(#variants(#types),)*
+///    #if unknown_variant
+///    Unknown { selector: [u8; 4], data: Bytes }, // `Unknown(LogData)` for events
+///    #endif
 /// }
 ///
 /// impl SolInterface for #name {
@@ -548,6 +574,8 @@ struct CallLikeExpander<'a> {
     cx: &'a ExpCtxt<'a>,
     contract_name: SolIdent,
     extra_methods: bool,
+    display: bool,
+    unknown_variant: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -557,6 +585,7 @@ struct ExpandData {
     types: Option<Vec<Ident>>,
     min_data_len: usize,
     trait_: Ident,
+    fmt_: Ident,
     selectors: Vec<ExprArray<u8>>,
 }
 
@@ -623,6 +652,7 @@ impl ToExpand<'_> {
                         .min()
                         .unwrap(),
                     trait_: format_ident!("SolCall"),
+                    fmt_: format_ident!("fmt_call"),
                     selectors: functions.iter().map(|f| cx.function_selector(f)).collect(),
                 }
             }
@@ -637,6 +667,7 @@ impl ToExpand<'_> {
                     .min()
                     .unwrap(),
                 trait_: format_ident!("SolError"),
+                fmt_: format_ident!("fmt_error"),
                 selectors: errors.iter().map(|e| cx.error_selector(e)).collect(),
             },
 
@@ -654,6 +685,7 @@ impl ToExpand<'_> {
                         .min()
                         .unwrap(),
                     trait_: format_ident!("SolEvent"),
+                    fmt_: format_ident!("fmt_event"),
                     selectors: events.iter().map(|e| cx.event_selector(e)).collect(),
                 }
             }
@@ -662,8 +694,14 @@ impl ToExpand<'_> {
 }
 
 impl CallLikeExpander<'_> {
-    fn expand(&self, to_expand: ToExpand<'_>, attrs: Vec<Attribute>) -> TokenStream {
+    fn expand(&self, to_expand: ToExpand<'_>, attrs: Vec<Attribute>) -> Result<TokenStream> {
         let data = &to_expand.to_data(self);
+        if self.unknown_variant {
+            if let Some(variant) = data.variants.iter().find(|v| *v == "Unknown") {
+                let msg = "`#[sol(unknown_variant)]` conflicts with an item named `Unknown`";
+                return Err(syn::Error::new(variant.span(), msg));
+            }
+        }
 
         let mut sorted_data = data.clone();
         sorted_data.sort_by_selector();
@@ -677,7 +715,7 @@ impl CallLikeExpander<'_> {
         }
 
         if let ToExpand::Events(events) = to_expand {
-            return self.expand_events(events, data, &sorted_data, attrs);
+            return Ok(self.expand_events(events, data, &sorted_data, attrs));
         }
 
         let def = self.generate_enum(data, &sorted_data, attrs);
@@ -689,7 +727,48 @@ impl CallLikeExpander<'_> {
         let sorted_variants = &sorted_data.variants;
         let sorted_types = sorted_data.types();
 
-        quote! {
+        let unknown = self.unknown_variant;
+        // Unknown data may be shorter than the known variants.
+        let min_data_len = if unknown { 0 } else { *min_data_len };
+        let unknown_selector =
+            unknown.then(|| quote!(Self::Unknown { selector, .. } => *selector,));
+        let unknown_size = unknown.then(|| quote!(Self::Unknown { data, .. } => data.len(),));
+        let unknown_encode =
+            unknown.then(|| quote!(Self::Unknown { data, .. } => out.extend_from_slice(data),));
+        let unknown_decode = if unknown {
+            quote! {
+                return Ok(Self::Unknown {
+                    selector,
+                    data: alloy_sol_types::private::Bytes::copy_from_slice(data),
+                });
+            }
+        } else {
+            quote! {
+                return Err(alloy_sol_types::Error::unknown_selector(
+                    <Self as alloy_sol_types::SolInterface>::NAME,
+                    selector,
+                ));
+            }
+        };
+
+        let nested_revert_data = (trait_ == "SolError").then(|| {
+            let unknown =
+                unknown.then(|| quote!(Self::Unknown { .. } => ::core::option::Option::None,));
+            quote! {
+                #[inline]
+                fn nested_revert_data(&self) -> ::core::option::Option<&[u8]> {
+                    match self {
+                        #(
+                            Self::#variants(inner) =>
+                                <#types as alloy_sol_types::SolError>::nested_revert_data(inner),
+                        )*
+                        #unknown
+                    }
+                }
+            }
+        });
+
+        Ok(quote! {
             #def
 
             #[automatically_derived]
@@ -697,12 +776,17 @@ impl CallLikeExpander<'_> {
                 const NAME: &'static str = #name_s;
                 const MIN_DATA_LENGTH: usize = #min_data_len;
                 const COUNT: usize = #count;
+                const SELECTOR_SET: alloy_sol_types::SelectorSet =
+                    alloy_sol_types::SelectorSet::List(Self::SELECTORS);
 
                 #[inline]
                 fn selector(&self) -> [u8; 4] {
-                    match self {#(
-                        Self::#variants(_) => <#types as alloy_sol_types::#trait_>::SELECTOR,
-                    )*}
+                    match self {
+                        #(
+                            Self::#variants(_) => <#types as alloy_sol_types::#trait_>::SELECTOR,
+                        )*
+                        #unknown_selector
+                    }
                 }
 
                 #[inline]
@@ -732,10 +816,7 @@ impl CallLikeExpander<'_> {
                     ];
 
                     let Ok(idx) = Self::SELECTORS.binary_search(&selector) else {
-                        return Err(alloy_sol_types::Error::unknown_selector(
-                            <Self as alloy_sol_types::SolInterface>::NAME,
-                            selector,
-                        ));
+                        #unknown_decode
                     };
                     // `SELECTORS` and `DECODE_SHIMS` have the same length and are sorted in the same order.
                     DECODE_SHIMS[idx](data)
@@ -758,10 +839,7 @@ impl CallLikeExpander<'_> {
                     ];
 
                     let Ok(idx) = Self::SELECTORS.binary_search(&selector) else {
-                        return Err(alloy_sol_types::Error::unknown_selector(
-                            <Self as alloy_sol_types::SolInterface>::NAME,
-                            selector,
-                        ));
+                        #unknown_decode
                     };
                     // `SELECTORS` and `DECODE_VALIDATE_SHIMS` have the same length and are sorted in the same order.
                     DECODE_VALIDATE_SHIMS[idx](data)
@@ -769,21 +847,29 @@ impl CallLikeExpander<'_> {
 
                 #[inline]
                 fn abi_encoded_size(&self) -> usize {
-                    match self {#(
-                        Self::#variants(inner) =>
-                            <#types as alloy_sol_types::#trait_>::abi_encoded_size(inner),
-                    )*}
+                    match self {
+                        #(
+                            Self::#variants(inner) =>
+                                <#types as alloy_sol_types::#trait_>::abi_encoded_size(inner),
+                        )*
+                        #unknown_size
+                    }
                 }
 
                 #[inline]
                 fn abi_encode_raw(&self, out: &mut alloy_sol_types::private::Vec<u8>) {
-                    match self {#(
-                        Self::#variants(inner) =>
-                            <#types as alloy_sol_types::#trait_>::abi_encode_raw(inner, out),
-                    )*}
+                    match self {
+                        #(
+                            Self::#variants(inner) =>
+                                <#types as alloy_sol_types::#trait_>::abi_encode_raw(inner, out),
+                        )*
+                        #unknown_encode
+                    }
                 }
+
+                #nested_revert_data
             }
-        }
+        })
     }
 
     fn expand_events(
@@ -803,14 +889,18 @@ impl CallLikeExpander<'_> {
         assert!(has_anon || has_non_anon, "events shouldn't be empty");
 
         let e_name = |&e: &&ItemEvent| self.cx.overloaded_name(e.into());
-        let err = quote! {
-            alloy_sol_types::private::Err(alloy_sol_types::Error::InvalidLog {
-                name: <Self as alloy_sol_types::SolEventInterface>::NAME,
-                log: alloy_sol_types::private::Box::new(alloy_sol_types::private::LogData::new_unchecked(
-                    topics.to_vec(),
-                    data.to_vec().into(),
-                )),
-            })
+        let log = quote! {
+            alloy_sol_types::private::LogData::new_unchecked(topics.to_vec(), data.to_vec().into())
+        };
+        let err = if self.unknown_variant {
+            quote!(alloy_sol_types::private::Ok(Self::Unknown(#log)))
+        } else {
+            quote! {
+                alloy_sol_types::private::Err(alloy_sol_types::Error::InvalidLog {
+                    name: <Self as alloy_sol_types::SolEventInterface>::NAME,
+                    log: alloy_sol_types::private::Box::new(#log),
+                })
+            }
         };
         let non_anon_impl = has_non_anon.then(|| {
             let variants = events.iter().filter(|e| !e.is_anonymous()).map(e_name);
@@ -842,21 +932,36 @@ impl CallLikeExpander<'_> {
         let into_impl = {
             let variants = events.iter().map(e_name);
             let v2 = variants.clone();
+            let (unknown_to, unknown_into) = self
+                .unknown_variant
+                .then(|| {
+                    (
+                        quote!(Self::Unknown(log) => ::core::clone::Clone::clone(log),),
+                        quote!(Self::Unknown(log) => log,),
+                    )
+                })
+                .unzip();
             quote! {
                 #[automatically_derived]
                 impl alloy_sol_types::private::IntoLogData for #name {
                     fn to_log_data(&self) -> alloy_sol_types::private::LogData {
-                        match self {#(
-                            Self::#variants(inner) =>
-                            alloy_sol_types::private::IntoLogData::to_log_data(inner),
-                        )*}
+                        match self {
+                            #(
+                                Self::#variants(inner) =>
+                                alloy_sol_types::private::IntoLogData::to_log_data(inner),
+                            )*
+                            #unknown_to
+                        }
                     }
 
                     fn into_log_data(self) -> alloy_sol_types::private::LogData {
-                        match self {#(
-                            Self::#v2(inner) =>
-                            alloy_sol_types::private::IntoLogData::into_log_data(inner),
-                        )*}
+                        match self {
+                            #(
+                                Self::#v2(inner) =>
+                                alloy_sol_types::private::IntoLogData::into_log_data(inner),
+                            )*
+                            #unknown_into
+                        }
                     }
                 }
             }
@@ -886,7 +991,7 @@ impl CallLikeExpander<'_> {
         sorted_data: &ExpandData,
         mut attrs: Vec<Attribute>,
     ) -> TokenStream {
-        let ExpandData { name, variants, .. } = data;
+        let ExpandData { name, variants, trait_, fmt_, .. } = data;
         let types = data.types();
 
         let selectors = &sorted_data.selectors;
@@ -897,6 +1002,27 @@ impl CallLikeExpander<'_> {
 
         self.cx.type_derives(&mut attrs, types.iter().cloned().map(ast::Type::custom), false);
 
+        let is_event = trait_ == "SolEvent";
+        let unknown_variant = self.unknown_variant.then(|| {
+            if is_event {
+                quote! {
+                    /// An event which is not known to this contract, with its raw topics and data.
+                    Unknown(alloy_sol_types::private::LogData),
+                }
+            } else {
+                quote! {
+                    /// A selector which is not known to this contract, with the ABI-encoded data
+                    /// which follows it.
+                    Unknown {
+                        /// The unknown selector.
+                        selector: #selector_type,
+                        /// The ABI-encoded data, without the selector.
+                        data: alloy_sol_types::private::Bytes,
+                    },
+                }
+            }
+        });
+
         let mut tokens = quote! {
             #(#attrs)*
             pub enum #name {
@@ -904,6 +1030,7 @@ impl CallLikeExpander<'_> {
                     #[allow(missing_docs)]
                     #variants(#types),
                 )*
+                #unknown_variant
             }
 
             #[automatically_derived]
@@ -932,6 +1059,45 @@ impl CallLikeExpander<'_> {
             });
         }
 
+        if self.display {
+            let unknown = self.unknown_variant.then(|| {
+                if is_event {
+                    quote! {
+                        Self::Unknown(log) => ::core::write!(
+                            f,
+                            "Unknown(topics: {:?}, data: {})",
+                            log.topics(),
+                            log.data,
+                        ),
+                    }
+                } else {
+                    quote! {
+                        Self::Unknown { selector, data } => ::core::write!(
+                            f,
+                            "Unknown(selector: {}, data: {})",
+                            alloy_sol_types::private::FixedBytes(*selector),
+                            data,
+                        ),
+                    }
+                }
+            });
+            tokens.extend(quote! {
+                #[automatically_derived]
+                impl ::core::fmt::Display for #name {
+                    #[inline]
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        match self {
+                            #(
+                                Self::#variants(inner) =>
+                                    <#types as alloy_sol_types::#trait_>::#fmt_(inner, f),
+                            )*
+                            #unknown
+                        }
+                    }
+                }
+            });
+        }
+
         tokens
     }
 }
@@ -1005,6 +1171,119 @@ fn generate_variant_methods((variant, ty): (&Ident, &Ident)) -> TokenStream {
     }
 }
 
+/// Expands the `#[sol(dispatch)]` mock dispatcher:
+///
+/// ```ignore (pseudo-code)
+/// pub trait #{name}Handler {
+///     fn #function_name(&mut self, #(#params),*) -> Result<#function_return, #error>;
+/// }
+///
+/// pub fn dispatch<H: ?Sized + #{name}Handler>(handler: &mut H, calldata: &[u8]) -> Result<Bytes, Bytes> {
+///     ...
+/// }
+/// ```
+fn expand_dispatch(
+    cx: &ExpCtxt<'_>,
+    name: &SolIdent,
+    functions: &[ItemFunction],
+    has_errors: bool,
+    unknown_variant: bool,
+) -> TokenStream {
+    let handler = format_ident!("{name}Handler");
+    let calls = format_ident!("{name}Calls");
+    let error = if has_errors {
+        let errors = format_ident!("{name}Errors");
+        quote!(alloy_sol_types::ContractError<#errors>)
+    } else {
+        quote!(alloy_sol_types::GenericContractError)
+    };
+
+    let methods = functions.iter().map(|f| {
+        let name = cx.function_name(f);
+        let call_name = cx.call_name(f);
+        let param_names = f.parameters.names().enumerate().map(anon_name);
+        let param_tys = f.parameters.types().map(|ty| cx.expand_rust_type(ty));
+        let doc = format!("Handles a call to the [`{name}`]({call_name}) function.");
+        quote! {
+            #[doc = #doc]
+            fn #name(&mut self, #(#param_names: #param_tys),*)
+                -> ::core::result::Result<<#call_name as alloy_sol_types::SolCall>::Return, #error>;
+        }
+    });
+
+    let arms = functions.iter().map(|f| {
+        let name = cx.function_name(f);
+        let call_name = cx.call_name(f);
+        let variant = &name;
+        let param_names = f.parameters.names().enumerate().map(anon_name);
+        // Bind the fields to generated names, so that parameters such as `handler` or
+        // `calldata` do not shadow the dispatcher's own variables.
+        let bindings = (0..f.parameters.len()).map(|i| format_ident!("_{i}")).collect::<Vec<_>>();
+        let pattern = if f.parameters.is_empty() {
+            quote! { #call_name }
+        } else if f.parameters.len() == 1 && f.parameters[0].name.is_none() {
+            quote! { #call_name(_0) }
+        } else {
+            quote! { #call_name { #(#param_names: #bindings),* } }
+        };
+        quote! {
+            #calls::#variant(#pattern) => handler.#name(#(#bindings),*).map(|ret| {
+                <#call_name as alloy_sol_types::SolCall>::abi_encode_returns(&ret)
+            }),
+        }
+    });
+
+    let unknown_arm = unknown_variant.then(|| {
+        quote! {
+            #calls::Unknown { .. } => {
+                return ::core::result::Result::Err(alloy_sol_types::private::Bytes::new());
+            }
+        }
+    });
+
+    let handler_doc = format!(
+        "Rust implementation of the [`{name}`](self) contract's functions, called by [`dispatch`].\n\
+         \n\
+         Returning an error reverts the call with its ABI encoding."
+    );
+    let dispatch_doc = format!(
+        "Decodes the given calldata and calls the corresponding [`{handler}`] method.\n\
+         \n\
+         Returns the ABI-encoded return values on success, or the ABI-encoded error on revert.\n\
+         Calldata which does not match any function, or which cannot be decoded, reverts with\n\
+         empty data."
+    );
+    quote! {
+        #[doc = #handler_doc]
+        #[allow(non_camel_case_types, non_snake_case, clippy::too_many_arguments)]
+        pub trait #handler {
+            #(#methods)*
+        }
+
+        #[doc = #dispatch_doc]
+        pub fn dispatch<H: ?::core::marker::Sized + #handler>(
+            handler: &mut H,
+            calldata: &[u8],
+        ) -> ::core::result::Result<alloy_sol_types::private::Bytes, alloy_sol_types::private::Bytes> {
+            let ::core::result::Result::Ok(call) =
+                <#calls as alloy_sol_types::SolInterface>::abi_decode(calldata)
+            else {
+                return ::core::result::Result::Err(alloy_sol_types::private::Bytes::new());
+            };
+            let result = match call {
+                #(#arms)*
+                #unknown_arm
+            };
+            match result {
+                ::core::result::Result::Ok(ret) => ::core::result::Result::Ok(ret.into()),
+                ::core::result::Result::Err(err) => ::core::result::Result::Err(
+                    <#error as alloy_sol_types::SolInterface>::abi_encode(&err).into(),
+                ),
+            }
+        }
+    }
+}
+
 fn call_builder_method(f: &ItemFunction, cx: &ExpCtxt<'_>) -> TokenStream {
     let name = cx.function_name(f);
     let call_name = cx.call_name(f);
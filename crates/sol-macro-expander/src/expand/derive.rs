@@ -0,0 +1,320 @@
+//! `#[derive(SolType)]` and `#[derive(SolValue)]` expansion.
+
+use super::{r#enum, r#struct, ExpCtxt, ExternCrates};
+use alloy_sol_macro_input::{CasingStyle, SolAttrs};
+use ast::{File, Type};
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{
+    ext::IdentExt, spanned::Spanned, Attribute, Data, DataEnum, DataStruct, DeriveInput, Error,
+    Fields, LitStr, Result,
+};
+
+/// The trait implemented by a derive macro.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeriveKind {
+    /// `#[derive(SolType)]`: implements `SolType`, and `SolStruct` or `SolEnum`.
+    SolType,
+    /// `#[derive(SolValue)]`: implements `SolValue` with `SolType = Self`.
+    SolValue,
+}
+
+/// Container `#[sol(...)]` attributes supported by the derive macros.
+struct ContainerAttrs {
+    rename: Option<LitStr>,
+    rename_all: Option<CasingStyle>,
+    crates: ExternCrates,
+}
+
+impl ContainerAttrs {
+    fn parse(input: &DeriveInput) -> Result<Self> {
+        let (mut attrs, _) = SolAttrs::parse(&input.attrs)?;
+        let rename = attrs.rename.take();
+        let rename_all = attrs.rename_all.take();
+        let mut crates = ExternCrates::default();
+        if let Some(sol_types) = attrs.alloy_sol_types.take() {
+            crates.sol_types = sol_types;
+        }
+        if attrs != SolAttrs::default() {
+            return Err(Error::new(
+                input.ident.span(),
+                "only `rename`, `rename_all` and `alloy_sol_types` are supported here",
+            ));
+        }
+        Ok(Self { rename, rename_all, crates })
+    }
+}
+
+/// Field `#[sol(...)]` attributes.
+#[derive(Default)]
+struct FieldAttrs {
+    /// `#[sol(rename = "name")]`
+    rename: Option<LitStr>,
+    /// `#[sol(ty = "uint96")]`
+    ty: Option<Type>,
+}
+
+impl FieldAttrs {
+    fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut this = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("sol")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    if this.rename.is_some() {
+                        return Err(meta.error("duplicate attribute"));
+                    }
+                    this.rename = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("ty") {
+                    if this.ty.is_some() {
+                        return Err(meta.error("duplicate attribute"));
+                    }
+                    let lit = meta.value()?.parse::<LitStr>()?;
+                    let ty = lit.parse::<Type>()?;
+                    if ty.has_custom() {
+                        return Err(Error::new(
+                            lit.span(),
+                            "custom types are not supported here; use the Rust type instead",
+                        ));
+                    }
+                    this.ty = Some(ty);
+                } else {
+                    return Err(meta.error("unknown `sol` attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(this)
+    }
+}
+
+/// Returns `true` if the enum variant is marked with `#[sol(fallback)]`.
+fn is_fallback(attrs: &[Attribute]) -> Result<bool> {
+    let mut fallback = false;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("sol")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("fallback") {
+                if fallback {
+                    return Err(meta.error("duplicate attribute"));
+                }
+                fallback = true;
+            } else {
+                return Err(meta.error("unknown `sol` attribute"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(fallback)
+}
+
+/// Expands a `#[derive(SolType)]` or `#[derive(SolValue)]` macro.
+pub(super) fn expand(input: &DeriveInput, kind: DeriveKind) -> Result<TokenStream> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(input.generics.span(), "generic types are not supported"));
+    }
+
+    let attrs = ContainerAttrs::parse(input)?;
+    let name = &input.ident;
+    let alloy_sol_types = &attrs.crates.sol_types;
+
+    let impls = match kind {
+        DeriveKind::SolValue => quote! {
+            #[automatically_derived]
+            impl alloy_sol_types::SolValue for #name {
+                type SolType = Self;
+            }
+        },
+        DeriveKind::SolType => match &input.data {
+            Data::Struct(data) => expand_struct(input, data, &attrs)?,
+            Data::Enum(data) => expand_enum(input, data, &attrs)?,
+            Data::Union(_) => return Err(Error::new(name.span(), "unions are not supported")),
+        },
+    };
+
+    Ok(quote! {
+        #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+        const _: () = {
+            use #alloy_sol_types as alloy_sol_types;
+
+            #impls
+        };
+    })
+}
+
+/// Expands a struct with named fields like an [`ItemStruct`](ast::ItemStruct).
+///
+/// Field types are mapped to Solidity types through their `SolValue`
+/// implementation, unless overridden with `#[sol(ty = "...")]`, and the
+/// EIP-712 type strings are built at runtime from the fields' `SOL_NAME`s.
+fn expand_struct(
+    input: &DeriveInput,
+    data: &DataStruct,
+    attrs: &ContainerAttrs,
+) -> Result<TokenStream> {
+    let name = &input.ident;
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new(name.span(), "only structs with named fields are supported"));
+    };
+    if fields.named.is_empty() {
+        return Err(Error::new(name.span(), "struct with zero fields"));
+    }
+
+    let dummy_file = File { attrs: Vec::new(), items: Vec::new() };
+    let mut cx = ExpCtxt::new(&dummy_file);
+    cx.crates = attrs.crates.clone();
+
+    let mut field_names = Vec::with_capacity(fields.named.len());
+    let mut field_types = Vec::with_capacity(fields.named.len());
+    let mut eip712_names = Vec::with_capacity(fields.named.len());
+    for field in &fields.named {
+        let ident = field.ident.clone().unwrap();
+        let field_attrs = FieldAttrs::parse(&field.attrs)?;
+        let ty = &field.ty;
+        field_types.push(match &field_attrs.ty {
+            Some(sol_ty) => cx.expand_type(sol_ty),
+            None => quote!(<#ty as alloy_sol_types::SolValue>::SolType),
+        });
+        eip712_names.push(match (field_attrs.rename, attrs.rename_all) {
+            (Some(rename), _) => rename.value(),
+            (None, Some(casing)) => casing.apply(&ident.unraw().to_string()),
+            (None, None) => ident.unraw().to_string(),
+        });
+        field_names.push(ident);
+    }
+
+    let name_s = attrs.rename.as_ref().map_or_else(|| name.unraw().to_string(), LitStr::value);
+    let convert = expand_from_into_tuples(name, &field_names, &field_types);
+    let eip712_encode_type_fns = expand_encode_type_fns(&name_s, &eip712_names, &field_types);
+    let impls = r#struct::expand_impls(
+        name,
+        &name_s,
+        &field_names,
+        &eip712_names,
+        &field_types,
+        eip712_encode_type_fns,
+    );
+
+    Ok(quote! {
+        #convert
+        #impls
+    })
+}
+
+/// Expands a field-less enum like an [`ItemEnum`](ast::ItemEnum), as `uint8`.
+///
+/// Since no hidden variant can be added to the enum, out-of-range values are
+/// decoded as the variant marked with `#[sol(fallback)]` when decoding without
+/// validation. This is required unless the enum has 256 variants.
+fn expand_enum(
+    input: &DeriveInput,
+    data: &DataEnum,
+    attrs: &ContainerAttrs,
+) -> Result<TokenStream> {
+    let name = &input.ident;
+    if data.variants.is_empty() {
+        return Err(Error::new(name.span(), "enum has no variants"));
+    }
+    if data.variants.len() > 256 {
+        return Err(Error::new(name.span(), "enum has too many variants"));
+    }
+    let mut fallback = None;
+    for variant in &data.variants {
+        if is_fallback(&variant.attrs)? {
+            if fallback.is_some() {
+                return Err(Error::new(variant.span(), "only one variant can be the fallback"));
+            }
+            fallback = Some(&variant.ident);
+        }
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new(variant.span(), "only unit variants are supported"));
+        }
+        if let Some((_, discriminant)) = &variant.discriminant {
+            return Err(Error::new(
+                discriminant.span(),
+                "explicit discriminants are not supported",
+            ));
+        }
+    }
+
+    let name_s = attrs.rename.as_ref().map_or_else(|| name.unraw().to_string(), LitStr::value);
+    let variants = data.variants.iter().map(|variant| &variant.ident).collect::<Vec<_>>();
+    let detokenize_unwrap = match fallback {
+        Some(fallback) => quote! { unwrap_or(Self::#fallback) },
+        None if variants.len() == 256 => quote! { expect("unreachable") },
+        None => {
+            return Err(Error::new(
+                name.span(),
+                "enums with fewer than 256 variants need a `#[sol(fallback)]` variant to decode \
+                 out-of-range values as",
+            ))
+        }
+    };
+    Ok(r#enum::expand_impls(name, &name_s, &variants, detokenize_unwrap))
+}
+
+/// Expands `From` impls between the struct and its underlying tuple.
+///
+/// The Rust tuple is made of the Solidity types' `RustType`s, which may differ
+/// from the field types, so conversions go through `Into`.
+fn expand_from_into_tuples(
+    name: &Ident,
+    field_names: &[Ident],
+    field_types: &[TokenStream],
+) -> TokenStream {
+    let idxs = (0..field_names.len()).map(syn::Index::from);
+    quote! {
+        #[doc(hidden)]
+        type UnderlyingSolTuple<'a> = (#(#field_types,)*);
+        #[doc(hidden)]
+        type UnderlyingRustTuple<'a> = (#(<#field_types as alloy_sol_types::SolType>::RustType,)*);
+
+        #[automatically_derived]
+        #[doc(hidden)]
+        impl ::core::convert::From<#name> for UnderlyingRustTuple<'_> {
+            fn from(value: #name) -> Self {
+                (#(::core::convert::Into::into(value.#field_names),)*)
+            }
+        }
+
+        #[automatically_derived]
+        #[doc(hidden)]
+        impl ::core::convert::From<UnderlyingRustTuple<'_>> for #name {
+            fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
+                Self { #(#field_names: ::core::convert::Into::into(tuple.#idxs)),* }
+            }
+        }
+    }
+}
+
+fn expand_encode_type_fns(
+    name_s: &str,
+    eip712_names: &[String],
+    field_types: &[TokenStream],
+) -> TokenStream {
+    let mut root = Vec::with_capacity(field_types.len() * 4 + 2);
+    root.push(format!("{name_s}(").into_token_stream());
+    for (i, (name, ty)) in eip712_names.iter().zip(field_types).enumerate() {
+        if i > 0 {
+            root.push(",".into_token_stream());
+        }
+        root.push(quote!(<#ty as alloy_sol_types::SolType>::SOL_NAME));
+        root.push(format!(" {name}").into_token_stream());
+    }
+    root.push(")".into_token_stream());
+
+    let capacity = proc_macro2::Literal::usize_unsuffixed(field_types.len());
+    quote! {
+        #[inline]
+        fn eip712_root_type() -> alloy_sol_types::private::Cow<'static, str> {
+            alloy_sol_types::private::Cow::Owned([#(#root),*].concat())
+        }
+
+        #[inline]
+        fn eip712_components() -> alloy_sol_types::private::Vec<alloy_sol_types::private::Cow<'static, str>> {
+            let mut components = alloy_sol_types::private::Vec::with_capacity(#capacity);
+            #(
+                components.extend(<#field_types as alloy_sol_types::SolType>::eip712_referenced_types());
+            )*
+            components
+        }
+    }
+}
@@ -3,9 +3,9 @@
 use super::ExpCtxt;
 use alloy_sol_macro_input::{derives_mapped, mk_doc, ContainsSolAttrs};
 use ast::{ItemEnum, Spanned};
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, TokenStream};
 use quote::quote;
-use syn::Result;
+use syn::{ext::IdentExt, Result};
 
 /// Expands an [`ItemEnum`]:
 ///
@@ -67,14 +67,8 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
 
     let alloy_sol_types = &cx.crates.sol_types;
 
-    let uint8 = quote!(alloy_sol_types::sol_data::Uint<8>);
-    let uint8_st = quote!(<#uint8 as alloy_sol_types::SolType>);
-
-    let index_to_variant = variants.iter().enumerate().map(|(idx, variant)| {
-        let ident = &variant.ident;
-        let idx = idx as u8;
-        quote! { #idx => ::core::result::Result::Ok(Self::#ident), }
-    });
+    let idents = variants.iter().map(|variant| &variant.ident.0).collect::<Vec<_>>();
+    let impls = expand_impls(&name.0, &name_s, &idents, detokenize_unwrap);
 
     let doc = docs.then(|| mk_doc(format!("```solidity\n{enumm}\n```")));
     let tokens = quote! {
@@ -92,107 +86,149 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
         const _: () = {
             use #alloy_sol_types as alloy_sol_types;
 
-            #[automatically_derived]
-            impl ::core::convert::From<#name> for u8 {
-                #[inline]
-                fn from(v: #name) -> Self {
-                    v as u8
-                }
-            }
-
-            #[automatically_derived]
-            impl ::core::convert::TryFrom<u8> for #name {
-                type Error = alloy_sol_types::Error;
-
-                #[inline]
-                fn try_from(value: u8) -> alloy_sol_types::Result<Self> {
-                    match value {
-                        #(#index_to_variant)*
-                        value => ::core::result::Result::Err(alloy_sol_types::Error::InvalidEnumValue {
-                            name: #name_s,
-                            value,
-                            max: #max,
-                        })
-                    }
-                }
-            }
-
             #[automatically_derived]
             impl alloy_sol_types::SolValue for #name {
                 type SolType = Self;
             }
 
-            #[automatically_derived]
-            impl alloy_sol_types::private::SolTypeValue<#name> for #name {
-                #[inline]
-                fn stv_to_tokens(&self) -> #uint8_st::Token<'_> {
-                    alloy_sol_types::Word::with_last_byte(*self as u8).into()
-                }
-
-                #[inline]
-                fn stv_eip712_data_word(&self) -> alloy_sol_types::Word {
-                    #uint8_st::eip712_data_word(&(*self as u8))
-                }
-
-                #[inline]
-                fn stv_abi_encode_packed_to(&self, out: &mut alloy_sol_types::private::Vec<u8>) {
-                    out.push(*self as u8);
-                }
-            }
+            #impls
+        };
+    };
+    Ok(tokens)
+}
 
-            #[automatically_derived]
-            impl alloy_sol_types::SolType for #name {
-                type RustType = #name;
-                type Token<'a> = #uint8_st::Token<'a>;
-
-                const SOL_NAME: &'static str = #uint8_st::SOL_NAME;
-                const ENCODED_SIZE: ::core::option::Option<usize> = #uint8_st::ENCODED_SIZE;
-                const PACKED_ENCODED_SIZE: ::core::option::Option<usize> = #uint8_st::PACKED_ENCODED_SIZE;
-
-                #[inline]
-                fn valid_token(token: &Self::Token<'_>) -> bool {
-                    Self::type_check(token).is_ok()
-                }
-
-                #[inline]
-                fn type_check(token: &Self::Token<'_>) -> alloy_sol_types::Result<()> {
-                    #uint8_st::type_check(token)?;
-                    <Self as ::core::convert::TryFrom<u8>>::try_from(
-                        #uint8_st::detokenize(*token)
-                    ).map(::core::mem::drop)
-                }
-
-                #[inline]
-                fn detokenize(token: Self::Token<'_>) -> Self::RustType {
-                    <Self as ::core::convert::TryFrom<u8>>::try_from(
-                        #uint8_st::detokenize(token)
-                    ).#detokenize_unwrap
-                }
-            }
+/// Expands the trait implementations shared by [`ItemEnum`]s and
+/// `#[derive(SolType)]` enums: conversions to and from `u8`, `SolTypeValue`,
+/// `SolType`, `EventTopic` and `SolEnum`.
+///
+/// Expects `alloy_sol_types` to be in scope.
+pub(super) fn expand_impls(
+    name: &Ident,
+    name_s: &str,
+    variants: &[&Ident],
+    detokenize_unwrap: TokenStream,
+) -> TokenStream {
+    let count = variants.len();
+    let max = (count - 1) as u8;
 
-            #[automatically_derived]
-            impl alloy_sol_types::EventTopic for #name {
-                #[inline]
-                fn topic_preimage_length(rust: &Self::RustType) -> usize {
-                    <#uint8 as alloy_sol_types::EventTopic>::topic_preimage_length(&(*rust as u8))
-                }
-
-                #[inline]
-                fn encode_topic_preimage(rust: &Self::RustType, out: &mut alloy_sol_types::private::Vec<u8>) {
-                    <#uint8 as alloy_sol_types::EventTopic>::encode_topic_preimage(&(*rust as u8), out);
-                }
-
-                #[inline]
-                fn encode_topic(rust: &Self::RustType) -> alloy_sol_types::abi::token::WordToken {
-                    <#uint8 as alloy_sol_types::EventTopic>::encode_topic(&(*rust as u8))
-                }
+    let uint8 = quote!(alloy_sol_types::sol_data::Uint<8>);
+    let uint8_st = quote!(<#uint8 as alloy_sol_types::SolType>);
+
+    let index_to_variant = variants.iter().enumerate().map(|(idx, ident)| {
+        let idx = idx as u8;
+        quote! { #idx => ::core::result::Result::Ok(Self::#ident), }
+    });
+
+    let index_to_label = variants.iter().enumerate().map(|(idx, ident)| {
+        let idx = idx as u8;
+        let label = format!("{name_s}.{}", ident.unraw());
+        quote! { #idx => f.write_str(#label), }
+    });
+    let unknown_fmt = format!("{name_s}({{}})");
+
+    quote! {
+    #[automatically_derived]
+    impl ::core::convert::From<#name> for u8 {
+        #[inline]
+        fn from(v: #name) -> Self {
+            v as u8
+        }
+    }
+
+    #[automatically_derived]
+    impl ::core::convert::TryFrom<u8> for #name {
+        type Error = alloy_sol_types::Error;
+
+        #[inline]
+        fn try_from(value: u8) -> alloy_sol_types::Result<Self> {
+            match value {
+                #(#index_to_variant)*
+                value => ::core::result::Result::Err(alloy_sol_types::Error::InvalidEnumValue {
+                    name: #name_s,
+                    value,
+                    max: #max,
+                })
             }
+        }
+    }
 
-            #[automatically_derived]
-            impl alloy_sol_types::SolEnum for #name {
-                const COUNT: usize = #count;
+    #[automatically_derived]
+    impl alloy_sol_types::private::SolTypeValue<#name> for #name {
+        #[inline]
+        fn stv_to_tokens(&self) -> #uint8_st::Token<'_> {
+            alloy_sol_types::Word::with_last_byte(*self as u8).into()
+        }
+
+        #[inline]
+        fn stv_eip712_data_word(&self) -> alloy_sol_types::Word {
+            #uint8_st::eip712_data_word(&(*self as u8))
+        }
+
+        #[inline]
+        fn stv_abi_encode_packed_to(&self, out: &mut alloy_sol_types::private::Vec<u8>) {
+            out.push(*self as u8);
+        }
+    }
+
+    #[automatically_derived]
+    impl alloy_sol_types::SolType for #name {
+        type RustType = #name;
+        type Token<'a> = #uint8_st::Token<'a>;
+
+        const SOL_NAME: &'static str = #uint8_st::SOL_NAME;
+        const ENCODED_SIZE: ::core::option::Option<usize> = #uint8_st::ENCODED_SIZE;
+        const PACKED_ENCODED_SIZE: ::core::option::Option<usize> = #uint8_st::PACKED_ENCODED_SIZE;
+
+        #[inline]
+        fn valid_token(token: &Self::Token<'_>) -> bool {
+            Self::type_check(token).is_ok()
+        }
+
+        #[inline]
+        fn type_check(token: &Self::Token<'_>) -> alloy_sol_types::Result<()> {
+            #uint8_st::type_check(token)?;
+            <Self as ::core::convert::TryFrom<u8>>::try_from(
+                #uint8_st::detokenize(*token)
+            ).map(::core::mem::drop)
+        }
+
+        #[inline]
+        fn detokenize(token: Self::Token<'_>) -> Self::RustType {
+            <Self as ::core::convert::TryFrom<u8>>::try_from(
+                #uint8_st::detokenize(token)
+            ).#detokenize_unwrap
+        }
+
+        #[inline]
+        fn fmt_token_with(token: &Self::Token<'_>, f: &mut ::core::fmt::Formatter<'_>, _redact: bool) -> ::core::fmt::Result {
+            match #uint8_st::detokenize(*token) {
+                #(#index_to_label)*
+                value => ::core::write!(f, #unknown_fmt, value),
             }
-        };
-    };
-    Ok(tokens)
+        }
+    }
+
+    #[automatically_derived]
+    impl alloy_sol_types::EventTopic for #name {
+        #[inline]
+        fn topic_preimage_length(rust: &Self::RustType) -> usize {
+            <#uint8 as alloy_sol_types::EventTopic>::topic_preimage_length(&(*rust as u8))
+        }
+
+        #[inline]
+        fn encode_topic_preimage(rust: &Self::RustType, out: &mut alloy_sol_types::private::Vec<u8>) {
+            <#uint8 as alloy_sol_types::EventTopic>::encode_topic_preimage(&(*rust as u8), out);
+        }
+
+        #[inline]
+        fn encode_topic(rust: &Self::RustType) -> alloy_sol_types::abi::token::WordToken {
+            <#uint8 as alloy_sol_types::EventTopic>::encode_topic(&(*rust as u8))
+        }
+    }
+
+    #[automatically_derived]
+    impl alloy_sol_types::SolEnum for #name {
+        const COUNT: usize = #count;
+    }
+    }
 }
@@ -1,8 +1,10 @@
 //! [`ItemError`] expansion.
 
-use super::{expand_fields, expand_from_into_tuples, expand_tokenize, ExpCtxt};
+use super::{
+    anon_name, expand_fields, expand_fmt_params, expand_from_into_tuples, expand_tokenize, ExpCtxt,
+};
 use alloy_sol_macro_input::{mk_doc, ContainsSolAttrs};
-use ast::ItemError;
+use ast::{ItemError, Spanned, Type};
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::Result;
@@ -26,10 +28,64 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, error: &ItemError) -> Result<TokenStream>
     cx.derives(&mut attrs, params, true);
     let docs = sol_attrs.docs.or(cx.attrs.docs).unwrap_or(true);
     let abi = sol_attrs.abi.or(cx.attrs.abi).unwrap_or(false);
+    let display = sol_attrs.display.or(cx.attrs.display).unwrap_or(false);
 
     let tokenize_impl = expand_tokenize(params, cx, super::FieldKind::Deconstruct);
 
     let name = cx.overloaded_name(error.into());
+
+    // With `#[sol(nested_revert)]`, the last `bytes` parameter holds the revert data of an
+    // inner call.
+    let nested_revert_data = if sol_attrs.nested_revert.unwrap_or(false) {
+        let Some((i, p)) =
+            params.iter().enumerate().rev().find(|(_, p)| matches!(p.ty, Type::Bytes(_)))
+        else {
+            let msg =
+                "`#[sol(nested_revert)]` requires a `bytes` parameter to hold the revert data";
+            return Err(syn::Error::new(error.name.span(), msg));
+        };
+        let field = if params.len() == 1 && p.name.is_none() {
+            quote!(0)
+        } else {
+            let name = anon_name((i, p.name.as_ref()));
+            quote!(#name)
+        };
+        Some(quote! {
+            #[inline]
+            fn nested_revert_data(&self) -> ::core::option::Option<&[u8]> {
+                ::core::option::Option::Some(&self.#field[..])
+            }
+        })
+    } else {
+        None
+    };
+
+    let (fmt_error, display_impl) = display
+        .then(|| {
+            let fmt_impl = expand_fmt_params(
+                &error.name.as_string(),
+                params.iter().enumerate().map(|(i, p)| (i, cx.expand_type(&p.ty), p.name.as_ref())),
+                params.len(),
+                super::FieldKind::Deconstruct,
+            );
+            let fmt_error = quote! {
+                #[inline]
+                fn fmt_error_with(&self, f: &mut ::core::fmt::Formatter<'_>, redact: bool) -> ::core::fmt::Result {
+                    #fmt_impl
+                }
+            };
+            let display_impl = quote! {
+                #[automatically_derived]
+                impl ::core::fmt::Display for #name {
+                    #[inline]
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        <Self as alloy_sol_types::SolError>::fmt_error(self, f)
+                    }
+                }
+            };
+            (fmt_error, display_impl)
+        })
+        .unzip();
     let signature = cx.error_signature(error);
     let selector = crate::utils::selector(&signature);
 
@@ -120,8 +176,14 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, error: &ItemError) -> Result<TokenStream>
                 fn abi_decode_raw_validate(data: &[u8]) -> alloy_sol_types::Result<Self> {
                     <Self::Parameters<'_> as alloy_sol_types::SolType>::abi_decode_sequence_validate(data).map(Self::new)
                 }
+
+                #nested_revert_data
+
+                #fmt_error
             }
 
+            #display_impl
+
             #abi
         };
     };
@@ -1,6 +1,6 @@
 //! [`ItemEvent`] expansion.
 
-use super::{anon_name, expand_event_tokenize, expand_tuple_types, ExpCtxt};
+use super::{anon_name, expand_event_tokenize, expand_fmt_params, expand_tuple_types, ExpCtxt};
 use alloy_sol_macro_input::{mk_doc, ContainsSolAttrs};
 use ast::{EventParameter, ItemEvent, SolIdent, Spanned};
 use proc_macro2::TokenStream;
@@ -25,6 +25,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
     cx.derives(&mut attrs, &params, true);
     let docs = sol_attrs.docs.or(cx.attrs.docs).unwrap_or(true);
     let abi = sol_attrs.abi.or(cx.attrs.abi).unwrap_or(false);
+    let display = sol_attrs.display.or(cx.attrs.display).unwrap_or(false);
 
     cx.assert_resolved(&params)?;
     event.assert_valid()?;
@@ -117,6 +118,41 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
         super::FieldKind::Original,
     );
 
+    let (fmt_event, display_impl) = display
+        .then(|| {
+            let params = event.parameters.iter().enumerate().map(|(i, p)| {
+                let ty = if p.is_indexed() {
+                    expand_event_topic_type(p, cx)
+                } else {
+                    cx.expand_type(&p.ty)
+                };
+                (i, ty, p.name.as_ref())
+            });
+            let fmt_impl = expand_fmt_params(
+                &event.name.as_string(),
+                params,
+                event.parameters.len(),
+                super::FieldKind::Original,
+            );
+            let fmt_event = quote! {
+                #[inline]
+                fn fmt_event_with(&self, f: &mut ::core::fmt::Formatter<'_>, redact: bool) -> ::core::fmt::Result {
+                    #fmt_impl
+                }
+            };
+            let display_impl = quote! {
+                #[automatically_derived]
+                impl ::core::fmt::Display for #name {
+                    #[inline]
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        <Self as alloy_sol_types::SolEvent>::fmt_event(self, f)
+                    }
+                }
+            };
+            (fmt_event, display_impl)
+        })
+        .unzip();
+
     let encode_topics_impl = encode_first_topic
         .into_iter()
         .chain(encode_topics_impl)
@@ -220,8 +256,12 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
                     #(#encode_topics_impl)*
                     Ok(())
                 }
+
+                #fmt_event
             }
 
+            #display_impl
+
             #[automatically_derived]
             impl alloy_sol_types::private::IntoLogData for #name {
                 fn to_log_data(&self) -> alloy_sol_types::private::LogData {
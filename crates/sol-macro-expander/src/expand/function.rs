@@ -1,8 +1,8 @@
 //! [`ItemFunction`] expansion.
 
 use super::{
-    anon_name, expand_fields, expand_from_into_tuples, expand_tokenize, expand_tuple_types,
-    ExpCtxt, FieldKind,
+    anon_name, expand_fields, expand_fmt_params, expand_from_into_tuples, expand_tokenize,
+    expand_tuple_types, ExpCtxt, FieldKind,
 };
 use alloy_sol_macro_input::{mk_doc, ContainsSolAttrs};
 use ast::{FunctionKind, ItemFunction, Spanned};
@@ -53,6 +53,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
     }
     let docs = sol_attrs.docs.or(cx.attrs.docs).unwrap_or(true);
     let abi = sol_attrs.abi.or(cx.attrs.abi).unwrap_or(false);
+    let display = sol_attrs.display.or(cx.attrs.display).unwrap_or(false);
 
     let call_name = cx.call_name(function);
     let return_name = cx.return_name(function);
@@ -70,6 +71,36 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
     let selector = crate::utils::selector(&signature);
     let tokenize_impl = expand_tokenize(parameters, cx, FieldKind::Deconstruct);
 
+    let (fmt_call, display_impl) = display
+        .then(|| {
+            let fmt_impl = expand_fmt_params(
+                &function.name().as_string(),
+                parameters
+                    .iter()
+                    .enumerate()
+                    .map(|(i, p)| (i, cx.expand_type(&p.ty), p.name.as_ref())),
+                parameters.len(),
+                FieldKind::Deconstruct,
+            );
+            let fmt_call = quote! {
+                #[inline]
+                fn fmt_call_with(&self, f: &mut ::core::fmt::Formatter<'_>, redact: bool) -> ::core::fmt::Result {
+                    #fmt_impl
+                }
+            };
+            let display_impl = quote! {
+                #[automatically_derived]
+                impl ::core::fmt::Display for #call_name {
+                    #[inline]
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        <Self as alloy_sol_types::SolCall>::fmt_call(self, f)
+                    }
+                }
+            };
+            (fmt_call, display_impl)
+        })
+        .unzip();
+
     let call_doc = docs.then(|| {
         let selector = hex::encode_prefixed(selector.array.as_slice());
         mk_doc(format!(
@@ -232,8 +263,12 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
                 fn abi_decode_returns_validate(data: &[u8]) -> alloy_sol_types::Result<Self::Return> {
                     #decode_returns_validate
                 }
+
+                #fmt_call
             }
 
+            #display_impl
+
             #abi
         };
     };
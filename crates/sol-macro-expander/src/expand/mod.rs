@@ -9,7 +9,7 @@ use ast::{
 use indexmap::IndexMap;
 use proc_macro2::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
 use proc_macro_error2::{abort, emit_error};
-use quote::{format_ident, quote, TokenStreamExt};
+use quote::{format_ident, quote, ToTokens, TokenStreamExt};
 use std::{
     borrow::Borrow,
     collections::HashMap,
@@ -23,6 +23,8 @@ use syn::{ext::IdentExt, parse_quote, Attribute, Error, Result};
 mod macros;
 
 mod contract;
+mod derive;
+pub use derive::DeriveKind;
 mod r#enum;
 mod error;
 mod event;
@@ -55,6 +57,11 @@ pub fn expand_type(ty: &Type, crates: &ExternCrates) -> TokenStream {
     })
 }
 
+/// The `#[derive(SolType)]` and `#[derive(SolValue)]` implementation.
+pub fn expand_derive(input: &syn::DeriveInput, kind: DeriveKind) -> Result<TokenStream> {
+    utils::pme_compat_result(|| derive::expand(input, kind))
+}
+
 /// Mapping namespace -> ident -> T
 ///
 /// Keeps namespaced items. Namespace `None` represents global namespace (top-level items).
@@ -984,6 +991,66 @@ fn tokenize_<'a>(
     }
 }
 
+/// Expand the body of a `fmt` function which writes `name(label: value, ...)`.
+///
+/// Each field is given as its optional label and an expression which formats
+/// its value and evaluates to a `fmt::Result`. The expressions can use the
+/// `redact` flag of the surrounding `fmt_*_with` function.
+fn expand_fmt_fields(
+    name: &str,
+    fields: impl IntoIterator<Item = (Option<String>, TokenStream)>,
+) -> TokenStream {
+    let mut prefix = format!("{name}(");
+    let mut statements = Vec::new();
+    for (i, (label, fmt)) in fields.into_iter().enumerate() {
+        if i > 0 {
+            prefix.push_str(", ");
+        }
+        if let Some(label) = label {
+            prefix.push_str(&label);
+            prefix.push_str(": ");
+        }
+        statements.push(quote! {
+            f.write_str(#prefix)?;
+            #fmt?;
+        });
+        prefix.clear();
+    }
+    prefix.push(')');
+    let unused = statements.is_empty().then(|| quote!(let _ = redact;));
+    quote! {
+        #unused
+        #(#statements)*
+        f.write_str(#prefix)
+    }
+}
+
+/// Expand the body of a `fmt_call_with`, `fmt_error_with` or `fmt_event_with` function,
+/// formatting the given parameters with their Solidity types.
+fn expand_fmt_params<'a>(
+    name: &str,
+    iter: impl Iterator<Item = (usize, TokenStream, Option<&'a SolIdent>)>,
+    params_len: usize,
+    field_kind: FieldKind,
+) -> TokenStream {
+    let fields = iter.map(|(i, ty, name)| {
+        let field = if params_len == 1 && name.is_none() && field_kind.is_deconstruct() {
+            quote!(0)
+        } else {
+            name.cloned().unwrap_or_else(|| generate_name(i).into()).to_token_stream()
+        };
+        let fmt = quote! {
+            <#ty as alloy_sol_types::SolType>::fmt_token_with(
+                &<#ty as alloy_sol_types::SolType>::tokenize(&self.#field),
+                f,
+                redact,
+            )
+        };
+        (name.map(SolIdent::as_string), fmt)
+    });
+    expand_fmt_fields(name, fields)
+}
+
 #[allow(dead_code)]
 fn emit_json_error() {
     static EMITTED: AtomicBool = AtomicBool::new(false);
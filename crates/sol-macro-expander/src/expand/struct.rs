@@ -1,10 +1,10 @@
 //! [`ItemStruct`] expansion.
 
-use super::{expand_fields, expand_from_into_tuples, expand_tokenize, ExpCtxt};
+use super::{expand_fields, expand_fmt_fields, expand_from_into_tuples, ExpCtxt};
 use alloy_sol_macro_input::{mk_doc, ContainsSolAttrs};
 use ast::{Item, ItemStruct, Spanned, Type};
-use proc_macro2::TokenStream;
-use quote::quote;
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
 use std::num::NonZeroU16;
 use syn::Result;
 
@@ -37,27 +37,20 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
 
     let eip712_encode_type_fns = expand_encode_type_fns(cx, fields, name);
 
-    let tokenize_impl = expand_tokenize(fields, cx, super::FieldKind::Original);
-
-    let encode_data_impl = match fields.len() {
-        0 => unreachable!("struct with zero fields"),
-        1 => {
-            let name = *field_names.first().unwrap();
-            let ty = field_types.first().unwrap();
-            quote!(<#ty as alloy_sol_types::SolType>::eip712_data_word(&self.#name).0.to_vec())
-        }
-        _ => quote! {
-            [#(
-                <#field_types as alloy_sol_types::SolType>::eip712_data_word(&self.#field_names).0,
-            )*].concat()
-        },
-    };
-
     let alloy_sol_types = &cx.crates.sol_types;
 
     let attrs = attrs.iter();
     let convert = expand_from_into_tuples(&name.0, fields, cx, super::FieldKind::Original);
     let name_s = name.as_string();
+    let field_labels = field_names.iter().map(|name| name.as_string()).collect::<Vec<_>>();
+    let impls = expand_impls(
+        &name.0,
+        &name_s,
+        &field_names,
+        &field_labels,
+        &field_types,
+        eip712_encode_type_fns,
+    );
     let fields = expand_fields(fields, cx);
 
     let doc = docs.then(|| mk_doc(format!("```solidity\n{s}\n```")));
@@ -81,113 +74,196 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
                 type SolType = Self;
             }
 
-            #[automatically_derived]
-            impl alloy_sol_types::private::SolTypeValue<Self> for #name {
-                #[inline]
-                fn stv_to_tokens(&self) -> <Self as alloy_sol_types::SolType>::Token<'_> {
-                    #tokenize_impl
-                }
-
-                #[inline]
-                fn stv_abi_encoded_size(&self) -> usize {
-                    if let Some(size) = <Self as alloy_sol_types::SolType>::ENCODED_SIZE {
-                        return size;
-                    }
+            #impls
+        };
+    };
+    Ok(tokens)
+}
 
-                    // TODO: Avoid cloning
-                    let tuple = <UnderlyingRustTuple<'_> as ::core::convert::From<Self>>::from(self.clone());
-                    <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::abi_encoded_size(&tuple)
-                }
+/// Expands the trait implementations shared by [`ItemStruct`]s and
+/// `#[derive(SolType)]` structs: `SolTypeValue`, `SolType`, `SolStruct` and
+/// `EventTopic`.
+///
+/// Expects `UnderlyingSolTuple` and `UnderlyingRustTuple` to be in scope, as
+/// well as `alloy_sol_types`.
+pub(super) fn expand_impls<N: ToTokens>(
+    name: &Ident,
+    name_s: &str,
+    field_names: &[N],
+    field_labels: &[String],
+    field_types: &[TokenStream],
+    eip712_encode_type_fns: TokenStream,
+) -> TokenStream {
+    let fmt_token_impl = expand_fmt_fields(
+        name_s,
+        field_labels.iter().zip(field_types).enumerate().map(|(i, (label, ty))| {
+            let i = syn::Index::from(i);
+            let fmt =
+                quote!(<#ty as alloy_sol_types::SolType>::fmt_token_with(&token.#i, f, redact));
+            (Some(label.clone()), fmt)
+        }),
+    );
+
+    let tokenize_impl = quote! {
+        (#(
+            <#field_types as alloy_sol_types::SolType>::tokenize(&self.#field_names),
+        )*)
+    };
 
-                #[inline]
-                fn stv_eip712_data_word(&self) -> alloy_sol_types::Word {
-                    <Self as alloy_sol_types::SolStruct>::eip712_hash_struct(self)
-                }
+    let encode_data_impl = match field_names {
+        [] => unreachable!("struct with zero fields"),
+        [name] => {
+            let ty = &field_types[0];
+            quote!(<#ty as alloy_sol_types::SolType>::eip712_data_word(&self.#name).0.to_vec())
+        }
+        _ => quote! {
+            [#(
+                <#field_types as alloy_sol_types::SolType>::eip712_data_word(&self.#field_names).0,
+            )*].concat()
+        },
+    };
 
-                #[inline]
-                fn stv_abi_encode_packed_to(&self, out: &mut alloy_sol_types::private::Vec<u8>) {
-                    // TODO: Avoid cloning
-                    let tuple = <UnderlyingRustTuple<'_> as ::core::convert::From<Self>>::from(self.clone());
-                    <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::abi_encode_packed_to(&tuple, out)
-                }
+    let encode_data_with_policy_impl = match field_names {
+        [] => unreachable!("struct with zero fields"),
+        [name] => {
+            let ty = &field_types[0];
+            quote!(<#ty as alloy_sol_types::SolType>::eip712_data_word_with_policy(&self.#name, policy).0.to_vec())
+        }
+        _ => quote! {
+            [#(
+                <#field_types as alloy_sol_types::SolType>::eip712_data_word_with_policy(&self.#field_names, policy).0,
+            )*].concat()
+        },
+    };
 
-                #[inline]
-                fn stv_abi_packed_encoded_size(&self) -> usize {
-                    if let Some(size) = <Self as alloy_sol_types::SolType>::PACKED_ENCODED_SIZE {
-                        return size;
-                    }
+    quote! {
+    #[automatically_derived]
+    impl alloy_sol_types::private::SolTypeValue<Self> for #name {
+        #[inline]
+        fn stv_to_tokens(&self) -> <Self as alloy_sol_types::SolType>::Token<'_> {
+            #tokenize_impl
+        }
 
-                    // TODO: Avoid cloning
-                    let tuple = <UnderlyingRustTuple<'_> as ::core::convert::From<Self>>::from(self.clone());
-                    <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::abi_packed_encoded_size(&tuple)
-                }
+        #[inline]
+        fn stv_abi_encoded_size(&self) -> usize {
+            if let Some(size) = <Self as alloy_sol_types::SolType>::ENCODED_SIZE {
+                return size;
             }
 
-            #[automatically_derived]
-            impl alloy_sol_types::SolType for #name {
-                type RustType = Self;
-                type Token<'a> = <UnderlyingSolTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-
-                const SOL_NAME: &'static str = <Self as alloy_sol_types::SolStruct>::NAME;
-                const ENCODED_SIZE: Option<usize> =
-                    <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::ENCODED_SIZE;
-                const PACKED_ENCODED_SIZE: Option<usize> =
-                    <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::PACKED_ENCODED_SIZE;
-
-                #[inline]
-                fn valid_token(token: &Self::Token<'_>) -> bool {
-                    <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::valid_token(token)
-                }
+            // TODO: Avoid cloning
+            let tuple = <UnderlyingRustTuple<'_> as ::core::convert::From<Self>>::from(self.clone());
+            <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::abi_encoded_size(&tuple)
+        }
 
-                #[inline]
-                fn detokenize(token: Self::Token<'_>) -> Self::RustType {
-                    let tuple = <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::detokenize(token);
-                    <Self as ::core::convert::From<UnderlyingRustTuple<'_>>>::from(tuple)
-                }
-            }
+        #[inline]
+        fn stv_eip712_data_word(&self) -> alloy_sol_types::Word {
+            <Self as alloy_sol_types::SolStruct>::eip712_hash_struct(self)
+        }
 
-            #[automatically_derived]
-            impl alloy_sol_types::SolStruct for #name {
-                const NAME: &'static str = #name_s;
+        #[inline]
+        fn stv_eip712_data_word_with_policy(&self, policy: alloy_sol_types::Eip712ShieldedPolicy) -> alloy_sol_types::Word {
+            <Self as alloy_sol_types::SolStruct>::eip712_hash_struct_with_policy(self, policy)
+        }
 
-                #eip712_encode_type_fns
+        #[inline]
+        fn stv_abi_encode_packed_to(&self, out: &mut alloy_sol_types::private::Vec<u8>) {
+            // TODO: Avoid cloning
+            let tuple = <UnderlyingRustTuple<'_> as ::core::convert::From<Self>>::from(self.clone());
+            <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::abi_encode_packed_to(&tuple, out)
+        }
 
-                #[inline]
-                fn eip712_encode_data(&self) -> alloy_sol_types::private::Vec<u8> {
-                    #encode_data_impl
-                }
+        #[inline]
+        fn stv_abi_packed_encoded_size(&self) -> usize {
+            if let Some(size) = <Self as alloy_sol_types::SolType>::PACKED_ENCODED_SIZE {
+                return size;
             }
 
-            #[automatically_derived]
-            impl alloy_sol_types::EventTopic for #name {
-                #[inline]
-                fn topic_preimage_length(rust: &Self::RustType) -> usize {
-                    0usize
-                    #(
-                        + <#field_types as alloy_sol_types::EventTopic>::topic_preimage_length(&rust.#field_names)
-                    )*
-                }
+            // TODO: Avoid cloning
+            let tuple = <UnderlyingRustTuple<'_> as ::core::convert::From<Self>>::from(self.clone());
+            <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::abi_packed_encoded_size(&tuple)
+        }
+    }
 
-                #[inline]
-                fn encode_topic_preimage(rust: &Self::RustType, out: &mut alloy_sol_types::private::Vec<u8>) {
-                    out.reserve(<Self as alloy_sol_types::EventTopic>::topic_preimage_length(rust));
-                    #(
-                        <#field_types as alloy_sol_types::EventTopic>::encode_topic_preimage(&rust.#field_names, out);
-                    )*
-                }
+    #[automatically_derived]
+    impl alloy_sol_types::SolType for #name {
+        type RustType = Self;
+        type Token<'a> = <UnderlyingSolTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
 
-                #[inline]
-                fn encode_topic(rust: &Self::RustType) -> alloy_sol_types::abi::token::WordToken {
-                    let mut out = alloy_sol_types::private::Vec::new();
-                    <Self as alloy_sol_types::EventTopic>::encode_topic_preimage(rust, &mut out);
-                    alloy_sol_types::abi::token::WordToken(
-                        alloy_sol_types::private::keccak256(out)
-                    )
-                }
-            }
-        };
-    };
-    Ok(tokens)
+        const SOL_NAME: &'static str = <Self as alloy_sol_types::SolStruct>::NAME;
+        const ENCODED_SIZE: Option<usize> =
+            <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::ENCODED_SIZE;
+        const PACKED_ENCODED_SIZE: Option<usize> =
+            <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::PACKED_ENCODED_SIZE;
+
+        #[inline]
+        fn valid_token(token: &Self::Token<'_>) -> bool {
+            <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::valid_token(token)
+        }
+
+        #[inline]
+        fn detokenize(token: Self::Token<'_>) -> Self::RustType {
+            let tuple = <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::detokenize(token);
+            <Self as ::core::convert::From<UnderlyingRustTuple<'_>>>::from(tuple)
+        }
+
+        #[inline]
+        fn eip712_referenced_types() -> alloy_sol_types::private::Vec<alloy_sol_types::private::Cow<'static, str>> {
+            let mut types = <Self as alloy_sol_types::SolStruct>::eip712_components();
+            types.push(<Self as alloy_sol_types::SolStruct>::eip712_root_type());
+            types
+        }
+
+        #[inline]
+        fn fmt_token_with(token: &Self::Token<'_>, f: &mut ::core::fmt::Formatter<'_>, redact: bool) -> ::core::fmt::Result {
+            #fmt_token_impl
+        }
+    }
+
+    #[automatically_derived]
+    impl alloy_sol_types::SolStruct for #name {
+        const NAME: &'static str = #name_s;
+
+        #eip712_encode_type_fns
+
+        #[inline]
+        fn eip712_encode_data(&self) -> alloy_sol_types::private::Vec<u8> {
+            #encode_data_impl
+        }
+
+        #[inline]
+        fn eip712_encode_data_with_policy(&self, policy: alloy_sol_types::Eip712ShieldedPolicy) -> alloy_sol_types::private::Vec<u8> {
+            #encode_data_with_policy_impl
+        }
+    }
+
+    #[automatically_derived]
+    impl alloy_sol_types::EventTopic for #name {
+        #[inline]
+        fn topic_preimage_length(rust: &Self::RustType) -> usize {
+            0usize
+            #(
+                + <#field_types as alloy_sol_types::EventTopic>::topic_preimage_length(&rust.#field_names)
+            )*
+        }
+
+        #[inline]
+        fn encode_topic_preimage(rust: &Self::RustType, out: &mut alloy_sol_types::private::Vec<u8>) {
+            out.reserve(<Self as alloy_sol_types::EventTopic>::topic_preimage_length(rust));
+            #(
+                <#field_types as alloy_sol_types::EventTopic>::encode_topic_preimage(&rust.#field_names, out);
+            )*
+        }
+
+        #[inline]
+        fn encode_topic(rust: &Self::RustType) -> alloy_sol_types::abi::token::WordToken {
+            let mut out = alloy_sol_types::private::Vec::new();
+            <Self as alloy_sol_types::EventTopic>::encode_topic_preimage(rust, &mut out);
+            alloy_sol_types::abi::token::WordToken(
+                alloy_sol_types::private::keccak256(out)
+            )
+        }
+    }
+    }
 }
 
 fn expand_encode_type_fns(
@@ -61,6 +61,17 @@ impl ExpCtxt<'_> {
 
                 quote_spanned! {span=> #alloy_sol_types::sol_data::#name<#size> }
             }
+            Type::Fixed(span, size) | Type::Ufixed(span, size) => {
+                let name = match ty {
+                    Type::Fixed(..) => "Fixed",
+                    Type::Ufixed(..) => "Ufixed",
+                    _ => unreachable!(),
+                };
+                let name = Ident::new(name, span);
+
+                let (m, n) = fixed_size(size);
+                quote_spanned! {span=> #alloy_sol_types::sol_data::#name<#m, #n> }
+            }
 
             #[cfg(feature = "seismic")]
             Type::Sint(span, size) | Type::Suint(span, size) => {
@@ -158,6 +169,18 @@ impl ExpCtxt<'_> {
                 let name = Ident::new(&format!("{prefix}{size}"), span);
                 quote_spanned! {span=> #alloy_sol_types::private::primitives::aliases::#name }
             }
+            Type::Fixed(span, size) | Type::Ufixed(span, size) => {
+                let name = match ty {
+                    Type::Fixed(..) => "Fixed",
+                    Type::Ufixed(..) => "UFixed",
+                    _ => unreachable!(),
+                };
+                let name = Ident::new(name, span);
+
+                // Always backed by a 256-bit integer, see `sol_data::Fixed`
+                let (_, n) = fixed_size(size);
+                quote_spanned! {span=> #alloy_sol_types::private::primitives::#name<256, 4, #n> }
+            }
             #[cfg(feature = "seismic")]
             Type::Sint(span, size) | Type::Suint(span, size) => {
                 let size = size.map_or(256, NonZeroU16::get);
@@ -232,6 +255,8 @@ impl ExpCtxt<'_> {
             | Type::Bool(_)
             | Type::Int(..)
             | Type::Uint(..)
+            | Type::Fixed(..)
+            | Type::Ufixed(..)
             | Type::FixedBytes(..)
             | Type::Function(_) => 32,
 
@@ -552,6 +577,8 @@ impl fmt::Display for TypePrinter<'_> {
         match self.ty {
             Type::Int(_, None) => f.write_str("int256"),
             Type::Uint(_, None) => f.write_str("uint256"),
+            Type::Fixed(_, None) => f.write_str("fixed128x18"),
+            Type::Ufixed(_, None) => f.write_str("ufixed128x18"),
 
             Type::Array(array) => {
                 Self::new(self.cx, &array.ty).fmt(f)?;
@@ -578,3 +605,11 @@ impl fmt::Display for TypePrinter<'_> {
         }
     }
 }
+
+/// Returns the `M` and `N` of a `fixedMxN` type as literals, defaulting to
+/// `128x18`.
+fn fixed_size(size: Option<(NonZeroU16, u8)>) -> (Literal, Literal) {
+    let (m, n) = size.map_or((128, 18), |(m, n)| (m.get(), n));
+    assert!(m <= 256 && m % 8 == 0 && n <= 80);
+    (Literal::u16_unsuffixed(m), Literal::u8_unsuffixed(n))
+}
@@ -131,6 +131,11 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, udt: &ItemUdt) -> Result<TokenStream> {
                 fn detokenize(token: Self::Token<'_>) -> Self::RustType {
                     <#underlying_sol as alloy_sol_types::SolType>::detokenize(token)
                 }
+
+                #[inline]
+                fn fmt_token_with(token: &Self::Token<'_>, f: &mut ::core::fmt::Formatter<'_>, redact: bool) -> ::core::fmt::Result {
+                    <#underlying_sol as alloy_sol_types::SolType>::fmt_token_with(token, f, redact)
+                }
             }
 
             #[automatically_derived]
@@ -90,6 +90,14 @@ pub struct SolAttrs {
     pub extra_methods: Option<bool>,
     /// `#[sol(docs)]`
     pub docs: Option<bool>,
+    /// `#[sol(dispatch)]`
+    pub dispatch: Option<bool>,
+    /// `#[sol(display)]`
+    pub display: Option<bool>,
+    /// `#[sol(nested_revert)]`
+    pub nested_revert: Option<bool>,
+    /// `#[sol(unknown_variant)]`
+    pub unknown_variant: Option<bool>,
 
     /// `#[sol(alloy_sol_types = alloy_core::sol_types)]`
     pub alloy_sol_types: Option<Path>,
@@ -194,6 +202,10 @@ impl SolAttrs {
                     extra_derives => list(&meta, Path::parse)?,
                     extra_methods => bool()?,
                     docs => bool()?,
+                    dispatch => bool()?,
+                    display => bool()?,
+                    nested_revert => bool()?,
+                    unknown_variant => bool()?,
 
                     alloy_sol_types => path()?,
                     alloy_contract => path()?,
@@ -435,6 +447,22 @@ mod tests {
             #[sol(rpc = true)] => Ok(sol_attrs! { rpc: true }),
             #[sol(rpc = false)] => Ok(sol_attrs! { rpc: false }),
 
+            #[sol(dispatch)] => Ok(sol_attrs! { dispatch: true }),
+            #[sol(dispatch = true)] => Ok(sol_attrs! { dispatch: true }),
+            #[sol(dispatch = false)] => Ok(sol_attrs! { dispatch: false }),
+
+            #[sol(display)] => Ok(sol_attrs! { display: true }),
+            #[sol(display = true)] => Ok(sol_attrs! { display: true }),
+            #[sol(display = false)] => Ok(sol_attrs! { display: false }),
+
+            #[sol(nested_revert)] => Ok(sol_attrs! { nested_revert: true }),
+            #[sol(nested_revert = true)] => Ok(sol_attrs! { nested_revert: true }),
+            #[sol(nested_revert = false)] => Ok(sol_attrs! { nested_revert: false }),
+
+            #[sol(unknown_variant)] => Ok(sol_attrs! { unknown_variant: true }),
+            #[sol(unknown_variant = true)] => Ok(sol_attrs! { unknown_variant: true }),
+            #[sol(unknown_variant = false)] => Ok(sol_attrs! { unknown_variant: false }),
+
             #[sol(alloy_sol_types)] => Err("expected `=`"),
             #[sol(alloy_sol_types = alloy_core::sol_types)] => Ok(sol_attrs! { alloy_sol_types: parse_quote!(alloy_core::sol_types) }),
             #[sol(alloy_sol_types = ::alloy_core::sol_types)] => Ok(sol_attrs! { alloy_sol_types: parse_quote!(::alloy_core::sol_types) }),
@@ -0,0 +1,107 @@
+use serde_json::{Map, Value};
+
+/// Returns the Solidity struct definitions for EIP-712 types JSON, or `None`
+/// if the value is not EIP-712 types JSON.
+///
+/// The value may either be a full typed data object, with `types` and
+/// `primaryType` keys, or only its `types` object. The `EIP712Domain` type is
+/// skipped, as it is not needed to hash or sign messages.
+///
+/// Keep the output in sync with `alloy_dyn_abi::Resolver::to_sol`.
+pub(crate) fn eip712_to_sol(value: &Value) -> Option<String> {
+    let object = value.as_object()?;
+    let types = match (object.get("types"), object.get("primaryType")) {
+        (Some(types), Some(Value::String(_))) => types.as_object()?,
+        _ => object,
+    };
+    let mut types = types
+        .iter()
+        .filter(|(name, _)| *name != "EIP712Domain")
+        .map(|(name, props)| Some((name, props.as_array().filter(|props| is_type_def(props))?)))
+        .collect::<Option<Vec<_>>>()?;
+    if types.is_empty() {
+        return None;
+    }
+    types.sort_unstable_by_key(|(name, _)| *name);
+
+    let mut sol = String::new();
+    for (name, props) in types {
+        if !sol.is_empty() {
+            sol.push('\n');
+        }
+        sol.push_str("struct ");
+        sol.push_str(name);
+        sol.push_str(" {\n");
+        for prop in props {
+            sol.push_str("    ");
+            sol.push_str(prop["type"].as_str().unwrap());
+            sol.push(' ');
+            sol.push_str(prop["name"].as_str().unwrap());
+            sol.push_str(";\n");
+        }
+        sol.push_str("}\n");
+    }
+    Some(sol)
+}
+
+/// Returns `true` if the values are a non-empty list of `{ "name", "type" }`
+/// property definitions.
+fn is_type_def(props: &[Value]) -> bool {
+    let is_prop = |prop: &Map<String, Value>| {
+        prop.len() == 2
+            && prop.get("name").is_some_and(Value::is_string)
+            && prop.get("type").is_some_and(Value::is_string)
+    };
+    !props.is_empty() && props.iter().all(|prop| prop.as_object().is_some_and(is_prop))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn typed_data() {
+        let types = json!({
+            "EIP712Domain": [{ "name": "name", "type": "string" }],
+            "Person": [
+                { "name": "name", "type": "string" },
+                { "name": "wallets", "type": "address[]" }
+            ],
+            "Mail": [
+                { "name": "from", "type": "Person" },
+                { "name": "to", "type": "Person[]" },
+                { "name": "contents", "type": "string" }
+            ]
+        });
+        let expected = "\
+struct Mail {
+    Person from;
+    Person[] to;
+    string contents;
+}
+
+struct Person {
+    string name;
+    address[] wallets;
+}
+";
+        assert_eq!(eip712_to_sol(&types).as_deref(), Some(expected));
+
+        let typed_data = json!({ "types": types, "primaryType": "Mail", "message": {} });
+        assert_eq!(eip712_to_sol(&typed_data).as_deref(), Some(expected));
+    }
+
+    #[test]
+    fn not_typed_data() {
+        assert_eq!(eip712_to_sol(&json!([])), None);
+        assert_eq!(eip712_to_sol(&json!({})), None);
+        assert_eq!(eip712_to_sol(&json!({ "abi": [] })), None);
+        assert_eq!(eip712_to_sol(&json!({ "EIP712Domain": [] })), None);
+        assert_eq!(
+            eip712_to_sol(&json!({ "abi": [{ "type": "function", "name": "f", "inputs": [] }] })),
+            None
+        );
+        assert_eq!(eip712_to_sol(&json!({ "types": {}, "primaryType": "Mail" })), None);
+    }
+}
@@ -144,6 +144,30 @@ impl SolInput {
         {
             #[cfg(feature = "json")]
             {
+                if let Some(sol) =
+                    serde_json::from_str(s).ok().as_ref().and_then(crate::eip712::eip712_to_sol)
+                {
+                    let sol = match &name {
+                        Some(name) => format!("library {name} {{\n{sol}}}"),
+                        None => sol,
+                    };
+                    let mut file = syn::parse_str::<ast::File>(&sol).map_err(|e| {
+                        let msg = format!("invalid EIP-712 types JSON: {e}\n{sol}");
+                        Error::new(span, msg)
+                    })?;
+
+                    // Outer attributes apply to the generated items, like for JSON ABIs.
+                    let (attrs, item_attrs) = attrs.into_iter().partition::<Vec<_>, _>(|attr| {
+                        matches!(attr.style, syn::AttrStyle::Inner(_))
+                    });
+                    for item in &mut file.items {
+                        if let Some(attrs) = item.attrs_mut() {
+                            attrs.extend(item_attrs.iter().cloned());
+                        }
+                    }
+                    return Ok(Self { attrs, path, kind: SolInputKind::Sol(file) });
+                }
+
                 let json = alloy_json_abi::ContractObject::from_json_with(
                     s,
                     _config.ignore_unlinked_bytecode,
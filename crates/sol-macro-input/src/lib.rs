@@ -20,6 +20,9 @@ pub use input::{SolInput, SolInputKind};
 mod expander;
 pub use expander::SolInputExpander;
 
+#[cfg(feature = "json")]
+mod eip712;
+
 #[cfg(feature = "json")]
 mod json;
 #[cfg(feature = "json")]
@@ -17,7 +17,7 @@
 #[macro_use]
 extern crate proc_macro_error2;
 
-use alloy_sol_macro_expander::expand;
+use alloy_sol_macro_expander::expand::{self, DeriveKind};
 use alloy_sol_macro_input::{SolAttrs, SolInput, SolInputExpander, SolInputKind};
 use proc_macro::TokenStream;
 use quote::quote;
@@ -46,7 +46,7 @@ use syn::parse_macro_input;
 /// There are two main ways to use this macro:
 /// - you can [write Solidity code](#solidity), or provide a path to a Solidity file,
 /// - if you enable the `json` feature, you can provide [an ABI, or a path to one, in JSON
-///   format](#json-abi).
+///   format](#json-abi), or EIP-712 types in JSON format.
 ///
 /// Note:
 /// - relative file system paths are rooted at the `CARGO_MANIFEST_DIR` environment variable by
@@ -139,6 +139,36 @@ use syn::parse_macro_input;
 ///     - `pub fn errors() -> BTreeMap<String, Vec<Error>>`
 ///   - items: generates implementations of the `SolAbiExt` trait, alongside the existing
 ///     [`alloy-sol-types`] traits
+/// - `dispatch [ = <bool = false>]` (contracts and alike only): generates a mock dispatcher, to
+///   call Rust implementations of the contract's functions with ABI-encoded calldata, without an
+///   EVM. Generates the following items inside of the `{contract_name}` module:
+///   - `trait {contract_name}Handler`, with a `fn <functionName>(&mut self, <parameters>...)`
+///     method for each function in the contract, which returns
+///     `Result<<functionCall as SolCall>::Return, ContractError<{contract_name}Errors>>`, or
+///     `Result<..., GenericContractError>` if the contract has no custom errors
+///   - `pub fn dispatch<H: {contract_name}Handler>(handler: &mut H, calldata: &[u8]) ->
+///     Result<Bytes, Bytes>`, which decodes the calldata with `{contract_name}Calls`, calls the
+///     handler, and returns the ABI-encoded return values, or the ABI-encoded revert data. Calldata
+///     that cannot be decoded reverts with empty data, like the Solidity dispatcher
+/// - `display [ = <bool = false>]`: implements [`Display`](core::fmt::Display) for the generated
+///   function calls, events and errors, and for the contract's `Calls`, `Events` and `Errors`
+///   enums, rendering them like `transfer(to: 0xAbC..., amount: 1000000)`. Addresses are
+///   checksummed, bytes are written in hex and structs by name. Shielded values are always
+///   redacted as `<shielded>`; they can only be written with the `fmt_*_unredacted` methods, such
+///   as `SolCall::fmt_call_unredacted`. See `SolType::fmt_token` for more details
+/// - `nested_revert [ = <bool = false>]` (errors only): marks the error's last `bytes` parameter as
+///   the revert data of an inner call, which is returned by `SolError::nested_revert_data` and
+///   decoded recursively by `SolInterface::abi_decode_chain`. The error must have a `bytes`
+///   parameter
+/// - `unknown_variant [ = <bool = false>]` (contracts and alike only): adds an `Unknown` variant to
+///   the contract's `Calls`, `Events` and `Errors` enums, so that decoding data with a selector or
+///   topic not known to the contract, for example after an upgrade, never fails:
+///   - `Unknown { selector: [u8; 4], data: Bytes }` for calls and errors, where `data` does not
+///     include the selector. It is re-encoded as-is
+///   - `Unknown(LogData)` for events, with the log's raw topics and data
+///
+///   Data with a known selector or topic which fails to decode is still an error. The enums'
+///   `SELECTORS` and `valid_selector` only contain the known selectors
 /// - `alloy_sol_types = <path = ::alloy_sol_types>` (inner attribute only): specifies the path to
 ///   the required dependency [`alloy-sol-types`].
 /// - `alloy_contract = <path = ::alloy_contract>` (inner attribute only): specifies the path to the
@@ -278,6 +308,17 @@ use syn::parse_macro_input;
 /// format omits some information which is useful to this macro, such as enum
 /// variants and visibility modifiers on functions.
 ///
+/// EIP-712 types JSON, either a full `eth_signTypedData` typed data object or
+/// only its `types` object, is also supported, and generates a struct for each
+/// type except `EIP712Domain`. The EIP-712 `encodeType` of the generated
+/// structs is the same as the one of the JSON types. If a name is given, the
+/// structs are generated in a library with that name:
+///
+/// ```ignore
+/// sol!(MailTypes, "path/to/mail.eip712.json");
+/// let hash = my_mail.eip712_signing_hash(&domain);
+/// ```
+///
 /// [abigen]: https://docs.rs/ethers/latest/ethers/contract/macro.abigen.html
 /// [`abigen`]: https://docs.rs/ethers/latest/ethers/contract/macro.abigen.html
 /// ```ignore
@@ -291,6 +332,80 @@ pub fn sol(input: TokenStream) -> TokenStream {
     SolMacroExpander.expand(&input).unwrap_or_else(syn::Error::into_compile_error).into()
 }
 
+/// Derives [`SolType`] and [`SolStruct`] for a Rust struct with named fields,
+/// or [`SolType`] and [`SolEnum`] for a Rust enum with only unit variants.
+///
+/// The generated implementations are the same as the ones generated by
+/// [`sol!`] for an equivalent Solidity struct or enum, including the ABI and
+/// [EIP-712] encodings. Derive [`SolValue`] as well to use the type as a
+/// field of other structs, or with the `SolValue` encoding methods.
+///
+/// Each struct field is mapped to the Solidity type of its Rust type's
+/// [`SolValue`] implementation, e.g. `U256` to `uint256`, `Vec<Address>` to
+/// `address[]`, and another derived struct to itself.
+/// The struct must implement [`Clone`], and enums must implement [`Copy`].
+///
+/// [`SolType`]: https://docs.rs/alloy-sol-types/latest/alloy_sol_types/trait.SolType.html
+/// [`SolStruct`]: https://docs.rs/alloy-sol-types/latest/alloy_sol_types/trait.SolStruct.html
+/// [`SolEnum`]: https://docs.rs/alloy-sol-types/latest/alloy_sol_types/trait.SolEnum.html
+/// [`SolValue`]: https://docs.rs/alloy-sol-types/latest/alloy_sol_types/trait.SolValue.html
+/// [EIP-712]: https://eips.ethereum.org/EIPS/eip-712
+///
+/// # Attributes
+///
+/// On the type:
+/// - `#[sol(rename = "Name")]`: the Solidity name of the type;
+/// - `#[sol(rename_all = "camelCase")]`: the casing of the Solidity field names;
+/// - `#[sol(alloy_sol_types = path)]`: the path to the `alloy_sol_types` crate.
+///
+/// On struct fields:
+/// - `#[sol(rename = "name")]`: the Solidity name of the field;
+/// - `#[sol(ty = "uint96")]`: the Solidity type of the field, instead of the one of its Rust type.
+///   The Rust type must be compatible with it, e.g. `U96` for `uint96`.
+///
+/// On enum variants:
+/// - `#[sol(fallback)]`: the variant that out-of-range values are decoded as when decoding without
+///   validation. Exactly one variant must be marked, unless the enum has 256 variants.
+///
+/// # Examples
+///
+/// ```ignore
+/// use alloy_primitives::{aliases::U96, Address};
+/// use alloy_sol_types::{SolType, SolValue};
+///
+/// #[derive(Clone, SolType, SolValue)]
+/// #[sol(rename_all = "camelCase")]
+/// struct Order {
+///     maker: Address,
+///     #[sol(ty = "uint96")]
+///     amount: U96,
+///     fee_recipients: Vec<Address>,
+/// }
+/// ```
+#[proc_macro_derive(SolType, attributes(sol))]
+#[proc_macro_error]
+pub fn derive_sol_type(input: TokenStream) -> TokenStream {
+    derive(input, DeriveKind::SolType)
+}
+
+/// Derives [`SolValue`] with `SolType = Self` for a type that derives [`SolType`].
+///
+/// See [`SolType`](derive@SolType) for more information.
+///
+/// [`SolType`]: https://docs.rs/alloy-sol-types/latest/alloy_sol_types/trait.SolType.html
+/// [`SolValue`]: https://docs.rs/alloy-sol-types/latest/alloy_sol_types/trait.SolValue.html
+#[proc_macro_derive(SolValue, attributes(sol))]
+#[proc_macro_error]
+pub fn derive_sol_value(input: TokenStream) -> TokenStream {
+    derive(input, DeriveKind::SolValue)
+}
+
+fn derive(input: TokenStream, kind: DeriveKind) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+
+    expand::expand_derive(&input, kind).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
 struct SolMacroExpander;
 
 impl SolInputExpander for SolMacroExpander {
@@ -85,10 +85,13 @@ impl<'a> RootType<'a> {
                     return Self("uint8");
                 }
 
-                // Normalize the `u?int` aliases to the canonical `u?int256`
+                // Normalize the `u?int` aliases to the canonical `u?int256`, and
+                // the `u?fixed` aliases to the canonical `u?fixed128x18`
                 match ident {
                     "uint" => Self("uint256"),
                     "int" => Self("int256"),
+                    "ufixed" => Self("ufixed128x18"),
+                    "fixed" => Self("fixed128x18"),
                     _ => Self(ident),
                 }
             })
@@ -106,7 +109,8 @@ impl<'a> RootType<'a> {
     #[inline]
     pub fn try_basic_solidity(self) -> Result<()> {
         match self.0 {
-            "address" | "bool" | "string" | "bytes" | "uint" | "int" | "function" => Ok(()),
+            "address" | "bool" | "string" | "bytes" | "uint" | "int" | "function" | "ufixed"
+            | "fixed" => Ok(()),
             #[cfg(feature = "seismic")]
             "saddress" | "sint" | "suint" | "sbool" | "sbytes" => Ok(()),
             name => {
@@ -119,6 +123,18 @@ impl<'a> RootType<'a> {
                     return Err(Error::invalid_size(name));
                 }
 
+                if let Some(sz) = name.strip_prefix("fixed").or_else(|| name.strip_prefix("ufixed"))
+                {
+                    if let Some((m, n)) = sz.split_once('x') {
+                        if let (Ok(m), Ok(n)) = (m.parse::<usize>(), n.parse::<usize>()) {
+                            if m != 0 && m <= 256 && m % 8 == 0 && n <= 80 {
+                                return Ok(());
+                            }
+                        }
+                    }
+                    return Err(Error::invalid_size(name));
+                }
+
                 // fast path both integer types
                 #[cfg(not(feature = "seismic"))]
                 let s = name.strip_prefix('u').unwrap_or(name);
@@ -156,5 +172,17 @@ mod tests {
 
         assert_eq!(RootType::parse("int"), Ok(RootType("int256")));
         assert_eq!(RootType::parse("uint"), Ok(RootType("uint256")));
+        assert_eq!(RootType::parse("fixed"), Ok(RootType("fixed128x18")));
+        assert_eq!(RootType::parse("ufixed"), Ok(RootType("ufixed128x18")));
+    }
+
+    #[test]
+    fn basic_fixed() {
+        for ty in ["fixed128x18", "ufixed8x0", "fixed256x80", "ufixed", "fixed"] {
+            assert_eq!(RootType::parse(ty).unwrap().try_basic_solidity(), Ok(()), "{ty}");
+        }
+        for ty in ["fixed0x18", "ufixed7x1", "fixed264x0", "fixed128x81", "fixed128", "ufixedx"] {
+            assert!(RootType::parse(ty).unwrap().try_basic_solidity().is_err(), "{ty}");
+        }
     }
 }
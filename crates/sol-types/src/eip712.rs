@@ -1,4 +1,4 @@
-use crate::SolValue;
+use crate::{sol_data, Error, Result, SolType, SolValue};
 use alloc::{borrow::Cow, string::String, vec::Vec};
 use alloy_primitives::{keccak256, Address, FixedBytes, B256, U256};
 
@@ -56,6 +56,87 @@ impl Eip712Domain {
         Self { name, version, chain_id, verifying_contract, salt }
     }
 
+    /// Instantiate a new EIP-712 domain from the return values of an
+    /// [EIP-5267](https://eips.ethereum.org/EIPS/eip-5267) `eip712Domain()`
+    /// call.
+    ///
+    /// Only the fields set in the `fields` bitmap are used. Fails if any of the
+    /// reserved bits are set, or if any extensions are present, since they
+    /// cannot be represented by this type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::{address, fixed_bytes, Address, B256, U256};
+    /// use alloy_sol_types::{eip712_domain, Eip712Domain};
+    ///
+    /// let domain = Eip712Domain::from_eip5267(
+    ///     fixed_bytes!("0x0d"),
+    ///     "Ether Mail".into(),
+    ///     String::new(),
+    ///     U256::from(1),
+    ///     address!("0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"),
+    ///     B256::ZERO,
+    ///     &[],
+    /// )?;
+    /// assert_eq!(
+    ///     domain,
+    ///     eip712_domain! {
+    ///         name: "Ether Mail",
+    ///         chain_id: 1,
+    ///         verifying_contract: address!("0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"),
+    ///     }
+    /// );
+    /// # Ok::<(), alloy_sol_types::Error>(())
+    /// ```
+    pub fn from_eip5267(
+        fields: FixedBytes<1>,
+        name: String,
+        version: String,
+        chain_id: U256,
+        verifying_contract: Address,
+        salt: B256,
+        extensions: &[U256],
+    ) -> Result<Self> {
+        let fields = fields[0];
+        if fields & 0xe0 != 0 {
+            return Err(Error::custom(format!("invalid EIP-5267 fields bitmap: {fields:#04x}")));
+        }
+        if !extensions.is_empty() {
+            return Err(Error::custom("EIP-5267 extensions are not supported"));
+        }
+
+        let has = |bit: u8| fields & (1 << bit) != 0;
+        Ok(Self {
+            name: has(0).then(|| name.into()),
+            version: has(1).then(|| version.into()),
+            chain_id: has(2).then_some(chain_id),
+            verifying_contract: has(3).then_some(verifying_contract),
+            salt: has(4).then_some(salt),
+        })
+    }
+
+    /// ABI-decodes the return data of an
+    /// [EIP-5267](https://eips.ethereum.org/EIPS/eip-5267) `eip712Domain()`
+    /// call into a domain.
+    ///
+    /// See [`from_eip5267`](Self::from_eip5267) for more details.
+    pub fn abi_decode_eip5267(data: &[u8]) -> Result<Self> {
+        type Eip5267Return = (
+            sol_data::FixedBytes<1>,
+            sol_data::String,
+            sol_data::String,
+            sol_data::Uint<256>,
+            sol_data::Address,
+            sol_data::FixedBytes<32>,
+            sol_data::Array<sol_data::Uint<256>>,
+        );
+
+        let (fields, name, version, chain_id, verifying_contract, salt, extensions) =
+            Eip5267Return::abi_decode_params(data)?;
+        Self::from_eip5267(fields, name, version, chain_id, verifying_contract, salt, &extensions)
+    }
+
     /// Calculate the domain separator for the domain object.
     #[inline]
     pub fn separator(&self) -> B256 {
@@ -166,6 +247,84 @@ impl Eip712Domain {
     }
 }
 
+/// How shielded struct members are named in the [EIP-712 `encodeType`]
+/// string, and therefore in the `typeHash` of their struct.
+///
+/// Shielded values are always encoded like their public counterparts: an
+/// `saddress` member is encoded like an `address`, an `suint256` like a
+/// `uint256`, and so on. Only the type names differ:
+///
+/// - [`Shielded`](Self::Shielded) keeps the shielded names, e.g.
+///   `Transfer(saddress to,suint256 amount)`. This is the default, and what
+///   [`SolStruct::eip712_encode_type`](crate::SolStruct::eip712_encode_type)
+///   returns.
+/// - [`Public`](Self::Public) uses the public names, e.g.
+///   `Transfer(address to,uint256 amount)`, so that the message hashes the
+///   same as the equivalent public struct. This is useful to sign messages
+///   with wallets which do not know about shielded types.
+///
+/// The policy applies to all the structs of a message, including nested ones.
+///
+/// [EIP-712 `encodeType`]: https://eips.ethereum.org/EIPS/eip-712#definition-of-encodetype
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Eip712ShieldedPolicy {
+    /// Shielded members keep their shielded type names.
+    #[default]
+    Shielded,
+    /// Shielded members are renamed to their public type names.
+    Public,
+}
+
+impl Eip712ShieldedPolicy {
+    /// Applies this policy to an `encodeType` string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_sol_types::Eip712ShieldedPolicy;
+    /// let encode_type = "Transfer(saddress to,suint256[] amounts,Note note)Note(sbytes32 data)";
+    /// assert_eq!(Eip712ShieldedPolicy::Shielded.encode_type(encode_type), encode_type);
+    /// assert_eq!(
+    ///     Eip712ShieldedPolicy::Public.encode_type(encode_type),
+    ///     "Transfer(address to,uint256[] amounts,Note note)Note(bytes32 data)"
+    /// );
+    /// ```
+    pub fn encode_type(self, encode_type: &str) -> Cow<'_, str> {
+        if self == Self::Shielded {
+            return Cow::Borrowed(encode_type);
+        }
+
+        // Member types start after an opening parenthesis or a comma, and end
+        // before their array suffix or the member name.
+        let mut out = String::with_capacity(encode_type.len());
+        let mut rest = encode_type;
+        while let Some(i) = rest.find(['(', ',']) {
+            out.push_str(&rest[..=i]);
+            rest = &rest[i + 1..];
+            let end = rest.find(['[', ' ', ',', ')']).unwrap_or(rest.len());
+            let (ty, tail) = rest.split_at(end);
+            out.push_str(public_type_name(ty).unwrap_or(ty));
+            rest = tail;
+        }
+        out.push_str(rest);
+        Cow::Owned(out)
+    }
+}
+
+/// Returns the public name of a shielded elementary type name.
+fn public_type_name(ty: &str) -> Option<&str> {
+    let public = ty.strip_prefix('s')?;
+    let is_shielded = match public {
+        "address" | "bool" | "uint" | "int" => true,
+        _ => ["uint", "int", "bytes"].iter().any(|prefix| {
+            public
+                .strip_prefix(prefix)
+                .is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+        }),
+    };
+    is_shielded.then_some(public)
+}
+
 /// Convenience macro to instantiate an [EIP-712 domain](Eip712Domain).
 ///
 /// This macro allows you to instantiate an [EIP-712 domain](Eip712Domain)
@@ -304,6 +463,37 @@ mod tests {
         salt: B256::ZERO,
     };
 
+    #[test]
+    fn eip5267() {
+        let contract = Address::repeat_byte(0x11);
+        let encode = |fields: u8, extensions: Vec<U256>| {
+            let name = String::from("abcd");
+            let version = String::from("1");
+            let salt = B256::repeat_byte(0x22);
+            (FixedBytes([fields]), name, version, U256::from(1), contract, salt, extensions)
+                .abi_encode_params()
+        };
+
+        let domain = eip712_domain! {
+            name: "abcd",
+            version: "1",
+            chain_id: 1,
+            verifying_contract: contract,
+            salt: B256::repeat_byte(0x22),
+        };
+        assert_eq!(Eip712Domain::abi_decode_eip5267(&encode(0x1f, vec![])), Ok(domain));
+
+        // Unset fields are ignored, even if their values are not zero.
+        let domain = eip712_domain! {
+            chain_id: 1,
+            verifying_contract: contract,
+        };
+        assert_eq!(Eip712Domain::abi_decode_eip5267(&encode(0x0c, vec![])), Ok(domain));
+
+        assert!(Eip712Domain::abi_decode_eip5267(&encode(0x20, vec![])).is_err());
+        assert!(Eip712Domain::abi_decode_eip5267(&encode(0x0f, vec![U256::from(1)])).is_err());
+    }
+
     #[test]
     fn runtime_domains() {
         let _: Eip712Domain = eip712_domain! {
@@ -323,4 +513,20 @@ mod tests {
             version: my_cow.into_owned(),
         };
     }
+
+    #[test]
+    fn shielded_policy_encode_type() {
+        let public = Eip712ShieldedPolicy::Public;
+        assert_eq!(
+            public.encode_type("A(sint sa,suint8[2][] sb,sbytes1 sc,sbool sd)"),
+            "A(int sa,uint8[2][] sb,bytes1 sc,bool sd)"
+        );
+        // Struct names and non-shielded types are left untouched.
+        assert_eq!(
+            public.encode_type("sbool(saddressBook a,sint8x b,sbytes c,string saddress)"),
+            "sbool(saddressBook a,sint8x b,sbytes c,string saddress)"
+        );
+        assert_eq!(public.encode_type("Empty()"), "Empty()");
+        assert_eq!(public.encode_type(""), "");
+    }
 }
@@ -172,6 +172,14 @@ impl From<hex::FromHexError> for Error {
     }
 }
 
+#[cfg(feature = "seismic-crypto")]
+impl From<alloy_primitives::seismic_crypto::DecryptionError> for Error {
+    #[inline]
+    fn from(_: alloy_primitives::seismic_crypto::DecryptionError) -> Self {
+        Self::custom("failed to decrypt ciphertext")
+    }
+}
+
 impl From<TryReserveError> for Error {
     #[inline]
     fn from(value: TryReserveError) -> Self {
@@ -30,10 +30,11 @@ mod impl_core;
 
 mod types;
 pub use types::{
-    data_type as sol_data, decode_revert_reason, ContractError, EventTopic, GenericContractError,
-    GenericRevertReason, Panic, PanicKind, Revert, RevertReason, Selectors, SolCall,
-    SolConstructor, SolEnum, SolError, SolEvent, SolEventInterface, SolInterface, SolStruct,
-    SolType, SolValue, TopicList,
+    data_type as sol_data, decode_revert_reason, ContractError, Either, EventTopic,
+    GenericContractError, GenericRevertReason, LogFilterExt, Panic, PanicKind, Revert,
+    RevertReason, SelectorSet, Selectors, SolCall, SolConstructor, SolEnum, SolError, SolEvent,
+    SolEventInterface, SolInterface, SolStruct, SolType, SolValue, TopicList,
+    MAX_NESTED_REVERT_DEPTH,
 };
 
 #[cfg(feature = "seismic")]
@@ -42,13 +43,13 @@ pub use types::data_type::{Saddress, Sbool, Sbytes, Sint, Suint};
 pub mod utils;
 
 mod eip712;
-pub use eip712::Eip712Domain;
+pub use eip712::{Eip712Domain, Eip712ShieldedPolicy};
 
 /// The ABI word type.
 pub type Word = alloy_primitives::B256;
 
 #[doc(no_inline)]
-pub use alloy_sol_macro::sol;
+pub use alloy_sol_macro::{sol, SolType, SolValue};
 
 // Not public API.
 #[doc(hidden)]
@@ -111,6 +112,15 @@ pub mod private {
         fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>);
 
         fn stv_eip712_data_word(&self) -> super::Word;
+
+        #[inline]
+        fn stv_eip712_data_word_with_policy(
+            &self,
+            policy: super::Eip712ShieldedPolicy,
+        ) -> super::Word {
+            let _ = policy;
+            self.stv_eip712_data_word()
+        }
     }
 
     #[inline(always)]
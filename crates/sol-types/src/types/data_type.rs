@@ -8,11 +8,11 @@
 
 #![allow(missing_copy_implementations, missing_debug_implementations)]
 
-use crate::{abi::token::*, private::SolTypeValue, utils, SolType, Word};
-use alloc::{string::String as RustString, vec::Vec};
+use crate::{abi::token::*, private::SolTypeValue, utils, Eip712ShieldedPolicy, SolType, Word};
+use alloc::{borrow::Cow, string::String as RustString, vec::Vec};
 use alloy_primitives::{
-    aliases::*, keccak256, Address as RustAddress, Bytes as RustBytes,
-    FixedBytes as RustFixedBytes, Function as RustFunction, I256, U256,
+    aliases::*, keccak256, Address as RustAddress, Bytes as RustBytes, Fixed as RustFixed,
+    FixedBytes as RustFixedBytes, Function as RustFunction, UFixed as RustUfixed, I256, U256,
 };
 
 #[cfg(feature = "seismic")]
@@ -60,6 +60,11 @@ impl SolType for Bool {
     fn detokenize(token: Self::Token<'_>) -> Self::RustType {
         token.0 != Word::ZERO
     }
+
+    #[inline]
+    fn fmt_token_with(token: &Self::Token<'_>, f: &mut Formatter<'_>, _redact: bool) -> Result {
+        write!(f, "{}", Self::detokenize(*token))
+    }
 }
 
 /// Int - `intX`
@@ -114,6 +119,11 @@ where
     fn detokenize(token: Self::Token<'_>) -> Self::RustType {
         IntBitCount::<BITS>::detokenize_int(token)
     }
+
+    #[inline]
+    fn fmt_token_with(token: &Self::Token<'_>, f: &mut Formatter<'_>, _redact: bool) -> Result {
+        write!(f, "{}", Self::detokenize(*token))
+    }
 }
 
 /// Uint - `uintX`
@@ -160,6 +170,135 @@ where
     fn detokenize(token: Self::Token<'_>) -> Self::RustType {
         IntBitCount::<BITS>::detokenize_uint(token)
     }
+
+    #[inline]
+    fn fmt_token_with(token: &Self::Token<'_>, f: &mut Formatter<'_>, _redact: bool) -> Result {
+        write!(f, "{}", Self::detokenize(*token))
+    }
+}
+
+/// Fixed - `fixedMxN`
+///
+/// The Rust type is always backed by a 256-bit integer. Values that do not fit
+/// in `M` bits encode to tokens that fail validation.
+pub struct Fixed<const M: usize, const N: usize>;
+
+impl<T, const M: usize, const N: usize> SolTypeValue<Fixed<M, N>> for T
+where
+    T: Borrow<RustFixed<256, 4, N>>,
+    IntBitCount<M>: SupportedInt,
+{
+    #[inline]
+    fn stv_to_tokens(&self) -> WordToken {
+        WordToken(self.borrow().into_raw().to_be_bytes::<32>().into())
+    }
+
+    #[inline]
+    fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
+        let word = self.borrow().into_raw().to_be_bytes::<32>();
+        out.extend_from_slice(&word[IntBitCount::<M>::WORD_MSB..]);
+    }
+
+    #[inline]
+    fn stv_eip712_data_word(&self) -> Word {
+        SolTypeValue::<Fixed<M, N>>::stv_to_tokens(self).0
+    }
+}
+
+impl<const M: usize, const N: usize> Fixed<M, N> {
+    const NAME: ([u8; 12], usize) = utils::fixed_name(true, M, N);
+}
+
+impl<const M: usize, const N: usize> SolType for Fixed<M, N>
+where
+    IntBitCount<M>: SupportedInt,
+{
+    type RustType = RustFixed<256, 4, N>;
+    type Token<'a> = WordToken;
+
+    const SOL_NAME: &'static str = utils::name_str(Self::NAME.0.split_at(Self::NAME.1).0);
+    const ENCODED_SIZE: Option<usize> = Some(32);
+    const PACKED_ENCODED_SIZE: Option<usize> = Some(M / 8);
+
+    #[inline]
+    fn valid_token(token: &Self::Token<'_>) -> bool {
+        <Int<M> as SolType>::valid_token(token)
+    }
+
+    #[inline]
+    fn detokenize(mut token: Self::Token<'_>) -> Self::RustType {
+        // sign extend bits to ignore
+        let msb = IntBitCount::<M>::WORD_MSB;
+        let sign_extension = (token.0[msb] & 0x80 == 0x80) as u8 * 0xff;
+        token.0[..msb].fill(sign_extension);
+        RustFixed::from_raw(I256::from_be_bytes(token.0 .0))
+    }
+
+    #[inline]
+    fn fmt_token_with(token: &Self::Token<'_>, f: &mut Formatter<'_>, _redact: bool) -> Result {
+        write!(f, "{}", Self::detokenize(*token))
+    }
+}
+
+/// Ufixed - `ufixedMxN`
+///
+/// The Rust type is always backed by a 256-bit integer. Values that do not fit
+/// in `M` bits encode to tokens that fail validation.
+pub struct Ufixed<const M: usize, const N: usize>;
+
+impl<T, const M: usize, const N: usize> SolTypeValue<Ufixed<M, N>> for T
+where
+    T: Borrow<RustUfixed<256, 4, N>>,
+    IntBitCount<M>: SupportedInt,
+{
+    #[inline]
+    fn stv_to_tokens(&self) -> WordToken {
+        WordToken(self.borrow().into_raw().into())
+    }
+
+    #[inline]
+    fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
+        let word = self.borrow().into_raw().to_be_bytes::<32>();
+        out.extend_from_slice(&word[IntBitCount::<M>::WORD_MSB..]);
+    }
+
+    #[inline]
+    fn stv_eip712_data_word(&self) -> Word {
+        SolTypeValue::<Ufixed<M, N>>::stv_to_tokens(self).0
+    }
+}
+
+impl<const M: usize, const N: usize> Ufixed<M, N> {
+    const NAME: ([u8; 12], usize) = utils::fixed_name(false, M, N);
+}
+
+impl<const M: usize, const N: usize> SolType for Ufixed<M, N>
+where
+    IntBitCount<M>: SupportedInt,
+{
+    type RustType = RustUfixed<256, 4, N>;
+    type Token<'a> = WordToken;
+
+    const SOL_NAME: &'static str = utils::name_str(Self::NAME.0.split_at(Self::NAME.1).0);
+    const ENCODED_SIZE: Option<usize> = Some(32);
+    const PACKED_ENCODED_SIZE: Option<usize> = Some(M / 8);
+
+    #[inline]
+    fn valid_token(token: &Self::Token<'_>) -> bool {
+        <Uint<M> as SolType>::valid_token(token)
+    }
+
+    #[inline]
+    fn detokenize(mut token: Self::Token<'_>) -> Self::RustType {
+        // zero out bits to ignore
+        token.0[..IntBitCount::<M>::WORD_MSB].fill(0);
+        RustUfixed::from_raw(U256::from_be_bytes(token.0 .0))
+    }
+
+    #[inline]
+    fn fmt_token_with(token: &Self::Token<'_>, f: &mut Formatter<'_>, _redact: bool) -> Result {
+        write!(f, "{}", Self::detokenize(*token))
+    }
 }
 
 /// FixedBytes - `bytesX`
@@ -208,6 +347,11 @@ where
     fn detokenize(token: Self::Token<'_>) -> Self::RustType {
         token.0[..N].try_into().unwrap()
     }
+
+    #[inline]
+    fn fmt_token_with(token: &Self::Token<'_>, f: &mut Formatter<'_>, _redact: bool) -> Result {
+        write!(f, "{}", Self::detokenize(*token))
+    }
 }
 
 /// Address - `address`
@@ -247,6 +391,11 @@ impl SolType for Address {
     fn valid_token(token: &Self::Token<'_>) -> bool {
         utils::check_zeroes(&token.0[..12])
     }
+
+    #[inline]
+    fn fmt_token_with(token: &Self::Token<'_>, f: &mut Formatter<'_>, _redact: bool) -> Result {
+        write!(f, "{}", Self::detokenize(*token))
+    }
 }
 
 /// Function - `function`
@@ -286,6 +435,11 @@ impl SolType for Function {
     fn valid_token(token: &Self::Token<'_>) -> bool {
         utils::check_zeroes(&token.0[24..])
     }
+
+    #[inline]
+    fn fmt_token_with(token: &Self::Token<'_>, f: &mut Formatter<'_>, _redact: bool) -> Result {
+        write!(f, "{}", Self::detokenize(*token))
+    }
 }
 
 /// Bytes - `bytes`
@@ -340,6 +494,11 @@ impl SolType for Bytes {
     fn detokenize(token: Self::Token<'_>) -> Self::RustType {
         token.into_bytes()
     }
+
+    #[inline]
+    fn fmt_token_with(token: &Self::Token<'_>, f: &mut Formatter<'_>, _redact: bool) -> Result {
+        fmt_hex(token.as_slice(), f)
+    }
 }
 
 /// String - `string`
@@ -398,6 +557,11 @@ impl SolType for String {
         // data.
         RustString::from_utf8_lossy(token.as_slice()).into_owned()
     }
+
+    #[inline]
+    fn fmt_token_with(token: &Self::Token<'_>, f: &mut Formatter<'_>, _redact: bool) -> Result {
+        write!(f, "{:?}", RustString::from_utf8_lossy(token.as_slice()))
+    }
 }
 
 /// Array - `T[]`
@@ -431,6 +595,15 @@ where
         keccak256(encoded)
     }
 
+    #[inline]
+    fn stv_eip712_data_word_with_policy(&self, policy: Eip712ShieldedPolicy) -> Word {
+        let mut encoded = Vec::new();
+        for item in self {
+            encoded.extend_from_slice(T::stv_eip712_data_word_with_policy(item, policy).as_slice());
+        }
+        keccak256(encoded)
+    }
+
     #[inline]
     fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
         for item in self {
@@ -468,6 +641,11 @@ where
         (**self).stv_eip712_data_word()
     }
 
+    #[inline]
+    fn stv_eip712_data_word_with_policy(&self, policy: Eip712ShieldedPolicy) -> Word {
+        (**self).stv_eip712_data_word_with_policy(policy)
+    }
+
     #[inline]
     fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
         (**self).stv_abi_encode_packed_to(out)
@@ -499,6 +677,11 @@ where
         (**self).stv_eip712_data_word()
     }
 
+    #[inline]
+    fn stv_eip712_data_word_with_policy(&self, policy: Eip712ShieldedPolicy) -> Word {
+        (**self).stv_eip712_data_word_with_policy(policy)
+    }
+
     #[inline]
     fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
         (**self).stv_abi_encode_packed_to(out)
@@ -530,6 +713,11 @@ where
         (**self).stv_eip712_data_word()
     }
 
+    #[inline]
+    fn stv_eip712_data_word_with_policy(&self, policy: Eip712ShieldedPolicy) -> Word {
+        (**self).stv_eip712_data_word_with_policy(policy)
+    }
+
     #[inline]
     fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
         (**self).stv_abi_encode_packed_to(out)
@@ -559,6 +747,16 @@ impl<T: SolType> SolType for Array<T> {
     fn detokenize(token: Self::Token<'_>) -> Self::RustType {
         token.0.into_iter().map(T::detokenize).collect()
     }
+
+    #[inline]
+    fn eip712_referenced_types() -> Vec<Cow<'static, str>> {
+        T::eip712_referenced_types()
+    }
+
+    #[inline]
+    fn fmt_token_with(token: &Self::Token<'_>, f: &mut Formatter<'_>, redact: bool) -> Result {
+        fmt_seq::<T>(&token.0, f, redact)
+    }
 }
 
 /// FixedArray - `T[M]`
@@ -594,6 +792,13 @@ where
         keccak256(encoded.as_flattened())
     }
 
+    #[inline]
+    fn stv_eip712_data_word_with_policy(&self, policy: Eip712ShieldedPolicy) -> Word {
+        let encoded =
+            core::array::from_fn::<_, N, _>(|i| self[i].stv_eip712_data_word_with_policy(policy).0);
+        keccak256(encoded.as_flattened())
+    }
+
     #[inline]
     fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
         for item in self {
@@ -631,6 +836,11 @@ where
         SolTypeValue::<FixedArray<U, N>>::stv_eip712_data_word(&**self)
     }
 
+    #[inline]
+    fn stv_eip712_data_word_with_policy(&self, policy: Eip712ShieldedPolicy) -> Word {
+        SolTypeValue::<FixedArray<U, N>>::stv_eip712_data_word_with_policy(&**self, policy)
+    }
+
     #[inline]
     fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
         SolTypeValue::<FixedArray<U, N>>::stv_abi_encode_packed_to(&**self, out)
@@ -662,6 +872,11 @@ where
         SolTypeValue::<FixedArray<U, N>>::stv_eip712_data_word(&**self)
     }
 
+    #[inline]
+    fn stv_eip712_data_word_with_policy(&self, policy: Eip712ShieldedPolicy) -> Word {
+        SolTypeValue::<FixedArray<U, N>>::stv_eip712_data_word_with_policy(&**self, policy)
+    }
+
     #[inline]
     fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
         SolTypeValue::<FixedArray<U, N>>::stv_abi_encode_packed_to(&**self, out)
@@ -698,6 +913,16 @@ impl<T: SolType, const N: usize> SolType for FixedArray<T, N> {
     fn detokenize(token: Self::Token<'_>) -> Self::RustType {
         token.0.map(T::detokenize)
     }
+
+    #[inline]
+    fn eip712_referenced_types() -> Vec<Cow<'static, str>> {
+        T::eip712_referenced_types()
+    }
+
+    #[inline]
+    fn fmt_token_with(token: &Self::Token<'_>, f: &mut Formatter<'_>, redact: bool) -> Result {
+        fmt_seq::<T>(token.as_array(), f, redact)
+    }
 }
 
 macro_rules! tuple_encodable_impls {
@@ -797,6 +1022,19 @@ macro_rules! tuple_impls {
                     <$ty as SolType>::detokenize($ty),
                 )+)
             }
+
+            fn fmt_token_with(token: &Self::Token<'_>, f: &mut Formatter<'_>, redact: bool) -> Result {
+                let ($($ty,)+) = token;
+                f.write_str("(")?;
+                let mut first = true;
+                $(
+                    if !core::mem::take(&mut first) {
+                        f.write_str(", ")?;
+                    }
+                    <$ty as SolType>::fmt_token_with($ty, f, redact)?;
+                )+
+                f.write_str(")")
+            }
         }
     };
 }
@@ -831,10 +1069,33 @@ impl SolType for () {
 
     #[inline]
     fn detokenize((): ()) -> Self::RustType {}
+
+    #[inline]
+    fn fmt_token_with((): &(), f: &mut Formatter<'_>, _redact: bool) -> Result {
+        f.write_str("()")
+    }
 }
 
 all_the_tuples!(tuple_impls);
 
+/// Formats bytes as a `0x`-prefixed hex string.
+fn fmt_hex(bytes: &[u8], f: &mut Formatter<'_>) -> Result {
+    f.write_str("0x")?;
+    bytes.iter().try_for_each(|b| write!(f, "{b:02x}"))
+}
+
+/// Formats a sequence of tokens as `[a, b, ...]`.
+fn fmt_seq<T: SolType>(tokens: &[T::Token<'_>], f: &mut Formatter<'_>, redact: bool) -> Result {
+    f.write_str("[")?;
+    for (i, token) in tokens.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        T::fmt_token_with(token, f, redact)?;
+    }
+    f.write_str("]")
+}
+
 #[allow(unknown_lints, unnameable_types)]
 mod sealed {
     /// To prevent users from implementing downstream
@@ -1198,6 +1459,10 @@ mod seismic {
     use super::*;
     use alloy_primitives::{Signed as RustSigned, Uint as RustUint};
 
+    /// Written in place of shielded values by [`SolType::fmt_token`]. See
+    /// [`SolType::fmt_token_unredacted`].
+    const SHIELDED_PLACEHOLDER: &str = "<shielded>";
+
     /// `Sbool` is our seismic-boolean type. Unlike the legacy `Bool`,
     /// which uses a plain `bool` in Rust, we store `bool` inside a newtype
     /// so we can treat it differently if desired (e.g. “shielded bool”).
@@ -1207,7 +1472,7 @@ mod seismic {
     // 1) Implement `SolType` for `Sbool` in the usual way
     impl SolType for Sbool {
         // Because `Sbool` is the final, stored type
-        type RustType = Sbool;
+        type RustType = Self;
         type Token<'a> = WordToken;
 
         const SOL_NAME: &'static str = "sbool";
@@ -1220,7 +1485,15 @@ mod seismic {
 
         fn detokenize(token: Self::Token<'_>) -> Self::RustType {
             // Non-zero last byte => true
-            Sbool(token.0 != Word::ZERO)
+            Self(token.0 != Word::ZERO)
+        }
+
+        #[inline]
+        fn fmt_token_with(token: &Self::Token<'_>, f: &mut Formatter<'_>, redact: bool) -> Result {
+            if redact {
+                return f.write_str(SHIELDED_PLACEHOLDER);
+            }
+            write!(f, "{}", Bool::detokenize(*token))
         }
     }
 
@@ -1248,7 +1521,7 @@ mod seismic {
     impl arbitrary::Arbitrary<'_> for Sbool {
         fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
             let arbitrary_bool = u.arbitrary::<bool>()?;
-            Ok(Sbool(arbitrary_bool))
+            Ok(Self(arbitrary_bool))
         }
     }
 
@@ -1292,6 +1565,14 @@ mod seismic {
         fn detokenize(token: Self::Token<'_>) -> Self::RustType {
             RustSAddress(RustAddress::from_word(token.0))
         }
+
+        #[inline]
+        fn fmt_token_with(token: &Self::Token<'_>, f: &mut Formatter<'_>, redact: bool) -> Result {
+            if redact {
+                return f.write_str(SHIELDED_PLACEHOLDER);
+            }
+            write!(f, "{}", Address::detokenize(*token))
+        }
     }
 
     /// Seismic Shielded Signed Integer - `sintX`
@@ -1333,13 +1614,21 @@ mod seismic {
 
         #[inline]
         fn valid_token(_token: &Self::Token<'_>) -> bool {
-            return true;
+            true
         }
 
         #[inline]
         fn detokenize(token: Self::Token<'_>) -> Self::RustType {
             IntBitCount::<BITS>::detokenize_int(token)
         }
+
+        #[inline]
+        fn fmt_token_with(token: &Self::Token<'_>, f: &mut Formatter<'_>, redact: bool) -> Result {
+            if redact {
+                return f.write_str(SHIELDED_PLACEHOLDER);
+            }
+            write!(f, "{}", RustSigned::<256, 4>::from_be_bytes(token.0 .0))
+        }
     }
 
     /// Seismic Shielded Unsigned Integer - `suintX`
@@ -1380,13 +1669,21 @@ mod seismic {
 
         #[inline]
         fn valid_token(_token: &Self::Token<'_>) -> bool {
-            return true;
+            true
         }
 
         #[inline]
         fn detokenize(token: Self::Token<'_>) -> Self::RustType {
             IntBitCount::<BITS>::detokenize_uint(token)
         }
+
+        #[inline]
+        fn fmt_token_with(token: &Self::Token<'_>, f: &mut Formatter<'_>, redact: bool) -> Result {
+            if redact {
+                return f.write_str(SHIELDED_PLACEHOLDER);
+            }
+            write!(f, "{}", RustUint::<256, 4>::from_be_bytes(token.0 .0))
+        }
     }
 
     macro_rules! declare_sint_types {
@@ -1598,6 +1895,14 @@ mod seismic {
         fn detokenize(token: Self::Token<'_>) -> Self::RustType {
             token.0[..N].try_into().unwrap()
         }
+
+        #[inline]
+        fn fmt_token_with(token: &Self::Token<'_>, f: &mut Formatter<'_>, redact: bool) -> Result {
+            if redact {
+                return f.write_str(SHIELDED_PLACEHOLDER);
+            }
+            fmt_hex(&token.0[..N], f)
+        }
     }
 }
 
@@ -1625,6 +1930,9 @@ mod tests {
         assert_name!(Int<8>, "int8");
         assert_name!(Int<16>, "int16");
         assert_name!(Int<32>, "int32");
+        assert_name!(Fixed<128, 18>, "fixed128x18");
+        assert_name!(Fixed<8, 0>, "fixed8x0");
+        assert_name!(Ufixed<256, 80>, "ufixed256x80");
         assert_name!(FixedBytes<1>, "bytes1");
         assert_name!(FixedBytes<16>, "bytes16");
         assert_name!(FixedBytes<32>, "bytes32");
@@ -2012,4 +2320,30 @@ mod tests {
         assert_eq!(hex::encode(res_ty), hex::encode(expected));
         assert_eq!(hex::encode(res_value), hex::encode(expected));
     }
+
+    #[test]
+    fn fixed() {
+        type F = Fixed<16, 2>;
+        type U = Ufixed<16, 2>;
+
+        let x: RustFixed<256, 4, 2> = "-1.5".parse().unwrap();
+        let encoded = F::abi_encode(&x);
+        assert_eq!(
+            encoded,
+            hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff6a")
+        );
+        assert_eq!(F::abi_decode(&encoded).unwrap(), x);
+        assert_eq!(F::abi_encode_packed(&x), hex!("ff6a"));
+        assert_eq!(x.abi_encode(), encoded);
+
+        let y: RustUfixed<256, 4, 2> = "655.35".parse().unwrap();
+        let encoded = U::abi_encode(&y);
+        assert_eq!(U::abi_decode_validate(&encoded).unwrap(), y);
+        assert_eq!(U::abi_encode_packed(&y), hex!("ffff"));
+
+        // Out of range for 16 bits.
+        let z: RustUfixed<256, 4, 2> = "655.36".parse().unwrap();
+        assert!(U::abi_decode_validate(&U::abi_encode(&z)).is_err());
+        assert_eq!(U::abi_decode(&U::abi_encode(&z)).unwrap(), RustUfixed::ZERO);
+    }
 }
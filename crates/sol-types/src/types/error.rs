@@ -1,7 +1,7 @@
 use crate::{
     abi::token::{PackedSeqToken, Token, TokenSeq, WordToken},
     types::interface::RevertReason,
-    Result, SolType, Word,
+    utils, Result, SolType, Word,
 };
 use alloc::{string::String, vec::Vec};
 use alloy_primitives::U256;
@@ -103,6 +103,53 @@ pub trait SolError: Sized {
         self.abi_encode_raw(&mut out);
         out
     }
+
+    /// Returns the revert data wrapped by this error, if any.
+    ///
+    /// Some errors carry the revert data of an inner call, like
+    /// `error CallFailed(uint256 index, bytes reason)`. For errors generated by
+    /// [`sol!`](crate::sol!) with `#[sol(nested_revert)]`, this is the value of
+    /// the last `bytes` parameter; other errors return `None`.
+    ///
+    /// This is used by [`SolInterface::abi_decode_chain`] to decode nested
+    /// errors.
+    ///
+    /// [`SolInterface::abi_decode_chain`]: crate::SolInterface::abi_decode_chain
+    #[inline]
+    fn nested_revert_data(&self) -> Option<&[u8]> {
+        None
+    }
+
+    /// Formats the error in a human-readable, Solidity-like way, e.g.
+    /// `InsufficientBalance(100, 200)`.
+    ///
+    /// Shielded values are redacted, see
+    /// [`fmt_error_unredacted`](Self::fmt_error_unredacted).
+    #[inline]
+    fn fmt_error(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_error_with(f, true)
+    }
+
+    /// Formats the error like [`fmt_error`](Self::fmt_error), but writes shielded
+    /// values in the clear.
+    #[inline]
+    fn fmt_error_unredacted(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_error_with(f, false)
+    }
+
+    /// Formats the error, redacting shielded values if `redact` is set.
+    ///
+    /// This is the method to implement for [`fmt_error`](Self::fmt_error) and
+    /// [`fmt_error_unredacted`](Self::fmt_error_unredacted).
+    ///
+    /// The default implementation writes the fields positionally; errors
+    /// generated with `#[sol(display)]` also write the field names. See
+    /// [`SolType::fmt_token`] for how values are formatted.
+    #[inline]
+    fn fmt_error_with(&self, f: &mut fmt::Formatter<'_>, redact: bool) -> fmt::Result {
+        f.write_str(utils::signature_name(Self::SIGNATURE))?;
+        <Self::Parameters<'_> as SolType>::fmt_token_with(&self.tokenize(), f, redact)
+    }
 }
 
 /// Represents a standard Solidity revert. These are thrown by `revert(reason)`
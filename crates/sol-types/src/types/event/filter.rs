@@ -0,0 +1,114 @@
+use crate::{EventTopic, SolType, SolValue};
+use alloy_primitives::LogFilter;
+
+#[allow(unknown_lints, unnameable_types)]
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for alloy_primitives::LogFilter {}
+}
+use sealed::Sealed;
+
+/// Extension trait for [`LogFilter`] to match indexed event parameters by
+/// their Rust values.
+///
+/// This trait is sealed and cannot be implemented for types outside of this
+/// crate. It is implemented only for [`LogFilter`].
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{Address, U256};
+/// use alloy_sol_types::{sol, LogFilterExt, SolEvent};
+///
+/// sol! {
+///     event Transfer(address indexed from, address indexed to, uint256 value);
+/// }
+///
+/// let from = Address::with_last_byte(1);
+/// let filter = Transfer::filter().indexed(1, &from);
+///
+/// let log = Transfer { from, to: Address::ZERO, value: U256::from(1) }.encode_log_data();
+/// assert!(filter.matches_raw(Address::ZERO, log.topics()));
+/// ```
+pub trait LogFilterExt: Sealed {
+    /// Adds the topic encoding of the given indexed parameter value to the
+    /// set of matched topics at the given position.
+    ///
+    /// The position counts the signature hash of non-anonymous events, so
+    /// the first indexed parameter is at position 1 for these.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 3.
+    fn indexed<V>(self, index: usize, value: &V) -> Self
+    where
+        V: SolValue,
+        V::SolType: EventTopic + SolType<RustType = V>;
+
+    /// Adds the topic encodings of the given indexed parameter values to the
+    /// set of matched topics at the given position.
+    ///
+    /// See [`indexed`](Self::indexed) for more details.
+    fn indexed_any<'a, V>(self, index: usize, values: impl IntoIterator<Item = &'a V>) -> Self
+    where
+        V: SolValue + 'a,
+        V::SolType: EventTopic + SolType<RustType = V>;
+}
+
+impl LogFilterExt for LogFilter {
+    #[inline]
+    #[track_caller]
+    fn indexed<V>(self, index: usize, value: &V) -> Self
+    where
+        V: SolValue,
+        V::SolType: EventTopic + SolType<RustType = V>,
+    {
+        self.topic(index, V::SolType::encode_topic(value))
+    }
+
+    #[inline]
+    #[track_caller]
+    fn indexed_any<'a, V>(self, index: usize, values: impl IntoIterator<Item = &'a V>) -> Self
+    where
+        V: SolValue + 'a,
+        V::SolType: EventTopic + SolType<RustType = V>,
+    {
+        self.topics(index, values.into_iter().map(V::SolType::encode_topic))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{sol, SolEvent};
+    use alloy_primitives::{keccak256, Address, U256};
+
+    sol! {
+        event Transfer(address indexed from, address indexed to, uint256 value);
+        event Named(string indexed name) anonymous;
+    }
+
+    #[test]
+    fn event_filter() {
+        let a = Address::with_last_byte(1);
+        let b = Address::with_last_byte(2);
+        let log = |from, to| Transfer { from, to, value: U256::ZERO }.encode_log_data();
+
+        let filter = Transfer::filter();
+        assert!(filter.matches_raw(a, log(a, b).topics()));
+        assert!(!filter.matches_raw(a, Named { name: keccak256("a") }.encode_log_data().topics()));
+
+        let filter = Transfer::filter().indexed(1, &a).indexed_any(2, &[a, b]);
+        assert!(filter.matches_raw(a, log(a, b).topics()));
+        assert!(filter.matches_raw(a, log(a, a).topics()));
+        assert!(!filter.matches_raw(a, log(b, a).topics()));
+
+        let filter = Named::filter();
+        assert!(filter.is_empty());
+        let filter = filter.indexed(0, &"alice".to_string());
+        assert!(
+            filter.matches_raw(a, Named { name: keccak256("alice") }.encode_log_data().topics())
+        );
+        assert!(!filter.matches_raw(a, Named { name: keccak256("bob") }.encode_log_data().topics()));
+    }
+}
@@ -1,9 +1,13 @@
 use crate::{
     abi::token::{Token, TokenSeq, WordToken},
-    Result, SolType, Word,
+    utils, Result, SolType, Word,
 };
 use alloc::vec::Vec;
-use alloy_primitives::{FixedBytes, Log, LogData, B256};
+use alloy_primitives::{FixedBytes, Log, LogData, LogFilter, B256};
+use core::fmt;
+
+mod filter;
+pub use filter::LogFilterExt;
 
 mod topic;
 pub use topic::EventTopic;
@@ -113,6 +117,38 @@ pub trait SolEvent: Sized {
         out
     }
 
+    /// Formats the event in a human-readable, Solidity-like way, e.g.
+    /// `Transfer(1000000)`.
+    ///
+    /// Shielded values are redacted, see
+    /// [`fmt_event_unredacted`](Self::fmt_event_unredacted).
+    #[inline]
+    fn fmt_event(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_event_with(f, true)
+    }
+
+    /// Formats the event like [`fmt_event`](Self::fmt_event), but writes shielded
+    /// values in the clear.
+    #[inline]
+    fn fmt_event_unredacted(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_event_with(f, false)
+    }
+
+    /// Formats the event, redacting shielded values if `redact` is set.
+    ///
+    /// This is the method to implement for [`fmt_event`](Self::fmt_event) and
+    /// [`fmt_event_unredacted`](Self::fmt_event_unredacted).
+    ///
+    /// The default implementation only writes the non-indexed parameters,
+    /// positionally; events generated with `#[sol(display)]` write all of the
+    /// parameters with their names, with hashed indexed parameters written as
+    /// their topic. See [`SolType::fmt_token`] for how values are formatted.
+    #[inline]
+    fn fmt_event_with(&self, f: &mut fmt::Formatter<'_>, redact: bool) -> fmt::Result {
+        f.write_str(utils::signature_name(Self::SIGNATURE))?;
+        <Self::DataTuple<'_> as SolType>::fmt_token_with(&self.tokenize_body(), f, redact)
+    }
+
     /// Encode the topics of this event into the given buffer.
     ///
     /// # Errors
@@ -141,6 +177,21 @@ pub trait SolEvent: Sized {
         out
     }
 
+    /// Returns a [`LogFilter`] that matches this event by its signature hash.
+    ///
+    /// Anonymous events do not emit their signature hash, so their filter
+    /// matches every log. Use [`LogFilterExt`] to also match indexed
+    /// parameters.
+    #[inline]
+    fn filter() -> LogFilter {
+        let filter = LogFilter::new();
+        if Self::ANONYMOUS {
+            filter
+        } else {
+            filter.event_signature(Self::SIGNATURE_HASH)
+        }
+    }
+
     /// Encode this event to a [`LogData`].
     fn encode_log_data(&self) -> LogData {
         LogData::new_unchecked(
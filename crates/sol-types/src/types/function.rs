@@ -1,9 +1,12 @@
 use crate::{
     abi::{Token, TokenSeq},
     private::SolTypeValue,
-    Result, SolType, Word,
+    utils, Result, SolType, Word,
 };
 use alloc::vec::Vec;
+#[cfg(feature = "seismic-crypto")]
+use alloy_primitives::seismic_crypto::{EncryptionKey, EncryptionNonce};
+use core::fmt;
 
 /// A Solidity function call.
 ///
@@ -138,6 +141,102 @@ pub trait SolCall: Sized {
     {
         crate::abi::encode_sequence(&e.stv_to_tokens())
     }
+
+    /// ABI encode the call **with** its selector, and encrypt it with the
+    /// given Seismic key and nonce.
+    ///
+    /// **Warning:** a nonce must never be used twice with the same key,
+    /// including to encrypt both a call and its return values with
+    /// [`abi_encode_returns_encrypted`](Self::abi_encode_returns_encrypted).
+    /// Reusing a nonce breaks both the confidentiality and the integrity of
+    /// AES-GCM.
+    ///
+    /// See [`seismic_crypto`](alloy_primitives::seismic_crypto) for details.
+    #[cfg(feature = "seismic-crypto")]
+    #[inline]
+    fn abi_encode_encrypted(&self, key: &EncryptionKey, nonce: &EncryptionNonce) -> Vec<u8> {
+        key.encrypt(nonce, &self.abi_encode(), &[])
+    }
+
+    /// Decrypt the given data with the given Seismic key and nonce, and ABI
+    /// decode it into a call, **with** its selector.
+    ///
+    /// **Warning:** the nonce must be the one the call was encrypted with, and
+    /// must not be reused to encrypt its return values. See
+    /// [`abi_encode_encrypted`](Self::abi_encode_encrypted).
+    #[cfg(feature = "seismic-crypto")]
+    #[inline]
+    fn abi_decode_encrypted(
+        data: &[u8],
+        key: &EncryptionKey,
+        nonce: &EncryptionNonce,
+    ) -> Result<Self> {
+        Self::abi_decode(&key.decrypt(nonce, data, &[])?)
+    }
+
+    /// ABI encode the call's return values, and encrypt them with the given
+    /// Seismic key and nonce.
+    ///
+    /// **Warning:** a nonce must never be used twice with the same key. In
+    /// particular, the return values must not be encrypted with the nonce of
+    /// the call they answer. See
+    /// [`abi_encode_encrypted`](Self::abi_encode_encrypted).
+    #[cfg(feature = "seismic-crypto")]
+    #[inline]
+    fn abi_encode_returns_encrypted(
+        ret: &Self::Return,
+        key: &EncryptionKey,
+        nonce: &EncryptionNonce,
+    ) -> Vec<u8> {
+        key.encrypt(nonce, &Self::abi_encode_returns(ret), &[])
+    }
+
+    /// Decrypt the given data with the given Seismic key and nonce, and ABI
+    /// decode it into the call's return values.
+    ///
+    /// **Warning:** the nonce must be the one the return values were encrypted
+    /// with, which must differ from the nonce of the call. See
+    /// [`abi_encode_encrypted`](Self::abi_encode_encrypted).
+    #[cfg(feature = "seismic-crypto")]
+    #[inline]
+    fn abi_decode_returns_encrypted(
+        data: &[u8],
+        key: &EncryptionKey,
+        nonce: &EncryptionNonce,
+    ) -> Result<Self::Return> {
+        Self::abi_decode_returns(&key.decrypt(nonce, data, &[])?)
+    }
+
+    /// Formats the call in a human-readable, Solidity-like way, e.g.
+    /// `transfer(0xAbC..., 1000000)`.
+    ///
+    /// Shielded values are redacted, see
+    /// [`fmt_call_unredacted`](Self::fmt_call_unredacted).
+    #[inline]
+    fn fmt_call(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_call_with(f, true)
+    }
+
+    /// Formats the call like [`fmt_call`](Self::fmt_call), but writes shielded
+    /// values in the clear.
+    #[inline]
+    fn fmt_call_unredacted(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_call_with(f, false)
+    }
+
+    /// Formats the call, redacting shielded values if `redact` is set.
+    ///
+    /// This is the method to implement for [`fmt_call`](Self::fmt_call) and
+    /// [`fmt_call_unredacted`](Self::fmt_call_unredacted).
+    ///
+    /// The default implementation writes the arguments positionally; calls
+    /// generated with `#[sol(display)]` also write the parameter names. See
+    /// [`SolType::fmt_token`] for how values are formatted.
+    #[inline]
+    fn fmt_call_with(&self, f: &mut fmt::Formatter<'_>, redact: bool) -> fmt::Result {
+        f.write_str(utils::signature_name(Self::SIGNATURE))?;
+        <Self::Parameters<'_> as SolType>::fmt_token_with(&self.tokenize(), f, redact)
+    }
 }
 
 /// A Solidity constructor.
@@ -0,0 +1,309 @@
+use super::{SelectorSet, SolInterface};
+use crate::{Error, Result};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// The union of two [`SolInterface`]s.
+///
+/// This is used to decode data which may belong to any of several interfaces,
+/// for example the errors of a call that goes through a router, which may
+/// revert with errors from the router itself or from any of the contracts it
+/// calls.
+///
+/// Decoding is first-match-wins: `L` is tried first, then `R`. More than two
+/// interfaces can be combined by nesting, e.g. `Either<A, Either<B, C>>`.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_sol_types::{sol, ContractError, Either, SolError, SolInterface};
+///
+/// sol! {
+///     contract Router {
+///         error Expired(uint256 deadline);
+///     }
+///
+///     contract Pool {
+///         error InsufficientLiquidity();
+///     }
+/// }
+///
+/// type RouterError = ContractError<Either<Router::RouterErrors, Pool::PoolErrors>>;
+///
+/// let data = Pool::InsufficientLiquidity {}.abi_encode();
+/// let error = RouterError::abi_decode(&data)?;
+/// assert!(matches!(
+///     error,
+///     ContractError::CustomError(Either::Right(Pool::PoolErrors::InsufficientLiquidity(_)))
+/// ));
+/// # Ok::<(), alloy_sol_types::Error>(())
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Either<L, R> {
+    /// A value of the first interface.
+    Left(L),
+    /// A value of the second interface.
+    Right(R),
+}
+
+impl<L: fmt::Display, R: fmt::Display> fmt::Display for Either<L, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Left(left) => left.fmt(f),
+            Self::Right(right) => right.fmt(f),
+        }
+    }
+}
+
+impl<L, R> core::error::Error for Either<L, R>
+where
+    L: core::error::Error + 'static,
+    R: core::error::Error + 'static,
+{
+    #[inline]
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Left(left) => Some(left),
+            Self::Right(right) => Some(right),
+        }
+    }
+}
+
+impl<L: SolInterface, R: SolInterface> SolInterface for Either<L, R> {
+    const NAME: &'static str = "Either";
+
+    const MIN_DATA_LENGTH: usize = if L::MIN_DATA_LENGTH < R::MIN_DATA_LENGTH {
+        L::MIN_DATA_LENGTH
+    } else {
+        R::MIN_DATA_LENGTH
+    };
+
+    // Selectors shared by both interfaces are only counted once, as `L`'s.
+    const COUNT: usize = Self::SELECTOR_SET.len();
+
+    const SELECTOR_SET: SelectorSet = SelectorSet::Union(&L::SELECTOR_SET, &R::SELECTOR_SET);
+
+    #[inline]
+    fn selector(&self) -> [u8; 4] {
+        match self {
+            Self::Left(left) => left.selector(),
+            Self::Right(right) => right.selector(),
+        }
+    }
+
+    #[inline]
+    fn selector_at(i: usize) -> Option<[u8; 4]> {
+        if i < L::COUNT {
+            return L::selector_at(i);
+        }
+        R::selectors().filter(|&selector| !L::valid_selector(selector)).nth(i - L::COUNT)
+    }
+
+    #[inline]
+    fn valid_selector(selector: [u8; 4]) -> bool {
+        L::valid_selector(selector) || R::valid_selector(selector)
+    }
+
+    #[inline]
+    fn abi_decode_raw(selector: [u8; 4], data: &[u8]) -> Result<Self> {
+        decode_either(selector, data, L::abi_decode_raw, R::abi_decode_raw)
+    }
+
+    #[inline]
+    fn abi_decode_raw_validate(selector: [u8; 4], data: &[u8]) -> Result<Self> {
+        decode_either(selector, data, L::abi_decode_raw_validate, R::abi_decode_raw_validate)
+    }
+
+    #[inline]
+    fn abi_encoded_size(&self) -> usize {
+        match self {
+            Self::Left(left) => left.abi_encoded_size(),
+            Self::Right(right) => right.abi_encoded_size(),
+        }
+    }
+
+    #[inline]
+    fn abi_encode_raw(&self, out: &mut Vec<u8>) {
+        match self {
+            Self::Left(left) => left.abi_encode_raw(out),
+            Self::Right(right) => right.abi_encode_raw(out),
+        }
+    }
+
+    #[inline]
+    fn nested_revert_data(&self) -> Option<&[u8]> {
+        match self {
+            Self::Left(left) => left.nested_revert_data(),
+            Self::Right(right) => right.nested_revert_data(),
+        }
+    }
+}
+
+impl<L, R> Either<L, R> {
+    /// Returns `true` if `self` matches [`Left`](Self::Left).
+    #[inline]
+    pub const fn is_left(&self) -> bool {
+        matches!(self, Self::Left(_))
+    }
+
+    /// Returns an immutable reference to the inner value if `self` matches
+    /// [`Left`](Self::Left).
+    #[inline]
+    pub const fn as_left(&self) -> Option<&L> {
+        match self {
+            Self::Left(left) => Some(left),
+            Self::Right(_) => None,
+        }
+    }
+
+    /// Returns `true` if `self` matches [`Right`](Self::Right).
+    #[inline]
+    pub const fn is_right(&self) -> bool {
+        matches!(self, Self::Right(_))
+    }
+
+    /// Returns an immutable reference to the inner value if `self` matches
+    /// [`Right`](Self::Right).
+    #[inline]
+    pub const fn as_right(&self) -> Option<&R> {
+        match self {
+            Self::Left(_) => None,
+            Self::Right(right) => Some(right),
+        }
+    }
+}
+
+/// Decodes with `L` if it knows the selector, falling back to `R` if it does
+/// not, or if decoding with `L` fails.
+fn decode_either<L: SolInterface, R: SolInterface>(
+    selector: [u8; 4],
+    data: &[u8],
+    decode_left: fn([u8; 4], &[u8]) -> Result<L>,
+    decode_right: fn([u8; 4], &[u8]) -> Result<R>,
+) -> Result<Either<L, R>> {
+    if L::valid_selector(selector) {
+        match decode_left(selector, data) {
+            Ok(left) => return Ok(Either::Left(left)),
+            Err(e) if !R::valid_selector(selector) => return Err(e),
+            Err(_) => {}
+        }
+    } else if !R::valid_selector(selector) {
+        return Err(Error::unknown_selector(Either::<L, R>::NAME, selector));
+    }
+    decode_right(selector, data).map(Either::Right)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{sol, ContractError, SolError, MAX_NESTED_REVERT_DEPTH};
+    use alloy_primitives::{Bytes, U256};
+
+    sol! {
+        contract A {
+            error Shared(uint256 value);
+            error OnlyA();
+        }
+
+        contract B {
+            error Shared(uint256 value);
+            error OnlyB(string reason);
+            #[sol(nested_revert)]
+            error Wrapped(uint256 index, bytes inner);
+            #[sol(nested_revert)]
+            error Bubble(bytes);
+            error Data(bytes data);
+        }
+    }
+
+    type Errors = ContractError<Either<A::AErrors, B::BErrors>>;
+
+    #[test]
+    fn first_match_wins() {
+        let data = A::Shared { value: U256::from(1) }.abi_encode();
+        let error = Errors::abi_decode(&data).unwrap();
+        assert!(matches!(error, ContractError::CustomError(Either::Left(A::AErrors::Shared(_)))));
+        assert_eq!(error.abi_encode(), data);
+
+        let data = B::OnlyB { reason: "b".into() }.abi_encode();
+        let error = Errors::abi_decode_validate(&data).unwrap();
+        assert!(matches!(error, ContractError::CustomError(Either::Right(B::BErrors::OnlyB(_)))));
+        assert_eq!(error.abi_encode(), data);
+
+        let data = crate::Revert::from("revert").abi_encode();
+        assert!(Errors::abi_decode(&data).unwrap().is_revert());
+
+        let unknown = [0xde, 0xad, 0xbe, 0xef];
+        assert!(!Either::<A::AErrors, B::BErrors>::valid_selector(unknown));
+        assert!(Errors::abi_decode(&[&unknown[..], &[0; 32]].concat()).is_err());
+    }
+
+    #[test]
+    fn selectors() {
+        type Union = Either<A::AErrors, B::BErrors>;
+        // `Shared` is in both interfaces, but is only counted once.
+        assert_eq!(Union::COUNT, 6);
+        assert_eq!(Union::selectors().count(), 6);
+        assert_eq!(Union::selectors().collect::<Vec<_>>().len(), 6);
+        assert!(Union::selectors().all(Union::valid_selector));
+        let mut selectors = Union::selectors().collect::<Vec<_>>();
+        selectors.sort_unstable();
+        selectors.dedup();
+        assert_eq!(selectors.len(), 6);
+        assert_eq!(Union::selector_at(6), None);
+
+        type Nested = Either<A::AErrors, Either<B::BErrors, A::AErrors>>;
+        assert_eq!(Nested::COUNT, 6);
+        assert_eq!(Nested::selectors().collect::<Vec<_>>(), Union::selectors().collect::<Vec<_>>());
+        assert_eq!(Either::<A::AErrors, A::AErrors>::COUNT, A::AErrors::COUNT);
+        assert_eq!(ContractError::<Union>::SELECTOR_SET.len(), ContractError::<Union>::COUNT);
+    }
+
+    #[test]
+    fn decode_chain() {
+        let inner = A::OnlyA {}.abi_encode();
+        let middle = B::Wrapped { index: U256::from(2), inner: inner.into() }.abi_encode();
+        let outer = B::Wrapped { index: U256::from(1), inner: middle.into() }.abi_encode();
+
+        let chain = Errors::abi_decode_chain(&outer).unwrap();
+        assert_eq!(chain.len(), 3);
+        let indices = chain[..2].iter().map(|error| match error {
+            ContractError::CustomError(Either::Right(B::BErrors::Wrapped(w))) => w.index,
+            _ => panic!("unexpected error"),
+        });
+        assert!(indices.eq([U256::from(1), U256::from(2)]));
+        assert!(matches!(chain[2], ContractError::CustomError(Either::Left(A::AErrors::OnlyA(_)))));
+        assert_eq!(chain[2].nested_revert_data(), None);
+
+        // Undecodable nested data ends the chain.
+        let garbage = Bytes::from_static(b"not an error");
+        let data = B::Wrapped { index: U256::ZERO, inner: garbage.clone() }.abi_encode();
+        let chain = Errors::abi_decode_chain(&data).unwrap();
+        assert_eq!(chain.len(), 1);
+        assert_eq!(chain[0].nested_revert_data(), Some(&garbage[..]));
+
+        let data = B::Bubble(A::OnlyA {}.abi_encode().into()).abi_encode();
+        let chain = Errors::abi_decode_chain(&data).unwrap();
+        assert_eq!(chain.len(), 2);
+        assert!(matches!(chain[1], ContractError::CustomError(Either::Left(A::AErrors::OnlyA(_)))));
+
+        assert!(Errors::abi_decode_chain(&garbage).is_err());
+
+        // Only errors marked with `#[sol(nested_revert)]` wrap revert data.
+        let data = B::Data { data: A::OnlyA {}.abi_encode().into() }.abi_encode();
+        let chain = Errors::abi_decode_chain(&data).unwrap();
+        assert_eq!(chain.len(), 1);
+        assert_eq!(chain[0].nested_revert_data(), None);
+    }
+
+    #[test]
+    fn decode_chain_depth() {
+        let mut data = A::OnlyA {}.abi_encode();
+        for _ in 0..MAX_NESTED_REVERT_DEPTH + 4 {
+            data = B::Bubble(data.into()).abi_encode();
+        }
+        let chain = Errors::abi_decode_chain(&data).unwrap();
+        assert_eq!(chain.len(), MAX_NESTED_REVERT_DEPTH + 1);
+        assert!(chain.iter().all(|error| error.nested_revert_data().is_some()));
+    }
+}
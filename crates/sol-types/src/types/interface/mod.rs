@@ -2,9 +2,16 @@ use crate::{alloc::string::ToString, Error, Panic, Result, Revert, SolError};
 use alloc::{string::String, vec::Vec};
 use core::{convert::Infallible, fmt, iter::FusedIterator, marker::PhantomData};
 
+mod either;
+pub use either::Either;
+
 mod event;
 pub use event::SolEventInterface;
 
+/// The maximum number of levels of nested revert data decoded by
+/// [`SolInterface::abi_decode_chain`].
+pub const MAX_NESTED_REVERT_DEPTH: usize = 16;
+
 /// A collection of ABI-encodable call-like types. This currently includes
 /// [`SolCall`] and [`SolError`].
 ///
@@ -34,6 +41,13 @@ pub trait SolInterface: Sized {
     /// The number of variants.
     const COUNT: usize;
 
+    /// The selectors of this type, in the order of
+    /// [`selector_at`](Self::selector_at).
+    ///
+    /// This allows selectors to be compared at compile time, e.g. by [`Either`]
+    /// to count the selectors shared by two interfaces only once.
+    const SELECTOR_SET: SelectorSet;
+
     /// The selector of this instance.
     fn selector(&self) -> [u8; 4];
 
@@ -110,6 +124,41 @@ pub trait SolInterface: Sized {
             Self::abi_decode_raw_validate(*selector, data)
         }
     }
+
+    /// Returns the revert data wrapped by this instance, if any.
+    ///
+    /// See [`SolError::nested_revert_data`] for more details.
+    #[inline]
+    fn nested_revert_data(&self) -> Option<&[u8]> {
+        None
+    }
+
+    /// ABI-decodes the given revert data into a chain of errors, from the
+    /// outermost to the innermost.
+    ///
+    /// After decoding the outermost error, its
+    /// [nested revert data](Self::nested_revert_data) is decoded recursively,
+    /// until an error does not wrap any revert data, or its revert data cannot
+    /// be decoded. In the latter case, the raw data is still accessible through
+    /// the last error in the chain.
+    ///
+    /// At most [`MAX_NESTED_REVERT_DEPTH`] levels of nested revert data are
+    /// decoded, so the chain contains at most `MAX_NESTED_REVERT_DEPTH + 1`
+    /// errors.
+    ///
+    /// Returns an error only if the outermost error cannot be decoded.
+    fn abi_decode_chain(data: &[u8]) -> Result<Vec<Self>> {
+        let mut chain = alloc::vec![Self::abi_decode(data)?];
+        while let Some(next) = chain
+            .last()
+            .filter(|_| chain.len() <= MAX_NESTED_REVERT_DEPTH)
+            .and_then(Self::nested_revert_data)
+            .and_then(|data| Self::abi_decode(data).ok())
+        {
+            chain.push(next);
+        }
+        Ok(chain)
+    }
 }
 
 /// An empty [`SolInterface`] implementation. Used by [`GenericContractError`].
@@ -120,6 +169,7 @@ impl SolInterface for Infallible {
     // no selectors or data are valid
     const MIN_DATA_LENGTH: usize = usize::MAX;
     const COUNT: usize = 0;
+    const SELECTOR_SET: SelectorSet = SelectorSet::List(&[]);
 
     #[inline]
     fn selector(&self) -> [u8; 4] {
@@ -252,6 +302,11 @@ impl<T: SolInterface> SolInterface for ContractError<T> {
 
     const COUNT: usize = T::COUNT + 2;
 
+    const SELECTOR_SET: SelectorSet = SelectorSet::Union(
+        &T::SELECTOR_SET,
+        &SelectorSet::List(&[Revert::SELECTOR, Panic::SELECTOR]),
+    );
+
     #[inline]
     fn selector(&self) -> [u8; 4] {
         match self {
@@ -319,6 +374,14 @@ impl<T: SolInterface> SolInterface for ContractError<T> {
             Self::Revert(revert) => revert.abi_encode_raw(out),
         }
     }
+
+    #[inline]
+    fn nested_revert_data(&self) -> Option<&[u8]> {
+        match self {
+            Self::CustomError(error) => error.nested_revert_data(),
+            Self::Panic(_) | Self::Revert(_) => None,
+        }
+    }
 }
 
 impl<T> ContractError<T> {
@@ -523,6 +586,90 @@ impl<T> RevertReason<T> {
     }
 }
 
+/// The selectors of a [`SolInterface`] type, known at compile time.
+///
+/// See [`SolInterface::SELECTOR_SET`].
+#[derive(Clone, Copy, Debug)]
+pub enum SelectorSet {
+    /// Distinct selectors.
+    List(&'static [[u8; 4]]),
+    /// The selectors of the first set, followed by the selectors of the second
+    /// set which are not in the first one.
+    Union(&'static Self, &'static Self),
+}
+
+impl SelectorSet {
+    /// Returns the number of selectors in the set.
+    pub const fn len(&self) -> usize {
+        match *self {
+            Self::List(list) => list.len(),
+            Self::Union(first, second) => {
+                let mut len = first.len();
+                let mut i = 0;
+                while let Some(selector) = second.get(i) {
+                    if !first.contains(selector) {
+                        len += 1;
+                    }
+                    i += 1;
+                }
+                len
+            }
+        }
+    }
+
+    /// Returns `true` if the set contains no selectors.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the selector at the given index.
+    pub const fn get(&self, index: usize) -> Option<[u8; 4]> {
+        match *self {
+            Self::List(list) => {
+                if index < list.len() {
+                    Some(list[index])
+                } else {
+                    None
+                }
+            }
+            Self::Union(first, second) => {
+                if let Some(selector) = first.get(index) {
+                    return Some(selector);
+                }
+                let mut remaining = index - first.len();
+                let mut i = 0;
+                while let Some(selector) = second.get(i) {
+                    if !first.contains(selector) {
+                        if remaining == 0 {
+                            return Some(selector);
+                        }
+                        remaining -= 1;
+                    }
+                    i += 1;
+                }
+                None
+            }
+        }
+    }
+
+    /// Returns `true` if the set contains the given selector.
+    pub const fn contains(&self, selector: [u8; 4]) -> bool {
+        match *self {
+            Self::List(list) => {
+                let mut i = 0;
+                while i < list.len() {
+                    if u32::from_be_bytes(list[i]) == u32::from_be_bytes(selector) {
+                        return true;
+                    }
+                    i += 1;
+                }
+                false
+            }
+            Self::Union(first, second) => first.contains(selector) || second.contains(selector),
+        }
+    }
+}
+
 /// Iterator over the function or error selectors of a [`SolInterface`] type.
 ///
 /// This `struct` is created by the [`selectors`] method on [`SolInterface`].
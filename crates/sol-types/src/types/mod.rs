@@ -7,15 +7,15 @@ mod error;
 pub use error::{decode_revert_reason, Panic, PanicKind, Revert, SolError};
 
 mod event;
-pub use event::{EventTopic, SolEvent, TopicList};
+pub use event::{EventTopic, LogFilterExt, SolEvent, TopicList};
 
 mod function;
 pub use function::{SolCall, SolConstructor};
 
 mod interface;
 pub use interface::{
-    ContractError, GenericContractError, GenericRevertReason, RevertReason, Selectors,
-    SolEventInterface, SolInterface,
+    ContractError, Either, GenericContractError, GenericRevertReason, RevertReason, SelectorSet,
+    Selectors, SolEventInterface, SolInterface, MAX_NESTED_REVERT_DEPTH,
 };
 
 mod r#struct;
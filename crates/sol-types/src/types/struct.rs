@@ -2,9 +2,11 @@
 //! Solidity structs logic, particularly for EIP-712 encoding/decoding.
 
 use super::SolType;
-use crate::Eip712Domain;
+use crate::{Eip712Domain, Eip712ShieldedPolicy};
 use alloc::{borrow::Cow, string::String, vec::Vec};
 use alloy_primitives::{keccak256, B256};
+#[cfg(feature = "k256")]
+use alloy_primitives::{Signature, SignatureError};
 
 /// A Solidity struct.
 ///
@@ -81,9 +83,36 @@ pub trait SolStruct: SolType<RustType = Self> {
         keccak256(Self::eip712_encode_type().as_bytes())
     }
 
+    /// The EIP-712-encoded type string, with shielded members named according
+    /// to the given [policy](Eip712ShieldedPolicy).
+    #[inline]
+    fn eip712_encode_type_with_policy(policy: Eip712ShieldedPolicy) -> Cow<'static, str> {
+        match policy {
+            Eip712ShieldedPolicy::Shielded => Self::eip712_encode_type(),
+            Eip712ShieldedPolicy::Public => {
+                Cow::Owned(policy.encode_type(&Self::eip712_encode_type()).into_owned())
+            }
+        }
+    }
+
+    /// Calculates the EIP-712 `typeHash` for this struct, with shielded
+    /// members named according to the given [policy](Eip712ShieldedPolicy).
+    #[inline]
+    fn eip712_type_hash_with_policy(&self, policy: Eip712ShieldedPolicy) -> B256 {
+        keccak256(Self::eip712_encode_type_with_policy(policy).as_bytes())
+    }
+
     /// Encodes this domain using [EIP-712 `encodeData`](https://eips.ethereum.org/EIPS/eip-712#definition-of-encodedata).
     fn eip712_encode_data(&self) -> Vec<u8>;
 
+    /// Encodes this struct using EIP-712 `encodeData`, hashing nested structs
+    /// with the given [shielded policy](Eip712ShieldedPolicy).
+    ///
+    /// This is the same as [`eip712_encode_data`](Self::eip712_encode_data),
+    /// except that nested structs are hashed with
+    /// [`eip712_hash_struct_with_policy`](Self::eip712_hash_struct_with_policy).
+    fn eip712_encode_data_with_policy(&self, policy: Eip712ShieldedPolicy) -> Vec<u8>;
+
     /// Calculates the EIP-712 [`hashStruct`] for this value.
     ///
     /// [`hashStruct`]: https://eips.ethereum.org/EIPS/eip-712#definition-of-hashstruct
@@ -95,6 +124,16 @@ pub trait SolStruct: SolType<RustType = Self> {
         hasher.finalize()
     }
 
+    /// Calculates the EIP-712 `hashStruct` for this value, with shielded
+    /// members named according to the given [policy](Eip712ShieldedPolicy).
+    #[inline]
+    fn eip712_hash_struct_with_policy(&self, policy: Eip712ShieldedPolicy) -> B256 {
+        let mut hasher = alloy_primitives::Keccak256::new();
+        hasher.update(self.eip712_type_hash_with_policy(policy));
+        hasher.update(self.eip712_encode_data_with_policy(policy));
+        hasher.finalize()
+    }
+
     /// Calculate the [EIP-712 signing hash](https://eips.ethereum.org/EIPS/eip-712#specification-of-the-eth_signtypeddata-json-rpc)
     /// for this struct.
     /// Note that this does not **sign** the hash, only calculates it.
@@ -112,4 +151,38 @@ pub trait SolStruct: SolType<RustType = Self> {
         digest_input[34..66].copy_from_slice(&self.eip712_hash_struct()[..]);
         keccak256(digest_input)
     }
+
+    /// Calculate the EIP-712 signing hash for this struct, with shielded
+    /// members named according to the given [policy](Eip712ShieldedPolicy).
+    ///
+    /// See [`eip712_signing_hash`](SolStruct::eip712_signing_hash).
+    fn eip712_signing_hash_with_policy(
+        &self,
+        domain: &Eip712Domain,
+        policy: Eip712ShieldedPolicy,
+    ) -> B256 {
+        let mut digest_input = [0u8; 2 + 32 + 32];
+        digest_input[0] = 0x19;
+        digest_input[1] = 0x01;
+        digest_input[2..34].copy_from_slice(&domain.hash_struct()[..]);
+        digest_input[34..66].copy_from_slice(&self.eip712_hash_struct_with_policy(policy)[..]);
+        keccak256(digest_input)
+    }
+
+    /// Signs this struct as an [EIP-712] typed data message in the given
+    /// domain.
+    ///
+    /// This signs the [`eip712_signing_hash`](SolStruct::eip712_signing_hash)
+    /// with [`Signature::sign_typed_data`].
+    ///
+    /// [EIP-712]: https://eips.ethereum.org/EIPS/eip-712
+    #[cfg(feature = "k256")]
+    #[inline]
+    fn sign_typed_data(
+        &self,
+        domain: &Eip712Domain,
+        key: &k256::ecdsa::SigningKey,
+    ) -> Result<Signature, SignatureError> {
+        Signature::sign_typed_data(key, domain.hash_struct(), self.eip712_hash_struct())
+    }
 }
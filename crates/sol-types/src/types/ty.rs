@@ -1,9 +1,10 @@
 use crate::{
     abi::{self, Token, TokenSeq},
     private::SolTypeValue,
-    Result, Word,
+    Eip712ShieldedPolicy, Result, Word,
 };
 use alloc::{borrow::Cow, vec::Vec};
+use core::fmt;
 
 /// A Solidity type.
 ///
@@ -177,6 +178,90 @@ pub trait SolType: Sized {
         rust.stv_eip712_data_word()
     }
 
+    /// Encode this data according to EIP-712 `encodeData` rules, hashing
+    /// nested structs with the given [shielded policy](Eip712ShieldedPolicy).
+    ///
+    /// This is the same as [`eip712_data_word`](SolType::eip712_data_word)
+    /// for all types except structs and arrays of structs.
+    #[inline]
+    fn eip712_data_word_with_policy<E: ?Sized + SolTypeValue<Self>>(
+        rust: &E,
+        policy: Eip712ShieldedPolicy,
+    ) -> Word {
+        rust.stv_eip712_data_word_with_policy(policy)
+    }
+
+    /// Returns the EIP-712 root types of all the structs referenced by this
+    /// type, including itself, in no particular order.
+    ///
+    /// This is used to build the `encodeType` string of structs that contain
+    /// this type. It is empty for all types except structs and arrays of
+    /// structs.
+    ///
+    /// See [`SolStruct::eip712_encode_type`](crate::SolStruct::eip712_encode_type).
+    #[inline]
+    fn eip712_referenced_types() -> Vec<Cow<'static, str>> {
+        Vec::new()
+    }
+
+    /// Formats the given token in a human-readable, Solidity-like way.
+    ///
+    /// Addresses are checksummed, integers are written in decimal, bytes in
+    /// hex, strings quoted, and structs by name with their named fields, e.g.
+    /// `Person(name: "Alice", wallet: 0xAbC...)`.
+    ///
+    /// Shielded values are always redacted, whatever the formatter's flags. Use
+    /// [`fmt_token_unredacted`](Self::fmt_token_unredacted) to write them.
+    #[inline]
+    fn fmt_token(token: &Self::Token<'_>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Self::fmt_token_with(token, f, true)
+    }
+
+    /// Formats the given token like [`fmt_token`](Self::fmt_token), but writes
+    /// shielded values in the clear.
+    #[inline]
+    fn fmt_token_unredacted(token: &Self::Token<'_>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Self::fmt_token_with(token, f, false)
+    }
+
+    /// Formats the given token, redacting shielded values if `redact` is set.
+    ///
+    /// This is the method to implement for [`fmt_token`](Self::fmt_token) and
+    /// [`fmt_token_unredacted`](Self::fmt_token_unredacted). Types which contain
+    /// other types must pass `redact` on to them.
+    ///
+    /// The default implementation only writes the type's [`SOL_NAME`](Self::SOL_NAME).
+    #[inline]
+    fn fmt_token_with(
+        token: &Self::Token<'_>,
+        f: &mut fmt::Formatter<'_>,
+        redact: bool,
+    ) -> fmt::Result {
+        let _ = (token, redact);
+        write!(f, "<{}>", Self::SOL_NAME)
+    }
+
+    /// Formats the given value in a human-readable, Solidity-like way.
+    ///
+    /// See [`fmt_token`](Self::fmt_token) for more details.
+    #[inline]
+    fn fmt_value<E: ?Sized + SolTypeValue<Self>>(
+        rust: &E,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        Self::fmt_token(&rust.stv_to_tokens(), f)
+    }
+
+    /// Formats the given value like [`fmt_value`](Self::fmt_value), but writes
+    /// shielded values in the clear.
+    #[inline]
+    fn fmt_value_unredacted<E: ?Sized + SolTypeValue<Self>>(
+        rust: &E,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        Self::fmt_token_unredacted(&rust.stv_to_tokens(), f)
+    }
+
     /// Returns the length of this value when ABI-encoded in Non-standard Packed Mode.
     ///
     /// See [`abi_encode_packed`][SolType::abi_encode_packed] for more details.
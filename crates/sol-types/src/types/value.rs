@@ -255,6 +255,9 @@ impl_sol_value! {
     [] I248 => sol_data::Int::<248> [];
     [] I256 => sol_data::Int::<256> [];
 
+    [const N: usize] alloy_primitives::Fixed<256, 4, N> => sol_data::Fixed<256, N> [];
+    [const N: usize] alloy_primitives::UFixed<256, 4, N> => sol_data::Ufixed<256, N> [];
+
     // TODO: `u8` is specialized to encode as `bytes` or `bytesN`
     // [] u8 => sol_data::Uint::<8> [];
     []  u16 => sol_data::Uint::<16> [];
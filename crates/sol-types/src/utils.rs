@@ -59,6 +59,56 @@ pub const fn just_ok<T>(_: &T) -> crate::Result<()> {
     Ok(())
 }
 
+/// Formats the name of a `fixedMxN` or `ufixedMxN` type in a const context.
+///
+/// Returns the name's bytes and length. Panics if `n > 80`.
+pub(crate) const fn fixed_name(signed: bool, m: usize, n: usize) -> ([u8; 12], usize) {
+    assert!(n <= 80, "fixed-point types have at most 80 decimals");
+    let mut buf = [0; 12];
+    let mut len = 0;
+    if !signed {
+        buf[0] = b'u';
+        len = 1;
+    }
+    let mut i = 0;
+    while i < 5 {
+        buf[len] = b"fixed"[i];
+        len += 1;
+        i += 1;
+    }
+    (buf, len) = push_decimal(buf, len, m);
+    buf[len] = b'x';
+    (buf, len) = push_decimal(buf, len + 1, n);
+    (buf, len)
+}
+
+const fn push_decimal(mut buf: [u8; 12], mut len: usize, x: usize) -> ([u8; 12], usize) {
+    if x >= 100 {
+        buf[len] = b'0' + (x / 100) as u8;
+        len += 1;
+    }
+    if x >= 10 {
+        buf[len] = b'0' + (x / 10 % 10) as u8;
+        len += 1;
+    }
+    buf[len] = b'0' + (x % 10) as u8;
+    (buf, len + 1)
+}
+
+/// [`core::str::from_utf8`] in a const context, for ASCII type names.
+pub(crate) const fn name_str(bytes: &[u8]) -> &str {
+    match core::str::from_utf8(bytes) {
+        Ok(s) => s,
+        Err(_) => panic!("invalid type name"),
+    }
+}
+
+/// Returns the name part of a function, error or event signature.
+#[inline]
+pub(crate) fn signature_name(signature: &str) -> &str {
+    signature.split_once('(').map_or(signature, |(name, _)| name)
+}
+
 #[inline]
 pub(crate) fn check_zeroes(data: &[u8]) -> bool {
     data.iter().all(|b| *b == 0)
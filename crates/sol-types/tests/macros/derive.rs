@@ -0,0 +1,169 @@
+use alloy_primitives::{aliases::U96, Address, Bytes, U256};
+use alloy_sol_types::{eip712_domain, SolEnum, SolStruct, SolType, SolValue};
+
+#[derive(Clone, Debug, PartialEq, SolType, SolValue)]
+struct Person {
+    name: String,
+    wallet: Address,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, SolType, SolValue)]
+enum Status {
+    #[sol(fallback)]
+    Pending,
+    Filled,
+    Cancelled,
+}
+
+#[derive(Clone, Debug, PartialEq, SolType, SolValue)]
+#[sol(rename = "Order", rename_all = "camelCase")]
+struct RustOrder {
+    maker: Person,
+    takers: Vec<Person>,
+    #[sol(ty = "uint96")]
+    amount: U96,
+    #[sol(rename = "memo")]
+    data: Bytes,
+    #[sol(ty = "uint8[]")]
+    fee_bps: Vec<u8>,
+    status: Status,
+}
+
+mod expected {
+    alloy_sol_types::sol! {
+        struct Person {
+            string name;
+            address wallet;
+        }
+
+        enum Status {
+            Pending,
+            Filled,
+            Cancelled,
+        }
+
+        struct Order {
+            Person maker;
+            Person[] takers;
+            uint96 amount;
+            bytes memo;
+            uint8[] feeBps;
+            Status status;
+        }
+    }
+}
+
+fn person(name: &str, byte: u8) -> Person {
+    Person { name: name.into(), wallet: Address::repeat_byte(byte) }
+}
+
+fn expected_person(person: &Person) -> expected::Person {
+    expected::Person { name: person.name.clone(), wallet: person.wallet }
+}
+
+#[test]
+fn derive_struct() {
+    let order = RustOrder {
+        maker: person("Alice", 0xaa),
+        takers: vec![person("Bob", 0xbb), person("Carol", 0xcc)],
+        amount: U96::from(1_000_000u64),
+        data: Bytes::from_static(b"hello"),
+        fee_bps: vec![5, 30],
+        status: Status::Filled,
+    };
+    let expected = expected::Order {
+        maker: expected_person(&order.maker),
+        takers: order.takers.iter().map(expected_person).collect(),
+        amount: order.amount,
+        memo: order.data.clone(),
+        feeBps: order.fee_bps.clone(),
+        status: expected::Status::Filled,
+    };
+
+    assert_eq!(RustOrder::SOL_NAME, "Order");
+    assert_eq!(<(RustOrder, Status) as SolType>::SOL_NAME, "(Order,uint8)");
+
+    assert_eq!(RustOrder::eip712_root_type(), expected::Order::eip712_root_type());
+    assert_eq!(RustOrder::eip712_encode_type(), expected::Order::eip712_encode_type());
+    assert_eq!(
+        RustOrder::eip712_encode_type(),
+        "Order(Person maker,Person[] takers,uint96 amount,bytes memo,uint8[] feeBps,uint8 status)\
+         Person(string name,address wallet)"
+    );
+    assert_eq!(RustOrder::eip712_type_hash(&order), expected.eip712_type_hash());
+    assert_eq!(order.eip712_hash_struct(), expected.eip712_hash_struct());
+
+    let domain = eip712_domain! {
+        name: "Derive",
+        version: "1",
+        chain_id: 1,
+    };
+    assert_eq!(order.eip712_signing_hash(&domain), expected.eip712_signing_hash(&domain));
+
+    let encoded = order.abi_encode();
+    assert_eq!(encoded, expected.abi_encode());
+    assert_eq!(order.abi_encode_packed(), expected.abi_encode_packed());
+    assert_eq!(<RustOrder as SolType>::abi_decode(&encoded).unwrap(), order);
+    assert_eq!(<RustOrder as SolValue>::abi_decode(&encoded).unwrap(), order);
+    assert_eq!(
+        <RustOrder as alloy_sol_types::EventTopic>::encode_topic(&order),
+        <expected::Order as alloy_sol_types::EventTopic>::encode_topic(&expected)
+    );
+}
+
+#[test]
+fn derive_enum() {
+    assert_eq!(Status::COUNT, 3);
+    assert_eq!(Status::SOL_NAME, "uint8");
+    assert_eq!(u8::from(Status::Cancelled), 2);
+    assert_eq!(Status::try_from(1u8).unwrap(), Status::Filled);
+    assert!(Status::try_from(3u8).is_err());
+
+    let encoded = Status::Cancelled.abi_encode();
+    assert_eq!(encoded, expected::Status::Cancelled.abi_encode());
+    assert_eq!(<Status as SolType>::abi_decode(&encoded).unwrap(), Status::Cancelled);
+
+    let invalid = U256::from(3).abi_encode();
+    assert!(<Status as SolType>::abi_decode_validate(&invalid).is_err());
+    assert_eq!(<Status as SolType>::abi_decode(&invalid).unwrap(), Status::Pending);
+}
+
+#[cfg(feature = "seismic")]
+#[test]
+fn derive_shielded() {
+    use alloy_primitives::{aliases::SUInt, SAddress};
+
+    #[derive(Clone, Debug, PartialEq, SolType, SolValue)]
+    struct Shielded {
+        owner: SAddress,
+        balance: SUInt<256, 4>,
+        public: Address,
+    }
+
+    mod expected {
+        alloy_sol_types::sol! {
+            struct Shielded {
+                saddress owner;
+                suint256 balance;
+                address public;
+            }
+        }
+    }
+
+    let value = Shielded {
+        owner: SAddress(Address::repeat_byte(0x11)),
+        balance: SUInt(U256::from(42)),
+        public: Address::repeat_byte(0x22),
+    };
+    let expected =
+        expected::Shielded { owner: value.owner, balance: value.balance, public: value.public };
+
+    assert_eq!(
+        Shielded::eip712_encode_type(),
+        "Shielded(saddress owner,suint256 balance,address public)"
+    );
+    assert_eq!(Shielded::eip712_encode_type(), expected::Shielded::eip712_encode_type());
+    assert_eq!(value.eip712_hash_struct(), expected.eip712_hash_struct());
+    assert_eq!(value.abi_encode(), expected.abi_encode());
+    assert_eq!(<Shielded as SolType>::abi_decode(&value.abi_encode()).unwrap(), value);
+}
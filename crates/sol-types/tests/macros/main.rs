@@ -1,4 +1,5 @@
 #![allow(clippy::missing_const_for_fn)]
 #![allow(missing_docs)]
 
+mod derive;
 mod sol;
@@ -63,3 +63,134 @@ fn encode_data_nesting() {
         "25c3d40a39e639a4d0b6e4d2ace5e1281e039c88494d97d8d08f99a6ea75d775".parse::<B256>().unwrap()
     )
 }
+
+#[test]
+#[cfg(feature = "k256")]
+fn sign_typed_data() {
+    use alloy_primitives::{address, keccak256, Address, Signature, U256};
+
+    sol! {
+        struct Person {
+            string name;
+            address wallet;
+        }
+
+        struct Mail {
+            Person from;
+            Person to;
+            string contents;
+        }
+    }
+
+    // Mail example from EIP-712.
+    let domain = eip712_domain! {
+        name: "Ether Mail",
+        version: "1",
+        chain_id: 1,
+        verifying_contract: address!("0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"),
+    };
+    let mail = Mail {
+        from: Person {
+            name: "Cow".to_owned(),
+            wallet: address!("0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"),
+        },
+        to: Person {
+            name: "Bob".to_owned(),
+            wallet: address!("0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB"),
+        },
+        contents: "Hello, Bob!".to_owned(),
+    };
+
+    let key = k256::ecdsa::SigningKey::from_slice(keccak256("cow").as_slice()).unwrap();
+    assert_eq!(Address::from_private_key(&key), mail.from.wallet);
+
+    let sig = mail.sign_typed_data(&domain, &key).unwrap();
+    let expected = Signature::new(
+        "0x4355c47d63924e8a72e509b65029052eb6c299d53a04e167c5775fd466751c9d"
+            .parse::<U256>()
+            .unwrap(),
+        "0x07299936d304c153f6443dfa05f40ff007d72911b6f72307f996231605b91562"
+            .parse::<U256>()
+            .unwrap(),
+        true,
+    );
+    assert_eq!(sig, expected);
+    assert!(sig.verify(&mail.eip712_signing_hash(&domain), &mail.from.wallet));
+}
+
+#[cfg(feature = "seismic")]
+#[test]
+fn shielded_policy() {
+    use alloy_primitives::{aliases::SUInt, Address, SAddress, U256};
+    use alloy_sol_types::{sol_data::Sbool, Eip712ShieldedPolicy};
+
+    sol! {
+        struct Vote {
+            saddress voter;
+            sbool support;
+        }
+
+        struct Ballot {
+            suint256 id;
+            Vote[] votes;
+        }
+    }
+
+    mod public {
+        alloy_sol_types::sol! {
+            struct Vote {
+                address voter;
+                bool support;
+            }
+
+            struct Ballot {
+                uint256 id;
+                Vote[] votes;
+            }
+        }
+    }
+
+    let ballot = Ballot {
+        id: SUInt(U256::from(7)),
+        votes: vec![Vote { voter: SAddress(Address::repeat_byte(0x11)), support: Sbool(true) }],
+    };
+    let public = public::Ballot {
+        id: U256::from(7),
+        votes: vec![public::Vote { voter: Address::repeat_byte(0x11), support: true }],
+    };
+    let domain = eip712_domain! { name: "Ballots", };
+
+    // The public policy is checked against the equivalent public structs, not
+    // against wallet-provided vectors.
+    assert_eq!(
+        Ballot::eip712_encode_type(),
+        "Ballot(suint256 id,Vote[] votes)Vote(saddress voter,sbool support)"
+    );
+    assert_eq!(
+        Ballot::eip712_encode_type_with_policy(Eip712ShieldedPolicy::Shielded),
+        Ballot::eip712_encode_type()
+    );
+    assert_eq!(
+        Ballot::eip712_encode_type_with_policy(Eip712ShieldedPolicy::Public),
+        public::Ballot::eip712_encode_type()
+    );
+
+    // Shielded values are encoded like their public counterparts, but nested
+    // struct hashes depend on the policy.
+    assert_eq!(ballot.votes[0].eip712_encode_data(), public.votes[0].eip712_encode_data());
+    assert_ne!(ballot.eip712_encode_data(), public.eip712_encode_data());
+    assert_eq!(
+        ballot.eip712_encode_data_with_policy(Eip712ShieldedPolicy::Public),
+        public.eip712_encode_data()
+    );
+
+    assert_eq!(
+        ballot.eip712_signing_hash_with_policy(&domain, Eip712ShieldedPolicy::Shielded),
+        ballot.eip712_signing_hash(&domain)
+    );
+    assert_ne!(ballot.eip712_signing_hash(&domain), public.eip712_signing_hash(&domain));
+    assert_eq!(
+        ballot.eip712_signing_hash_with_policy(&domain, Eip712ShieldedPolicy::Public),
+        public.eip712_signing_hash(&domain)
+    );
+}
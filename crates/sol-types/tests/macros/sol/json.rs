@@ -1,6 +1,6 @@
 use alloy_json_abi::{Function, JsonAbi, Param, StateMutability};
-use alloy_primitives::{Address, Signed, B256, I256, U256};
-use alloy_sol_types::{sol, SolCall, SolError, SolEvent, SolStruct};
+use alloy_primitives::{address, b256, Address, Signed, B256, I256, U256};
+use alloy_sol_types::{eip712_domain, sol, SolCall, SolError, SolEvent, SolStruct};
 use pretty_assertions::assert_eq;
 use std::borrow::Cow;
 
@@ -283,3 +283,92 @@ fn ignore_unlinked_bytecode_attr() {
 
     let _ = AnotherUnlinked::addCall { a: U256::ZERO, b: U256::ZERO };
 }
+
+#[test]
+fn eip712_types() {
+    // `eth_signTypedData_v4` example from `eth-sig-util`.
+    sol!(
+        #[derive(Debug)]
+        MailTypes,
+        r#"{
+            "types": {
+                "EIP712Domain": [
+                    { "name": "name", "type": "string" },
+                    { "name": "version", "type": "string" },
+                    { "name": "chainId", "type": "uint256" },
+                    { "name": "verifyingContract", "type": "address" }
+                ],
+                "Group": [
+                    { "name": "name", "type": "string" },
+                    { "name": "members", "type": "Person[]" }
+                ],
+                "Person": [
+                    { "name": "name", "type": "string" },
+                    { "name": "wallets", "type": "address[]" }
+                ],
+                "Mail": [
+                    { "name": "from", "type": "Person" },
+                    { "name": "to", "type": "Person[]" },
+                    { "name": "contents", "type": "string" }
+                ]
+            },
+            "primaryType": "Mail",
+            "domain": {},
+            "message": {}
+        }"#
+    );
+    use MailTypes::{Group, Mail, Person};
+
+    assert_eq!(
+        Mail::eip712_encode_type(),
+        "Mail(Person from,Person[] to,string contents)Person(string name,address[] wallets)"
+    );
+    assert_eq!(
+        Group::eip712_encode_type(),
+        "Group(string name,Person[] members)Person(string name,address[] wallets)"
+    );
+
+    let mail = Mail {
+        from: Person {
+            name: "Cow".into(),
+            wallets: vec![
+                address!("0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"),
+                address!("0xDeaDbeefdEAdbeefdEadbEEFdeadbeEFdEaDbeeF"),
+            ],
+        },
+        to: vec![Person {
+            name: "Bob".into(),
+            wallets: vec![
+                address!("0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB"),
+                address!("0xB0BdaBea57B0BDABeA57b0bdABEA57b0BDabEa57"),
+                address!("0xB0B0b0b0b0b0B000000000000000000000000000"),
+            ],
+        }],
+        contents: "Hello, Bob!".into(),
+    };
+    let domain = eip712_domain! {
+        name: "Ether Mail",
+        version: "1",
+        chain_id: 1,
+        verifying_contract: address!("0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"),
+    };
+    assert_eq!(
+        mail.eip712_signing_hash(&domain),
+        b256!("0xa85c2e2b118698e88db68a8105b794a8cc7cec074e89ef991cb4f5f533819cc2")
+    );
+}
+
+#[test]
+fn eip712_types_only() {
+    sol!(
+        r#"{
+            "Person": [
+                { "name": "name", "type": "string" },
+                { "name": "wallet", "type": "address" }
+            ]
+        }"#
+    );
+
+    let _ = Person { name: String::new(), wallet: Address::ZERO };
+    assert_eq!(Person::eip712_encode_type(), "Person(string name,address wallet)");
+}
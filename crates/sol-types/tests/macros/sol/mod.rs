@@ -686,6 +686,35 @@ fn contract_type() {
     }
 }
 
+#[test]
+fn fixed_point_types() {
+    sol! {
+        #[derive(Debug, PartialEq)]
+        function price(fixed128x18 a, ufixed8x1 b, fixed c) returns (ufixed d);
+    }
+
+    assert_eq!(priceCall::SIGNATURE, "price(fixed128x18,ufixed8x1,fixed128x18)");
+    assert_eq!(priceCall::SELECTOR, keccak256(priceCall::SIGNATURE)[..4]);
+
+    let call = priceCall {
+        a: "-1.5".parse().unwrap(),
+        b: "25.5".parse().unwrap(),
+        c: "0".parse().unwrap(),
+    };
+    let encoded = call.abi_encode();
+    assert_eq!(
+        encoded[4..36],
+        I256::try_from(-1_500_000_000_000_000_000i128).unwrap().to_be_bytes::<32>()
+    );
+    assert_eq!(U256::from_be_slice(&encoded[36..68]), U256::from(255));
+    assert_eq!(priceCall::abi_decode(&encoded).unwrap(), call);
+
+    let mut overflow = encoded;
+    overflow[67] = 0;
+    overflow[66] = 1;
+    assert!(priceCall::abi_decode_validate(&overflow).is_err());
+}
+
 // Correctly identify whether a type is dynamic
 // https://github.com/alloy-rs/core/issues/352
 #[test]
@@ -1366,3 +1395,433 @@ fn extra_derives() {
     let s = MyStruct { a: U256::ZERO };
     let _ = format!("{s:#?}");
 }
+
+#[test]
+fn dispatch() {
+    use alloy_primitives::Bytes;
+    use alloy_sol_types::{ContractError, Panic, PanicKind, Revert};
+
+    sol! {
+        #[sol(dispatch)]
+        contract Vault {
+            error Insufficient(uint256 available, uint256 requested);
+
+            function deposit(uint256 amount);
+            function withdraw(uint256 amount) returns (uint256 remaining);
+            function balanceOf(address) returns (uint256);
+            function stats() returns (uint256 total, uint256 count);
+            function divide(uint256 a, uint256 b) returns (uint256);
+        }
+
+        #[sol(dispatch)]
+        contract Echo {
+            function echo(string s) returns (string);
+        }
+    }
+
+    #[derive(Default)]
+    struct MockVault {
+        balance: U256,
+        count: U256,
+    }
+
+    impl Vault::VaultHandler for MockVault {
+        fn deposit(
+            &mut self,
+            amount: U256,
+        ) -> Result<Vault::depositReturn, ContractError<Vault::VaultErrors>> {
+            self.balance += amount;
+            self.count += U256::from(1);
+            Ok(Vault::depositReturn {})
+        }
+
+        fn withdraw(&mut self, amount: U256) -> Result<U256, ContractError<Vault::VaultErrors>> {
+            if amount > self.balance {
+                return Err(Vault::VaultErrors::Insufficient(Vault::Insufficient {
+                    available: self.balance,
+                    requested: amount,
+                })
+                .into());
+            }
+            self.balance -= amount;
+            Ok(self.balance)
+        }
+
+        fn balanceOf(
+            &mut self,
+            _account: Address,
+        ) -> Result<U256, ContractError<Vault::VaultErrors>> {
+            Ok(self.balance)
+        }
+
+        fn stats(&mut self) -> Result<Vault::statsReturn, ContractError<Vault::VaultErrors>> {
+            Ok(Vault::statsReturn { total: self.balance, count: self.count })
+        }
+
+        fn divide(&mut self, a: U256, b: U256) -> Result<U256, ContractError<Vault::VaultErrors>> {
+            a.checked_div(b).ok_or_else(|| Panic::from(PanicKind::DivisionByZero).into())
+        }
+    }
+
+    let mut vault = MockVault::default();
+    fn call(vault: &mut MockVault, call: impl SolCall) -> Result<Bytes, Bytes> {
+        Vault::dispatch(vault, &call.abi_encode())
+    }
+
+    let ret = call(&mut vault, Vault::depositCall { amount: U256::from(10) }).unwrap();
+    assert!(ret.is_empty());
+    let ret = call(&mut vault, Vault::withdrawCall { amount: U256::from(3) }).unwrap();
+    assert_eq!(Vault::withdrawCall::abi_decode_returns(&ret).unwrap(), U256::from(7));
+    let ret = call(&mut vault, Vault::balanceOfCall(Address::ZERO)).unwrap();
+    assert_eq!(Vault::balanceOfCall::abi_decode_returns(&ret).unwrap(), U256::from(7));
+    let ret = call(&mut vault, Vault::statsCall).unwrap();
+    let stats = Vault::statsCall::abi_decode_returns(&ret).unwrap();
+    assert_eq!((stats.total, stats.count), (U256::from(7), U256::from(1)));
+
+    let err = call(&mut vault, Vault::withdrawCall { amount: U256::from(8) }).unwrap_err();
+    let err = Vault::Insufficient::abi_decode(&err).unwrap();
+    assert_eq!((err.available, err.requested), (U256::from(7), U256::from(8)));
+    let err = call(&mut vault, Vault::divideCall { a: U256::from(1), b: U256::ZERO }).unwrap_err();
+    assert_eq!(Panic::abi_decode(&err).unwrap().kind(), Some(PanicKind::DivisionByZero));
+
+    assert_eq!(Vault::dispatch(&mut vault, &[0xde, 0xad, 0xbe, 0xef]).unwrap_err(), Bytes::new());
+    assert_eq!(Vault::dispatch(&mut vault, &[]).unwrap_err(), Bytes::new());
+
+    struct Reverter;
+
+    impl Echo::EchoHandler for Reverter {
+        fn echo(&mut self, s: String) -> Result<String, alloy_sol_types::GenericContractError> {
+            Err(Revert::from(s).into())
+        }
+    }
+
+    let calldata = Echo::echoCall { s: "nope".into() }.abi_encode();
+    let err = Echo::dispatch(&mut Reverter, &calldata).unwrap_err();
+    assert_eq!(Revert::abi_decode(&err).unwrap().reason(), "nope");
+}
+
+#[test]
+fn dispatch_shadowing() {
+    use alloy_primitives::Bytes;
+
+    sol! {
+        #[sol(dispatch)]
+        contract Relay {
+            function relay(address handler, bytes calldata calldata, uint256 call, uint256 result)
+                returns (uint256);
+        }
+    }
+
+    struct Handler;
+
+    impl Relay::RelayHandler for Handler {
+        fn relay(
+            &mut self,
+            handler: Address,
+            calldata: Bytes,
+            call: U256,
+            result: U256,
+        ) -> Result<U256, alloy_sol_types::GenericContractError> {
+            assert_eq!(handler, Address::repeat_byte(0x11));
+            assert_eq!(calldata, Bytes::from_static(b"data"));
+            Ok(call + result)
+        }
+    }
+
+    let call = Relay::relayCall {
+        handler: Address::repeat_byte(0x11),
+        calldata: Bytes::from_static(b"data"),
+        call: U256::from(1),
+        result: U256::from(2),
+    };
+    let ret = Relay::dispatch(&mut Handler, &call.abi_encode()).unwrap();
+    assert_eq!(Relay::relayCall::abi_decode_returns(&ret).unwrap(), U256::from(3));
+}
+
+#[test]
+fn display() {
+    use alloy_primitives::{address, Bytes};
+
+    sol! {
+        #[sol(display)]
+        contract Exchange {
+            struct Order {
+                address maker;
+                uint96 amount;
+                bytes data;
+            }
+
+            enum Side { Buy, Sell }
+
+            error Insufficient(uint256 available, uint256 requested);
+
+            event Transfer(address indexed from, string indexed memo, uint256 value);
+
+            function transfer(address to, uint256 amount) returns (bool);
+            function place(Order order, Side side, string note, int8[] deltas);
+            function ping(bytes4);
+        }
+
+        function positional(uint256 a, address b);
+    }
+
+    struct FmtCall<'a, T>(&'a T);
+
+    impl<T: SolCall> std::fmt::Display for FmtCall<'_, T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            self.0.fmt_call(f)
+        }
+    }
+
+    let vitalik = address!("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
+
+    let call = Exchange::transferCall { to: vitalik, amount: U256::from(1_000_000) };
+    let expected = "transfer(to: 0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045, amount: 1000000)";
+    assert_eq!(call.to_string(), expected);
+    assert_eq!(Exchange::ExchangeCalls::transfer(call).to_string(), expected);
+
+    let call = Exchange::placeCall {
+        order: Exchange::Order {
+            maker: vitalik,
+            amount: alloy_primitives::aliases::U96::from(5),
+            data: Bytes::from_static(&[0xde, 0xad]),
+        },
+        side: Exchange::Side::Sell,
+        note: "gm \"ser\"".into(),
+        deltas: vec![-1, 2],
+    };
+    assert_eq!(
+        call.to_string(),
+        "place(order: Order(maker: 0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045, amount: 5, \
+         data: 0xdead), side: Side.Sell, note: \"gm \\\"ser\\\"\", deltas: [-1, 2])"
+    );
+    assert_eq!(
+        Exchange::pingCall(alloy_primitives::fixed_bytes!("0x12345678")).to_string(),
+        "ping(0x12345678)"
+    );
+
+    let error = Exchange::Insufficient { available: U256::from(1), requested: U256::from(2) };
+    assert_eq!(error.to_string(), "Insufficient(available: 1, requested: 2)");
+    assert_eq!(
+        Exchange::ExchangeErrors::Insufficient(error).to_string(),
+        "Insufficient(available: 1, requested: 2)"
+    );
+
+    let memo = keccak256("memo");
+    let event = Exchange::Transfer { from: vitalik, memo, value: U256::from(7) };
+    let expected = format!(
+        "Transfer(from: 0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045, memo: {memo}, value: 7)"
+    );
+    assert_eq!(event.to_string(), expected);
+    assert_eq!(Exchange::ExchangeEvents::Transfer(event).to_string(), expected);
+
+    // Without `#[sol(display)]`, `fmt_call` writes the arguments positionally.
+    let call = positionalCall { a: U256::from(42), b: Address::ZERO };
+    assert_eq!(
+        FmtCall(&call).to_string(),
+        "positional(42, 0x0000000000000000000000000000000000000000)"
+    );
+}
+
+#[test]
+fn unknown_variant() {
+    use alloy_primitives::{Bytes, IntoLogData, LogData};
+    use alloy_sol_types::{ContractError, Revert, SolEventInterface, SolInterface};
+
+    sol! {
+        #[sol(unknown_variant, display, dispatch)]
+        #[derive(Debug, PartialEq)]
+        contract Token {
+            error Unauthorized(address caller);
+
+            event Transfer(address indexed from, address indexed to, uint256 amount);
+
+            function transfer(address to, uint256 amount) returns (bool);
+            function balanceOf(address owner) returns (uint256);
+        }
+    }
+    use Token::{TokenCalls, TokenErrors, TokenEvents};
+
+    // Known selectors decode to their variants.
+    let call = TokenCalls::balanceOf(Token::balanceOfCall { owner: Address::ZERO });
+    assert_eq!(TokenCalls::abi_decode(&call.abi_encode()), Ok(call));
+
+    // Unknown selectors decode to `Unknown`, even if the data is shorter than any known call,
+    // and are re-encoded as-is.
+    let data = hex!("deadbeef0102");
+    let unknown = TokenCalls::abi_decode(&data).unwrap();
+    assert_eq!(
+        unknown,
+        TokenCalls::Unknown { selector: hex!("deadbeef"), data: Bytes::from_static(&[1, 2]) }
+    );
+    assert_eq!(TokenCalls::abi_decode_validate(&data).as_ref(), Ok(&unknown));
+    assert_eq!(unknown.selector(), hex!("deadbeef"));
+    assert_eq!(unknown.abi_encode(), data);
+    assert_eq!(unknown.to_string(), "Unknown(selector: 0xdeadbeef, data: 0x0102)");
+    assert!(!TokenCalls::valid_selector(hex!("deadbeef")));
+    assert_eq!(TokenCalls::selectors().count(), 2);
+
+    // Known selectors with invalid data are still errors.
+    assert!(TokenCalls::abi_decode(&Token::balanceOfCall::SELECTOR).is_err());
+
+    // Calls to unknown functions revert with empty data.
+    struct Handler;
+
+    impl Token::TokenHandler for Handler {
+        fn transfer(
+            &mut self,
+            _to: Address,
+            _amount: U256,
+        ) -> Result<bool, ContractError<TokenErrors>> {
+            Ok(true)
+        }
+
+        fn balanceOf(&mut self, _owner: Address) -> Result<U256, ContractError<TokenErrors>> {
+            Ok(U256::ZERO)
+        }
+    }
+
+    assert_eq!(Token::dispatch(&mut Handler, &data).unwrap_err(), Bytes::new());
+
+    // Unknown errors, while reverts and panics keep their own variants.
+    let error = TokenErrors::abi_decode(&hex!("01020304")).unwrap();
+    assert_eq!(error, TokenErrors::Unknown { selector: hex!("01020304"), data: Bytes::new() });
+    let revert = Revert::from("nope").abi_encode();
+    assert_eq!(
+        ContractError::<TokenErrors>::abi_decode(&revert),
+        Ok(ContractError::Revert(Revert::from("nope")))
+    );
+
+    // Unknown events keep their raw log data.
+    let event = Token::Transfer { from: Address::ZERO, to: Address::ZERO, amount: U256::from(1) };
+    let log = event.encode_log_data();
+    assert_eq!(
+        TokenEvents::decode_raw_log(log.topics(), &log.data),
+        Ok(TokenEvents::Transfer(event))
+    );
+
+    let log = LogData::new_unchecked(vec![B256::repeat_byte(1)], Bytes::from_static(&[0xaa]));
+    let unknown = TokenEvents::decode_raw_log(log.topics(), &log.data).unwrap();
+    assert_eq!(unknown, TokenEvents::Unknown(log.clone()));
+    assert_eq!(unknown.to_log_data(), log);
+    assert_eq!(
+        unknown.to_string(),
+        format!("Unknown(topics: [{}], data: 0xaa)", B256::repeat_byte(1))
+    );
+    assert_eq!(unknown.into_log_data(), log);
+    assert_eq!(
+        TokenEvents::decode_raw_log(&[], &[]),
+        Ok(TokenEvents::Unknown(LogData::new_unchecked(vec![], Bytes::new())))
+    );
+}
+
+#[cfg(feature = "seismic")]
+#[test]
+fn display_shielded() {
+    use alloy_primitives::{aliases::SUInt, SAddress};
+    use alloy_sol_types::sol_data::Sbool;
+
+    sol! {
+        #[sol(display)]
+        function deposit(saddress to, suint256 amount, sbool flag, address from);
+
+        #[sol(display)]
+        struct Note {
+            suint256 secret;
+            uint8 tag;
+        }
+
+        #[sol(display)]
+        function memo(Note[] notes);
+
+        #[sol(display)]
+        function reset();
+    }
+
+    let call = depositCall {
+        to: SAddress(Address::repeat_byte(0x11)),
+        amount: SUInt(U256::from(42)),
+        flag: Sbool(true),
+        from: Address::repeat_byte(0x22),
+    };
+    assert_eq!(
+        call.to_string(),
+        "deposit(to: <shielded>, amount: <shielded>, flag: <shielded>, \
+         from: 0x2222222222222222222222222222222222222222)"
+    );
+    assert_eq!(format!("{call:#}"), call.to_string());
+
+    struct Unredacted<'a, T>(&'a T);
+
+    impl<T: SolCall> core::fmt::Display for Unredacted<'_, T> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            self.0.fmt_call_unredacted(f)
+        }
+    }
+
+    assert_eq!(
+        Unredacted(&call).to_string(),
+        "deposit(to: 0x1111111111111111111111111111111111111111, amount: 42, flag: true, \
+         from: 0x2222222222222222222222222222222222222222)"
+    );
+
+    let memo = memoCall { notes: vec![Note { secret: SUInt(U256::from(7)), tag: 1 }] };
+    assert_eq!(memo.to_string(), "memo(notes: [Note(secret: <shielded>, tag: 1)])");
+    assert_eq!(format!("{memo:#}"), memo.to_string());
+    assert_eq!(Unredacted(&memo).to_string(), "memo(notes: [Note(secret: 7, tag: 1)])");
+    assert_eq!(resetCall {}.to_string(), "reset()");
+}
+
+#[cfg(feature = "seismic-crypto")]
+#[test]
+fn encrypted_calldata() {
+    use alloy_primitives::{
+        address, fixed_bytes,
+        seismic_crypto::{EncryptionKey, EncryptionNonce},
+    };
+
+    sol! {
+        function transfer(address to, uint256 amount) returns (bool);
+    }
+
+    // Known-answer vectors, see `alloy_primitives::seismic_crypto`.
+    let key = EncryptionKey::new(hex!(
+        "707efac21d4fe72c7201c910c9e927dd0db33cb079e1e86e762578100f6a0525"
+    ));
+    // A call and its return values must be encrypted with distinct nonces.
+    let nonce: EncryptionNonce = fixed_bytes!("0x000102030405060708090a0b");
+    let returns_nonce: EncryptionNonce = fixed_bytes!("0x0c0d0e0f1011121314151617");
+
+    let call = transferCall {
+        to: address!("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045"),
+        amount: U256::from(1_000_000),
+    };
+    let encrypted = call.abi_encode_encrypted(&key, &nonce);
+    assert_eq!(
+        encrypted,
+        hex!(
+            "25ecff5511fe6395c5a2e7b13f9f464dc60a591d6f63dea405f706551c07e383"
+            "5218d9d3707fdd5e86ba4b8745b905f5db9d9fce638134a2ed3fc6c4c5116104"
+            "9ae9519907fbf0e3c010b31b84966eeab81b5843"
+        )
+    );
+    let decrypted = transferCall::abi_decode_encrypted(&encrypted, &key, &nonce).unwrap();
+    assert_eq!((decrypted.to, decrypted.amount), (call.to, call.amount));
+
+    let encrypted = transferCall::abi_encode_returns_encrypted(&true, &key, &returns_nonce);
+    assert_eq!(
+        encrypted,
+        hex!(
+            "51296feb5ca6eadeb39a16c5fb83286cb2eb913d4ed1c47528ece9b08a75c172"
+            "6df9b5c3db2fe00a412e45e66fb1fcc7"
+        )
+    );
+    assert!(transferCall::abi_decode_returns_encrypted(&encrypted, &key, &returns_nonce).unwrap());
+
+    for wrong_nonce in [nonce, EncryptionNonce::ZERO] {
+        assert_eq!(
+            transferCall::abi_decode_returns_encrypted(&encrypted, &key, &wrong_nonce),
+            Err(alloy_sol_types::Error::custom("failed to decrypt ciphertext"))
+        );
+    }
+}
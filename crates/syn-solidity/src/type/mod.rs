@@ -31,7 +31,6 @@ pub use tuple::TypeTuple;
 /// <https://docs.soliditylang.org/en/latest/grammar.html#a4.SolidityParser.typeName>
 #[derive(Clone)]
 pub enum Type {
-    // TODO: `fixed` and `ufixed`
     /// `address $(payable)?`
     Address(Span, Option<kw::payable>),
     /// `bool`
@@ -48,6 +47,10 @@ pub enum Type {
     Int(Span, Option<NonZeroU16>),
     /// `uint[size]`
     Uint(Span, Option<NonZeroU16>),
+    /// `fixed[MxN]`
+    Fixed(Span, Option<(NonZeroU16, u8)>),
+    /// `ufixed[MxN]`
+    Ufixed(Span, Option<(NonZeroU16, u8)>),
 
     #[cfg(feature = "seismic")]
     /// `sint[size]`
@@ -86,6 +89,8 @@ impl PartialEq for Type {
             (Self::FixedBytes(_, a), Self::FixedBytes(_, b)) => a == b,
             (Self::Int(_, a), Self::Int(_, b)) => a == b,
             (Self::Uint(_, a), Self::Uint(_, b)) => a == b,
+            (Self::Fixed(_, a), Self::Fixed(_, b)) => a == b,
+            (Self::Ufixed(_, a), Self::Ufixed(_, b)) => a == b,
 
             #[cfg(feature = "seismic")]
             (Self::Sint(_, a), Self::Sint(_, b)) => a == b,
@@ -118,6 +123,8 @@ impl Hash for Type {
             Self::FixedBytes(_, size) => size.hash(state),
             Self::Int(_, size) => size.hash(state),
             Self::Uint(_, size) => size.hash(state),
+            Self::Fixed(_, size) => size.hash(state),
+            Self::Ufixed(_, size) => size.hash(state),
 
             #[cfg(feature = "seismic")]
             Self::Sint(_, size) => size.hash(state),
@@ -148,6 +155,8 @@ impl fmt::Debug for Type {
             Self::FixedBytes(_, size) => f.debug_tuple("FixedBytes").field(size).finish(),
             Self::Int(_, size) => f.debug_tuple("Int").field(size).finish(),
             Self::Uint(_, size) => f.debug_tuple("Uint").field(size).finish(),
+            Self::Fixed(_, size) => f.debug_tuple("Fixed").field(size).finish(),
+            Self::Ufixed(_, size) => f.debug_tuple("Ufixed").field(size).finish(),
 
             #[cfg(feature = "seismic")]
             Self::Sint(_, size) => f.debug_tuple("Sint").field(size).finish(),
@@ -179,6 +188,8 @@ impl fmt::Display for Type {
             Self::FixedBytes(_, size) => write!(f, "bytes{size}"),
             Self::Int(_, size) => write_opt(f, "int", *size),
             Self::Uint(_, size) => write_opt(f, "uint", *size),
+            Self::Fixed(_, size) => write_fixed(f, "fixed", *size),
+            Self::Ufixed(_, size) => write_fixed(f, "ufixed", *size),
 
             #[cfg(feature = "seismic")]
             Self::Sint(_, size) => write_opt(f, "sint", *size),
@@ -223,7 +234,9 @@ impl Spanned for Type {
             | Self::Bytes(span)
             | Self::FixedBytes(span, _)
             | Self::Int(span, _)
-            | Self::Uint(span, _) => *span,
+            | Self::Uint(span, _)
+            | Self::Fixed(span, _)
+            | Self::Ufixed(span, _) => *span,
             #[cfg(feature = "seismic")]
             Self::Sint(span, _)
             | Self::Suint(span, _)
@@ -250,7 +263,9 @@ impl Spanned for Type {
             | Self::Bytes(span)
             | Self::FixedBytes(span, _)
             | Self::Int(span, _)
-            | Self::Uint(span, _) => *span = new_span,
+            | Self::Uint(span, _)
+            | Self::Fixed(span, _)
+            | Self::Ufixed(span, _) => *span = new_span,
 
             #[cfg(feature = "seismic")]
             Self::Sint(span, _)
@@ -351,6 +366,16 @@ impl Type {
                         }
                         Some(size) => Self::Int(span, size),
                     }
+                } else if let Some(s) = s.strip_prefix("fixed") {
+                    match parse_fixed_size(s, span)? {
+                        None => Self::custom(ident),
+                        Some(size) => Self::Fixed(span, size),
+                    }
+                } else if let Some(s) = s.strip_prefix("ufixed") {
+                    match parse_fixed_size(s, span)? {
+                        None => Self::custom(ident),
+                        Some(size) => Self::Ufixed(span, size),
+                    }
                 } else if let Some(s) = s.strip_prefix("uint") {
                     match parse_size(s, span)? {
                         None => Self::custom(ident),
@@ -395,6 +420,8 @@ impl Type {
             Self::Bool(_)
                 | Self::Int(..)
                 | Self::Uint(..)
+                | Self::Fixed(..)
+                | Self::Ufixed(..)
                 | Self::FixedBytes(..)
                 | Self::Address(..)
                 | Self::Function(_)
@@ -409,6 +436,8 @@ impl Type {
             Self::Bool(_)
             | Self::Int(..)
             | Self::Uint(..)
+            | Self::Fixed(..)
+            | Self::Ufixed(..)
             | Self::FixedBytes(..)
             | Self::Address(..)
             | Self::Function(_) => false,
@@ -449,6 +478,8 @@ impl Type {
             Self::Bool(_)
                 | Self::Int(..)
                 | Self::Uint(..)
+                | Self::Fixed(..)
+                | Self::Ufixed(..)
                 | Self::FixedBytes(..)
                 | Self::Address(..)
                 | Self::Function(_)
@@ -483,6 +514,8 @@ impl Type {
             Self::Bool(_)
             | Self::Int(..)
             | Self::Uint(..)
+            | Self::Fixed(..)
+            | Self::Ufixed(..)
             | Self::FixedBytes(..)
             | Self::Address(..)
             | Self::String(_)
@@ -503,6 +536,8 @@ impl Type {
             Self::Bool(_)
             | Self::Int(..)
             | Self::Uint(..)
+            | Self::Fixed(..)
+            | Self::Ufixed(..)
             | Self::FixedBytes(..)
             | Self::Address(..)
             | Self::Function(_)
@@ -619,6 +654,37 @@ fn write_opt(f: &mut fmt::Formatter<'_>, name: &str, size: Option<NonZeroU16>) -
     Ok(())
 }
 
+fn write_fixed(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    size: Option<(NonZeroU16, u8)>,
+) -> fmt::Result {
+    f.write_str(name)?;
+    if let Some((m, n)) = size {
+        write!(f, "{m}x{n}")?;
+    }
+    Ok(())
+}
+
+// None => Custom
+// Some(size) => size
+fn parse_fixed_size(s: &str, span: Span) -> Result<Option<Option<(NonZeroU16, u8)>>> {
+    if s.is_empty() {
+        return Ok(Some(None));
+    }
+    let is_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    let Some((m, n)) = s.split_once('x').filter(|(m, n)| is_digits(m) && is_digits(n)) else {
+        return Ok(None);
+    };
+    match (m.parse::<NonZeroU16>(), n.parse::<u8>()) {
+        (Ok(m), Ok(n)) if m.get() <= 256 && m.get() % 8 == 0 && n <= 80 => Ok(Some(Some((m, n)))),
+        _ => Err(Error::new(
+            span,
+            "fixedMxN must have a multiple of 8 up to 256 bits and at most 80 decimals",
+        )),
+    }
+}
+
 // None => Custom
 // Some(size) => size
 fn parse_size(s: &str, span: Span) -> Result<Option<Option<NonZeroU16>>> {